@@ -12,16 +12,18 @@ use crate::bid_escrow::types::{BidId, JobOfferId};
 use crate::configuration::{Configuration, ConfigurationBuilder};
 use crate::modules::refs::ContractRefs;
 use crate::utils::withdraw;
+use crate::voting::keeper_reward::{KeeperRewardPool, KeeperRewarded};
 use odra::contract_env::{caller, get_block_time};
 use odra::types::{event::OdraEvent, Address, Balance, BlockTime};
 use std::rc::Rc;
 
 /// Manages the Bidding process.
-#[odra::module(events = [JobCreated, JobOfferCreated, BidSubmitted, BidCancelled])]
+#[odra::module(events = [JobCreated, JobOfferCreated, BidSubmitted, BidCancelled, KeeperRewarded])]
 pub struct BidEngine {
     bid_storage: BidStorage,
     job_storage: JobStorage,
     refs: ContractRefs,
+    keeper_reward_pool: KeeperRewardPool,
 }
 
 impl BidEngine {
@@ -188,6 +190,9 @@ impl BidEngine {
     /// If a Job with the given id does not exists, contract execution stop with [`Error::JobOfferNotFound`].
     ///
     /// Executes validations: [`HasPermissionsToCancelJobOffer`] and [`CanJobOfferBeCancelled`].
+    /// Once the auction has ended without picking a bid, anyone can cancel the offer.
+    ///
+    /// The caller is rewarded as a keeper, see [KeeperRewardPool::reward_keeper()].
     ///
     /// [`HasPermissionsToCancelJobOffer`]: crate::rules::validation::bid_escrow::HasPermissionsToCancelJobOffer
     /// [`CanJobOfferBeCancelled`]: crate::rules::validation::bid_escrow::CanJobOfferBeCancelled
@@ -199,12 +204,35 @@ impl BidEngine {
             block_time: get_block_time(),
         };
         job_offer.cancel(&cancel_job_offer_request);
+        let configuration = job_offer.configuration.clone();
 
         self.cancel_all_bids(&job_offer_id);
         self.return_job_offer_poster_dos_fee(&job_offer_id);
 
         self.bid_storage.update_job_offer(&job_offer_id, job_offer);
         self.bid_storage.remove_from_active_offers(job_offer_id);
+
+        self.keeper_reward_pool.reward_keeper(
+            cancel_job_offer_request.caller,
+            &configuration,
+            &self.refs,
+        );
+    }
+
+    /// Returns the job offers from a page of the active job offers whose auction has ended without picking a bid,
+    /// so they can be canceled.
+    pub fn expired_job_offers(&self, offset: u32, limit: u32) -> Vec<JobOfferId> {
+        let block_time = get_block_time();
+        self.bid_storage
+            .get_active_offers_page(offset, limit)
+            .into_iter()
+            .filter(|job_offer_id| {
+                self.bid_storage
+                    .get_job_offer(job_offer_id)
+                    .map(|job_offer| job_offer.is_expired(block_time))
+                    .unwrap_or_default()
+            })
+            .collect()
     }
 
     pub fn pick_bid(&mut self, job_offer_id: JobOfferId, bid_id: BidId, cspr_amount: Balance) {
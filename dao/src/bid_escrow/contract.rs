@@ -192,18 +192,18 @@ use crate::bid_escrow::events::BidEscrowSlashResults;
 use crate::bid_escrow::job::Job;
 use crate::bid_escrow::job_engine::{JobEngine, JobEngineComposer};
 use crate::bid_escrow::job_offer::JobOffer;
-use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
+use crate::bid_escrow::types::{ActionableWork, BidId, JobId, JobOfferId};
 use crate::modules::refs::ContractRefs;
 use crate::modules::AccessControl;
 use crate::utils::types::DocumentHash;
-use crate::voting::ballot::{Ballot, Choice};
+use crate::voting::ballot::{Ballot, Choice, SealedBallot};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::voting_state_machine::{
     VotingStateMachine, VotingSummary, VotingType,
 };
 use crate::voting::voting_engine::{VotingEngine, VotingEngineComposer};
 use odra::contract_env::{caller, self_balance};
-use odra::types::{event::OdraEvent, Address, Balance, BlockTime};
+use odra::types::{event::OdraEvent, Address, Balance, BlockTime, Bytes};
 use odra::{Composer, Instance};
 
 /// A contract that manages the full `Bid Escrow` process.
@@ -222,8 +222,10 @@ pub struct BidEscrowContract {
 impl Instance for BidEscrowContract {
     fn instance(namespace: &str) -> Self {
         let refs = Composer::new(namespace, "refs").compose();
+        let keeper_reward_pool = Composer::new(namespace, "keeper_reward_pool").compose();
         let voting_engine = VotingEngineComposer::new(namespace, "voting_engine")
             .with_refs(&refs)
+            .with_keeper_reward_pool(&keeper_reward_pool)
             .compose();
         let job_storage = Composer::new(namespace, "job_storage").compose();
         let bid_storage = Composer::new(namespace, "bid_storage").compose();
@@ -232,11 +234,13 @@ impl Instance for BidEscrowContract {
             .with_job_storage(&job_storage)
             .with_bid_storage(&bid_storage)
             .with_voting_engine(&voting_engine)
+            .with_keeper_reward_pool(&keeper_reward_pool)
             .compose();
         let bid_engine = BidEngineComposer::new(namespace, "bid_engine")
             .with_refs(&refs)
             .with_job_storage(&job_storage)
             .with_bid_storage(&bid_storage)
+            .with_keeper_reward_pool(&keeper_reward_pool)
             .compose();
         Self {
             refs,
@@ -263,11 +267,48 @@ impl BidEscrowContract {
                 address: Address,
             ) -> Option<Ballot>;
 
+            /// Returns the Voter's [`SealedBallot`].
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
+
             /// Returns the address of nth voter who voted on Voting with `voting_id`.
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
 
+            /// Gets the total number of users participated in voting.
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+
+            /// Returns a page of the ids of the votings that have not been finished or canceled yet.
+            ///
+            /// The pages are not stable - when a voting is removed from the list, the last one is moved to its position,
+            /// so a voting may be skipped if the list changes between the calls.
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+
+            /// Returns a page of the ids of the votings created by the `creator`.
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+
+            /// Returns a page of the [`Ballot`]s cast by the `voter`.
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+
+            /// Returns a page of the [`Ballot`]s cast in the voting with `voting_id`.
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
+
             /// Returns [Voting](VotingStateMachine) for given id.
             pub fn get_voting(&self, voting_id: VotingId) -> Option<VotingStateMachine>;
+
+            /// Returns the total reputation that couldn't be redistributed.
+            pub fn get_reputation_dust(&self) -> Balance;
+
+            /// Returns the total CSPR that couldn't be redistributed.
+            pub fn get_cspr_dust(&self) -> Balance;
+
+            /// Adds the attached CSPR to the pool the keepers are rewarded from.
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+
+            /// Returns the CSPR left in the pool the keepers are rewarded from.
+            pub fn get_keeper_reward_pool(&self) -> Balance;
+
+            /// Returns how many Formal Votings finished within `[from, to)` each voter has voted in.
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
         }
 
 
@@ -388,6 +429,8 @@ impl BidEscrowContract {
                 onboard: bool,
             );
 
+            /// Cancels a Job that has not been submitted in time. The caller is rewarded as a keeper.
+            /// [`Read more`](JobEngine::cancel_job()).
             pub fn cancel_job(&mut self, job_id: JobId);
 
             /// Casts a vote over a job.
@@ -398,7 +441,111 @@ impl BidEscrowContract {
             /// # Errors
             /// * [`CannotVoteOnOwnJob`](crate::utils::Error::CannotVoteOnOwnJob) if the voter is either of Job Poster or Worker
             /// * [`VotingNotStarted`](crate::utils::Error::VotingNotStarted) if the voting was not yet started for this job
-            pub fn vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance);
+            /// * [`RationaleRequired`](crate::utils::Error::RationaleRequired) if a ballot against has no rationale
+            /// and [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set
+            pub fn vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance, rationale: Option<DocumentHash>);
+
+            /// Casts a vote over a job on behalf of the caller and his delegators.
+            ///
+            /// # Events
+            /// * [`BallotCast`](crate::voting::voting_engine::events::BallotCast)
+            ///
+            /// # Errors
+            /// * [`CannotVoteOnOwnJob`](crate::utils::Error::CannotVoteOnOwnJob) if the delegate or a delegator is either of Job Poster or Worker
+            /// * [`RationaleRequired`](crate::utils::Error::RationaleRequired) if the ballot is against
+            /// and [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set
+            /// * [`NotADelegate`](crate::utils::Error::NotADelegate) if a delegator has not delegated his voting power to the caller
+            pub fn vote_as_delegate(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance, delegators: Vec<(Address, Balance)>);
+
+            /// Changes the choice and/or the stake of a ballot over a job.
+            ///
+            /// # Events
+            /// * [`BallotChanged`](crate::voting::voting_engine::events::BallotChanged)
+            ///
+            /// # Errors
+            /// * [`CannotVoteOnOwnJob`](crate::utils::Error::CannotVoteOnOwnJob) if the voter is either of Job Poster or Worker
+            /// * [`RationaleRequired`](crate::utils::Error::RationaleRequired) if a ballot changed to against has no rationale
+            /// and [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set
+            /// * [`BallotDoesNotExist`](crate::utils::Error::BallotDoesNotExist) if the voter has not voted
+            pub fn change_vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance, rationale: Option<DocumentHash>);
+
+            /// Withdraws a ballot over a job and unstakes the reputation.
+            ///
+            /// # Events
+            /// * [`BallotChanged`](crate::voting::voting_engine::events::BallotChanged)
+            ///
+            /// # Errors
+            /// * [`CannotVoteOnOwnJob`](crate::utils::Error::CannotVoteOnOwnJob) if the voter is either of Job Poster or Worker
+            /// * [`BallotDoesNotExist`](crate::utils::Error::BallotDoesNotExist) if the voter has not voted
+            pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType);
+
+            /// Cancels an active voting over a job. Only the guardian can call this method.
+            ///
+            /// The job is canceled, and the Job Poster and the External Worker get their CSPR back.
+            ///
+            /// # Events
+            /// * [`VotingCanceled`](crate::voting::voting_engine::events::VotingCanceled)
+            /// * [`JobCancelled`](crate::bid_escrow::events::JobCancelled)
+            ///
+            /// # Errors
+            /// * [`NotGuardian`](crate::utils::Error::NotGuardian) if the caller is not the guardian
+            /// * [`GuardianCancelTooEarly`](crate::utils::Error::GuardianCancelTooEarly) if the guardian has canceled
+            /// a voting in any contract less than `GuardianCancelInterval` ago
+            pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash);
+
+            /// Casts many ballots over jobs in one call. Either all the ballots are cast or none of them.
+            ///
+            /// # Events
+            /// * [`BallotCast`](crate::voting::voting_engine::events::BallotCast) for each item
+            ///
+            /// # Errors
+            /// * [`vote_batch_error()`](crate::utils::vote_batch_error) with the index of the first
+            /// item that fails any of the [vote()](Self::vote) rules and the reason it failed
+            pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>);
+
+            /// Casts many ballots over jobs in one call, each with an optional rationale.
+            /// Either all the ballots are cast or none of them.
+            ///
+            /// # Events
+            /// * [`BallotCast`](crate::voting::voting_engine::events::BallotCast) for each item
+            ///
+            /// # Errors
+            /// * [`vote_batch_error()`](crate::utils::vote_batch_error) with the index of the first
+            /// item that fails any of the [vote()](Self::vote) rules and the reason it failed
+            pub fn vote_batch_with_rationale(
+                &mut self,
+                ballots: Vec<(VotingId, VotingType, Choice, Balance, Option<DocumentHash>)>,
+            );
+
+            /// Commits a secret ballot over a job in the formal phase.
+            ///
+            /// # Events
+            /// * [`BallotCommitted`](crate::voting::voting_engine::events::BallotCommitted)
+            ///
+            /// # Errors
+            /// * [`CannotVoteOnOwnJob`](crate::utils::Error::CannotVoteOnOwnJob) if the voter is either of Job Poster or Worker
+            /// * [`SecretVotingDisabled`](crate::utils::Error::SecretVotingDisabled) if the formal voting is not secret
+            pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance);
+
+            /// Reveals a secret ballot over a job.
+            ///
+            /// # Events
+            /// * [`BallotCast`](crate::voting::voting_engine::events::BallotCast)
+            ///
+            /// # Errors
+            /// * [`InvalidBallotCommitment`](crate::utils::Error::InvalidBallotCommitment) if the choice and the salt
+            /// do not match the commitment
+            /// * [`RevealNotInProgress`](crate::utils::Error::RevealNotInProgress) if the reveal phase is not in progress
+            /// * [`RationaleRequired`](crate::utils::Error::RationaleRequired) if the revealed ballot is against,
+            /// has no rationale and [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required())
+            /// is set
+            pub fn reveal_vote(
+                &mut self,
+                voting_id: VotingId,
+                choice: Choice,
+                salt: Bytes,
+                rationale: Option<DocumentHash>,
+            );
 
             /// Finishes voting. Depending on type of voting, different actions are performed.
             /// [Read more](VotingEngine::finish_voting())
@@ -509,4 +656,18 @@ impl BidEscrowContract {
         }
         .emit();
     }
+
+    /// Returns the work a keeper can currently be rewarded for - votings waiting to be finished,
+    /// jobs and job offers waiting to be canceled.
+    ///
+    /// The same page is checked in the active votings, jobs and job offers, so each list
+    /// may be shorter than the `limit`. The pages are not stable - when an item is removed from a list,
+    /// the last one is moved to its position, so an item may be skipped if the lists change between the calls.
+    pub fn get_actionable_work(&self, offset: u32, limit: u32) -> ActionableWork {
+        ActionableWork {
+            votings: self.voting_engine.get_actionable_votings(offset, limit),
+            jobs: self.job_engine.expired_jobs(offset, limit),
+            job_offers: self.bid_engine.expired_job_offers(offset, limit),
+        }
+    }
 }
@@ -3,9 +3,9 @@
 use crate::bid_escrow::job::Job;
 use crate::bid_escrow::job_offer::JobOffer;
 use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
-use crate::configuration::Configuration;
 use crate::utils::types::DocumentHash;
 use crate::voting::types::VotingId;
+use crate::voting::voting_engine::events::VotingCreatedInfo;
 use odra::types::{Address, Balance, BlockTime};
 use odra::Event;
 
@@ -224,6 +224,10 @@ pub struct BidEscrowVotingCreated {
     config_informal_voting_time: u64,
     config_formal_quorum: u32,
     config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
     config_total_onboarded: Balance,
     config_double_time_between_votings: bool,
     config_voting_clearness_delta: Balance,
@@ -231,29 +235,28 @@ pub struct BidEscrowVotingCreated {
 }
 
 impl BidEscrowVotingCreated {
-    pub fn new(
-        job: &Job,
-        creator: Address,
-        voting_id: VotingId,
-        configuration: &Configuration,
-    ) -> BidEscrowVotingCreated {
+    pub fn new(job: &Job, info: &VotingCreatedInfo) -> BidEscrowVotingCreated {
         BidEscrowVotingCreated {
             bid_id: job.bid_id(),
             job_id: job.job_id(),
             job_offer_id: job.job_offer_id(),
             job_poster: job.poster(),
             worker: job.worker(),
-            creator,
-            voting_id,
-            config_informal_quorum: configuration.informal_voting_quorum(),
-            config_informal_voting_time: configuration.informal_voting_time(),
-            config_formal_quorum: configuration.formal_voting_quorum(),
-            config_formal_voting_time: configuration.formal_voting_time(),
-            config_total_onboarded: configuration.total_onboarded(),
-            config_double_time_between_votings: configuration.should_double_time_between_votings(),
-            config_voting_clearness_delta: configuration.voting_clearness_delta(),
-            config_time_between_informal_and_formal_voting: configuration
-                .time_between_informal_and_formal_voting(),
+            creator: info.creator,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
         }
     }
 }
@@ -269,6 +272,7 @@ pub enum TransferReason {
     JobPaymentAndDOSFeeReturn,
     Redistribution,
     OnboardingStakeReturn,
+    KeeperReward,
 }
 
 impl ToString for TransferReason {
@@ -283,6 +287,7 @@ impl ToString for TransferReason {
             TransferReason::JobPaymentAndDOSFeeReturn => "JobPaymentAndDOSFeeReturn".to_string(),
             TransferReason::Redistribution => "Redistribution".to_string(),
             TransferReason::OnboardingStakeReturn => "OnboardingStakeReturn".to_string(),
+            TransferReason::KeeperReward => "KeeperReward".to_string(),
         }
     }
 }
@@ -178,6 +178,9 @@ impl Job {
 
     /// Verifies if the job can be canceled at a given time.
     ///
+    /// Once the job has expired, anyone can cancel it. Before that, only the poster is permitted
+    /// to try, but the job cannot be canceled yet.
+    ///
     /// # Errors
     /// * [`Error::CannotCancelJob`]
     /// * [`Error::JobCannotBeYetCanceled`]
@@ -186,15 +189,20 @@ impl Job {
             return Err(Error::CannotCancelJob);
         }
 
-        if self.finish_time() + self.grace_period() >= block_time {
-            return Err(Error::JobCannotBeYetCanceled);
+        if self.is_expired(block_time) {
+            return Ok(());
         }
 
-        if self.status() != JobStatus::Created || self.poster() != caller {
+        if self.poster() != caller {
             return Err(Error::CannotCancelJob);
         }
 
-        Ok(())
+        Err(Error::JobCannotBeYetCanceled)
+    }
+
+    /// Checks if the job has not been submitted in time, including the grace period, so it can be canceled.
+    pub fn is_expired(&self, block_time: BlockTime) -> bool {
+        self.status() == JobStatus::Created && self.finish_time() + self.grace_period() < block_time
     }
 
     /// Changes status to the Cancelled
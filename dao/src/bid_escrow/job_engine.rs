@@ -12,24 +12,26 @@ use crate::utils::types::DocumentHash;
 use crate::utils::{withdraw, Error};
 use crate::voting::ballot::Choice;
 use crate::voting::cspr_redistribution::{
-    redistribute_cspr_to_all_vas, redistribute_to_governance,
+    redistribute_cspr_to_all_vas, redistribute_to_governance, split_by_balances,
 };
+use crate::voting::keeper_reward::{KeeperRewardPool, KeeperRewarded};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::voting_state_machine::{VotingResult, VotingSummary, VotingType};
-use crate::voting::voting_engine::VotingEngine;
+use crate::voting::voting_engine::{without_rationale, VotingEngine};
 use odra::contract_env::{attached_value, caller, get_block_time, revert};
 use odra::types::Address;
-use odra::types::{event::OdraEvent, Balance};
+use odra::types::{event::OdraEvent, Balance, Bytes};
 use odra::UnwrapOrRevert;
 use std::collections::BTreeMap;
 
 /// Manages Jobs lifecycle.
-#[odra::module(events = [JobSubmitted, JobRejected, JobCancelled, JobDone, BidEscrowVotingCreated])]
+#[odra::module(events = [JobSubmitted, JobRejected, JobCancelled, JobDone, BidEscrowVotingCreated, KeeperRewarded])]
 pub struct JobEngine {
     job_storage: JobStorage,
     bid_storage: BidStorage,
     refs: ContractRefs,
     voting_engine: VotingEngine,
+    keeper_reward_pool: KeeperRewardPool,
 }
 
 #[odra::module]
@@ -81,8 +83,7 @@ impl JobEngine {
             self.voting_engine
                 .create_voting(worker, Balance::zero(), voting_configuration.clone());
 
-        BidEscrowVotingCreated::new(&job, worker, voting_info.voting_id, &voting_configuration)
-            .emit();
+        BidEscrowVotingCreated::new(&job, &voting_info).emit();
 
         job.set_voting_id(voting_info.voting_id);
 
@@ -91,6 +92,7 @@ impl JobEngine {
             Choice::InFavor,
             stake_for_voting,
             job.is_unbound(),
+            None,
             &mut voting,
             &voting_configuration,
         );
@@ -136,7 +138,8 @@ impl JobEngine {
         // redistribute original cspr stake
         if let Some(cspr_stake) = old_bid.cspr_stake {
             let left = redistribute_to_governance(cspr_stake, &configuration);
-            redistribute_cspr_to_all_vas(left, &self.refs);
+            let dust = redistribute_cspr_to_all_vas(left, &self.refs);
+            self.voting_engine.record_cspr_dust(dust);
         }
 
         // burn original reputation stake
@@ -205,6 +208,9 @@ impl JobEngine {
     /// * the bid stake is redistributed along the VAs' and the multisig wallet.
     /// * `DOS Fee` is returned to the `Job Poster`.
     ///
+    /// Anyone can cancel a job that has not been submitted in time, including the grace period.
+    /// The caller is rewarded as a keeper, see [KeeperRewardPool::reward_keeper()].
+    ///
     /// # Error
     /// If the state in which the process cannot be canceled, the execution reverts with
     /// [Error::CannotCancelJob] or [Error::JobCannotBeYetCanceled].
@@ -226,20 +232,201 @@ impl JobEngine {
             self.slash_worker(&job);
         }
 
+        let configuration = self.bid_storage.get_job_offer_configuration(&job);
         self.raw_cancel_job(job, &bid, caller);
+        self.keeper_reward_pool
+            .reward_keeper(caller, &configuration, &self.refs);
+    }
+
+    /// Returns the jobs from a page of the active jobs that have not been submitted in time and can be canceled.
+    pub fn expired_jobs(&self, offset: u32, limit: u32) -> Vec<JobId> {
+        let block_time = get_block_time();
+        self.job_storage
+            .get_active_jobs_page(offset, limit)
+            .into_iter()
+            .filter(|job_id| {
+                self.job_storage
+                    .get_job(*job_id)
+                    .map(|job| job.is_expired(block_time))
+                    .unwrap_or_default()
+            })
+            .collect()
     }
 
     /// Records vote in [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
     ///
+    /// If [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required())
+    /// is set, a ballot against must include a rationale.
+    ///
     /// # Error
     /// * [`Error::CannotVoteOnOwnJob`].
+    /// * [`Error::RationaleRequired`].
     pub fn vote(
         &mut self,
         voting_id: VotingId,
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        let caller = caller();
+        let job = self.job_storage.get_job_by_voting_id(voting_id);
+
+        if caller == job.poster() || caller == job.worker() {
+            revert(Error::CannotVoteOnOwnJob);
+        }
+        let configuration = self.voting_engine.get_configuration_or_revert(voting_id);
+        if configuration.bid_escrow_rationale_required()
+            && choice.is_against()
+            && rationale.is_none()
+        {
+            revert(Error::RationaleRequired);
+        }
+        self.voting_engine
+            .vote(caller, voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Records a vote on behalf of the caller and his delegators in [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
+    ///
+    /// Neither the delegate nor any of the delegators can be the Job Poster or the Worker. A delegated ballot
+    /// carries no rationale, so a ballot against fails if
+    /// [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set.
+    ///
+    /// See [VotingEngine::vote_as_delegate()](crate::voting::voting_engine::VotingEngine::vote_as_delegate()).
+    ///
+    /// # Error
+    /// * [`Error::CannotVoteOnOwnJob`].
+    /// * [`Error::RationaleRequired`].
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        let caller = caller();
+        let job = self.job_storage.get_job_by_voting_id(voting_id);
+
+        let is_job_party = |voter: &Address| *voter == job.poster() || *voter == job.worker();
+        if is_job_party(&caller)
+            || delegators
+                .iter()
+                .any(|(delegator, _)| is_job_party(delegator))
+        {
+            revert(Error::CannotVoteOnOwnJob);
+        }
+        let configuration = self.voting_engine.get_configuration_or_revert(voting_id);
+        if configuration.bid_escrow_rationale_required() && choice.is_against() {
+            revert(Error::RationaleRequired);
+        }
+        self.voting_engine.vote_as_delegate(
+            caller,
+            voting_id,
+            voting_type,
+            choice,
+            stake,
+            delegators,
+        );
+    }
+
+    /// Changes the choice and/or the stake of a ballot in [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
+    ///
+    /// Like in [vote()](Self::vote), a ballot changed to against must include a rationale if
+    /// [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set.
+    ///
+    /// # Error
+    /// * [`Error::CannotVoteOnOwnJob`].
+    /// * [`Error::RationaleRequired`].
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        let caller = caller();
+        let job = self.job_storage.get_job_by_voting_id(voting_id);
+
+        if caller == job.poster() || caller == job.worker() {
+            revert(Error::CannotVoteOnOwnJob);
+        }
+        let configuration = self.voting_engine.get_configuration_or_revert(voting_id);
+        if configuration.bid_escrow_rationale_required()
+            && choice.is_against()
+            && rationale.is_none()
+        {
+            revert(Error::RationaleRequired);
+        }
+        self.voting_engine
+            .change_vote(caller, voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Withdraws a ballot from [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
+    ///
+    /// # Error
+    /// * [`Error::CannotVoteOnOwnJob`].
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        let caller = caller();
+        let job = self.job_storage.get_job_by_voting_id(voting_id);
+
+        if caller == job.poster() || caller == job.worker() {
+            revert(Error::CannotVoteOnOwnJob);
+        }
+        self.voting_engine
+            .withdraw_vote(caller, voting_id, voting_type);
+    }
+
+    /// Records many votes in one call.
+    ///
+    /// Each item is checked like in [vote()](Self::vote). The items carry no rationale, so a ballot against fails
+    /// if [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set -
+    /// use [vote_batch_with_rationale()](Self::vote_batch_with_rationale) instead.
+    ///
+    /// See [VotingEngine::vote_batch()](crate::voting::voting_engine::VotingEngine::vote_batch()).
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        self.vote_batch_with_rationale(without_rationale(ballots));
+    }
+
+    /// Records many votes in one call, each with an optional rationale.
+    ///
+    /// Each item is checked like in [vote()](Self::vote), so a ballot against must include a rationale if
+    /// [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set.
+    ///
+    /// See [VotingEngine::vote_batch_with_rationale()](crate::voting::voting_engine::VotingEngine::vote_batch_with_rationale()).
+    pub fn vote_batch_with_rationale(
+        &mut self,
+        ballots: Vec<(VotingId, VotingType, Choice, Balance, Option<DocumentHash>)>,
     ) {
+        let caller = caller();
+        self.voting_engine.vote_batch_with(
+            caller,
+            ballots,
+            |voting_id, choice, rationale, configuration| {
+                let job = self
+                    .job_storage
+                    .find_job_by_voting_id(voting_id)
+                    .ok_or(Error::VotingIdNotFound)?;
+                if caller == job.poster() || caller == job.worker() {
+                    return Err(Error::CannotVoteOnOwnJob);
+                }
+                if configuration.bid_escrow_rationale_required()
+                    && choice.is_against()
+                    && rationale.is_none()
+                {
+                    return Err(Error::RationaleRequired);
+                }
+                Ok(())
+            },
+        );
+    }
+
+    /// Commits a secret ballot in [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
+    ///
+    /// # Error
+    /// * [`Error::CannotVoteOnOwnJob`].
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
         let caller = caller();
         let job = self.job_storage.get_job_by_voting_id(voting_id);
 
@@ -247,7 +434,32 @@ impl JobEngine {
             revert(Error::CannotVoteOnOwnJob);
         }
         self.voting_engine
-            .vote(caller, voting_id, voting_type, choice, stake);
+            .commit_vote(caller, voting_id, commitment, stake);
+    }
+
+    /// Reveals a secret ballot in [Voting](crate::voting::voting_engine::voting_state_machine::VotingStateMachine).
+    ///
+    /// Like in [vote()](Self::vote), a revealed ballot against must include a rationale if
+    /// [BidEscrowRationaleRequired](crate::configuration::Configuration::bid_escrow_rationale_required()) is set.
+    ///
+    /// # Error
+    /// * [`Error::RationaleRequired`].
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        let configuration = self.voting_engine.get_configuration_or_revert(voting_id);
+        if configuration.bid_escrow_rationale_required()
+            && choice.is_against()
+            && rationale.is_none()
+        {
+            revert(Error::RationaleRequired);
+        }
+        self.voting_engine
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
     }
 
     /// Ends the current voting phase and redistributes funds.
@@ -345,6 +557,29 @@ impl JobEngine {
         voting_summary
     }
 
+    /// Cancels the voting over a job on behalf of the guardian. See [VotingEngine::guardian_cancel_voting()].
+    ///
+    /// The job is canceled - the Job Poster gets the payment and the DOS fee back, and the External Worker
+    /// gets the CSPR stake back.
+    ///
+    /// # Events
+    /// * [`VotingCanceled`](crate::voting::voting_engine::events::VotingCanceled) with the `reason`.
+    /// * [`JobCancelled`]
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        let mut job = self.job_storage.get_job_by_voting_id(voting_id);
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+
+        self.return_job_poster_payment_and_dos_fee(&job);
+        self.return_external_worker_cspr_stake(&job);
+
+        job.cancel();
+        JobCancelled::new(&job, caller()).emit();
+
+        self.job_storage.remove_from_active_jobs(job.job_id());
+        self.job_storage.store_job(job);
+    }
+
     /// Slashes the voter and cancels all his active jobs.
     /// Returns the lists of slashed jobs, canceled votings and affected votings.
     pub fn slash_voter(&mut self, voter: Address) -> (Vec<JobId>, Vec<VotingId>, Vec<VotingId>) {
@@ -471,7 +706,7 @@ impl JobEngine {
             )
             .unwrap_or_revert_with(Error::VotingDoesNotExist);
 
-        let mut mints = BTreeMap::new();
+        let mut stakes = BTreeMap::new();
 
         for i in 0..self
             .voting_engine
@@ -484,10 +719,17 @@ impl JobEngine {
             if ballot.unbound || ballot.canceled {
                 continue;
             }
-            let to_transfer = ballot.stake * amount / voting.total_bound_stake();
-            mints.insert(ballot.voter, to_transfer);
+            for (owner, stake) in ballot.stakes() {
+                *stakes.entry(owner).or_insert_with(Balance::zero) += stake;
+            }
         }
 
+        let (shares, dust) = split_by_balances(amount, &stakes);
+        self.voting_engine.record_reputation_dust(dust);
+        let mints: BTreeMap<Address, Balance> = shares
+            .into_iter()
+            .filter(|(_, share)| !share.is_zero())
+            .collect();
         if !mints.is_empty() {
             self.refs
                 .reputation_token()
@@ -507,11 +749,12 @@ impl JobEngine {
         let redistribute_to_all_vas = configuration.distribute_payment_to_non_voters();
 
         // For VA's
-        if redistribute_to_all_vas {
-            redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+        let dust = if redistribute_to_all_vas {
+            redistribute_cspr_to_all_vas(to_redistribute, &self.refs)
         } else {
-            self.redistribute_cspr_to_voters(job, to_redistribute);
-        }
+            self.redistribute_cspr_to_voters(job, to_redistribute)
+        };
+        self.voting_engine.record_cspr_dust(dust);
     }
 
     fn redistribute_cspr_external_worker(&mut self, job: &Job, configuration: &Configuration) {
@@ -526,11 +769,12 @@ impl JobEngine {
         let redistribute_to_all_vas = configuration.distribute_payment_to_non_voters();
 
         // For VA's
-        if redistribute_to_all_vas {
-            redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+        let dust = if redistribute_to_all_vas {
+            redistribute_cspr_to_all_vas(to_redistribute, &self.refs)
         } else {
-            self.redistribute_cspr_to_voters(job, to_redistribute);
-        }
+            self.redistribute_cspr_to_voters(job, to_redistribute)
+        };
+        self.voting_engine.record_cspr_dust(dust);
     }
 
     fn redistribute_cspr_external_worker_failed(
@@ -543,12 +787,11 @@ impl JobEngine {
 
         // For VA's
         let all_balances = self.refs.reputation_token().all_balances();
-        let total_supply = all_balances.total_supply();
-
-        for (address, balance) in all_balances.balances() {
-            let amount = total_left * *balance / total_supply;
-            withdraw(address, amount, TransferReason::Redistribution);
+        let (shares, dust) = split_by_balances(total_left, all_balances.balances());
+        for (address, amount) in shares {
+            withdraw(&address, amount, TransferReason::Redistribution);
         }
+        self.voting_engine.record_cspr_dust(dust);
     }
 
     fn return_job_poster_dos_fee(&mut self, job: &Job) {
@@ -562,18 +805,19 @@ impl JobEngine {
         );
     }
 
-    fn redistribute_cspr_to_voters(&mut self, job: &Job, to_redistribute: Balance) {
+    /// Transfers CSPRs to the voters proportionally to their reputation. Returns the dust.
+    fn redistribute_cspr_to_voters(&mut self, job: &Job, to_redistribute: Balance) -> Balance {
         let voting_id = job
             .voting_id()
             .unwrap_or_revert_with(Error::VotingDoesNotExist);
         let all_voters = self.voting_engine.all_voters(voting_id, VotingType::Formal);
 
         let balances = self.refs.reputation_token().partial_balances(all_voters);
-        let partial_supply = balances.total_supply();
-        for (address, balance) in balances.balances() {
-            let amount = to_redistribute * *balance / partial_supply;
-            withdraw(address, amount, TransferReason::Redistribution)
+        let (shares, dust) = split_by_balances(to_redistribute, balances.balances());
+        for (address, amount) in shares {
+            withdraw(&address, amount, TransferReason::Redistribution)
         }
+        dust
     }
 
     fn raw_cancel_job(&mut self, mut job: Job, bid: &Bid, caller: Address) {
@@ -584,7 +828,8 @@ impl JobEngine {
         // redistribute cspr stake
         if let Some(cspr_stake) = bid.cspr_stake {
             let left = redistribute_to_governance(cspr_stake, &configuration);
-            redistribute_cspr_to_all_vas(left, &self.refs);
+            let dust = redistribute_cspr_to_all_vas(left, &self.refs);
+            self.voting_engine.record_cspr_dust(dust);
         }
 
         job.cancel();
@@ -138,6 +138,7 @@ impl JobOffer {
             .add_validation(HasPermissionsToCancelJobOffer::create(
                 request.caller,
                 self.job_poster,
+                self.is_expired(request.block_time),
             ))
             .add_validation(CanJobOfferBeCancelled::create(
                 self.auction_state(request.block_time),
@@ -163,6 +164,12 @@ impl JobOffer {
         }
     }
 
+    /// Checks if the auction has ended without picking a bid, so the job offer can be canceled.
+    pub fn is_expired(&self, block_time: BlockTime) -> bool {
+        self.status == JobOfferStatus::Created
+            && self.auction_state(block_time) == AuctionState::None
+    }
+
     /// Gets a reference to the job configuration.
     pub fn configuration(&self) -> &Configuration {
         &self.configuration
@@ -5,10 +5,11 @@ use crate::bid_escrow::job::Job;
 use crate::bid_escrow::job_offer::JobOffer;
 use crate::bid_escrow::types::{BidId, JobId, JobOfferId};
 use crate::configuration::Configuration;
+use crate::modules::IndexedList;
 use crate::utils::Error;
 use crate::voting::types::VotingId;
 use odra::types::Address;
-use odra::{List, Mapping, Sequence, UnwrapOrRevert, Variable};
+use odra::{List, Mapping, Sequence, UnwrapOrRevert};
 
 /// Stores [Bid]-related variables and mappings.
 #[odra::module]
@@ -18,7 +19,7 @@ pub struct BidStorage {
     bids: Mapping<BidId, Bid>,
     job_offers_bids: Mapping<JobOfferId, List<BidId>>,
     bids_count: Sequence<BidId>,
-    active_job_offers_ids: Variable<Vec<JobOfferId>>,
+    active_job_offers_ids: IndexedList,
     worker_bids: Mapping<(Address, JobOfferId), Option<BidId>>,
 }
 
@@ -108,19 +109,20 @@ impl BidStorage {
     }
 
     pub fn add_to_active_offers(&mut self, job_offer_id: JobOfferId) {
-        let mut active_list = self.active_job_offers_ids.get_or_default();
-        active_list.push(job_offer_id);
-        self.active_job_offers_ids.set(active_list);
+        self.active_job_offers_ids.push(job_offer_id);
     }
 
     pub fn remove_from_active_offers(&mut self, job_offer_id: JobOfferId) {
-        let mut active_list = self.active_job_offers_ids.get_or_default();
-        active_list.retain(|&id| id != job_offer_id);
-        self.active_job_offers_ids.set(active_list);
+        self.active_job_offers_ids.remove(job_offer_id);
     }
 
     pub fn get_active_offers(&self) -> Vec<JobOfferId> {
-        self.active_job_offers_ids.get_or_default()
+        self.active_job_offers_ids.to_vec()
+    }
+
+    /// Returns a page of the active [JobOffer]s.
+    pub fn get_active_offers_page(&self, offset: u32, limit: u32) -> Vec<JobOfferId> {
+        self.active_job_offers_ids.page(offset, limit)
     }
 
     pub fn add_to_active_bids(&mut self, worker: Address, job_offer_id: JobOfferId, bid_id: BidId) {
@@ -142,7 +144,7 @@ pub struct JobStorage {
     jobs: Mapping<JobId, Job>,
     jobs_for_voting: Mapping<VotingId, JobId>,
     jobs_count: Sequence<JobId>,
-    active_jobs: Variable<Vec<JobId>>,
+    active_jobs: IndexedList,
 }
 
 impl JobStorage {
@@ -166,6 +168,13 @@ impl JobStorage {
         self.get_job_or_revert(job_id)
     }
 
+    /// Gets the [Job] matching to a given voting id or `None`.
+    pub fn find_job_by_voting_id(&self, voting_id: VotingId) -> Option<Job> {
+        self.jobs_for_voting
+            .get(&voting_id)
+            .and_then(|job_id| self.get_job(job_id))
+    }
+
     /// Gets the [Job] with a given id or reverts with [MappingItemNotAvailable](crate::utils::Error::MappingItemNotAvailable).
     pub fn get_job_or_revert(&self, job_id: JobId) -> Job {
         self.jobs
@@ -190,20 +199,21 @@ impl JobStorage {
 
     /// Adds a job to the list of active jobs.
     pub fn add_to_active_jobs(&mut self, job_id: JobId) {
-        let mut active_list = self.active_jobs.get_or_default();
-        active_list.push(job_id);
-        self.active_jobs.set(active_list);
+        self.active_jobs.push(job_id);
     }
 
     /// Removes a job from the list of active jobs.
     pub fn remove_from_active_jobs(&mut self, job_id: JobId) {
-        let mut active_list = self.active_jobs.get_or_default();
-        active_list.retain(|&id| id != job_id);
-        self.active_jobs.set(active_list);
+        self.active_jobs.remove(job_id);
     }
 
     /// Returns all active jobs.
     pub fn get_active_jobs(&self) -> Vec<JobId> {
-        self.active_jobs.get_or_default()
+        self.active_jobs.to_vec()
+    }
+
+    /// Returns a page of the active jobs.
+    pub fn get_active_jobs_page(&self, offset: u32, limit: u32) -> Vec<JobId> {
+        self.active_jobs.page(offset, limit)
     }
 }
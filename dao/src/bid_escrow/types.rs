@@ -1,4 +1,7 @@
 //! Bid Escrow simple types.
+use crate::voting::types::VotingId;
+use crate::voting::voting_engine::voting_state_machine::VotingType;
+use odra::OdraType;
 
 /// A unique bid id.
 pub type BidId = u32;
@@ -6,3 +9,14 @@ pub type BidId = u32;
 pub type JobOfferId = u32;
 /// A unique job id.
 pub type JobId = u32;
+
+/// The work in the Bid Escrow a keeper can be rewarded for.
+#[derive(OdraType)]
+pub struct ActionableWork {
+    /// Votings whose current phase has ended.
+    pub votings: Vec<(VotingId, VotingType)>,
+    /// Jobs that have not been submitted in time. Can be canceled by the Job Poster.
+    pub jobs: Vec<JobId>,
+    /// Job offers whose auction has ended without picking a bid. Can be canceled by the Job Poster.
+    pub job_offers: Vec<JobOfferId>,
+}
@@ -1,9 +1,10 @@
 use crate::configuration::dao_configuration::DaoConfiguration;
-use crate::configuration::voting_configuration::VotingConfiguration;
+use crate::configuration::voting_configuration::{VoterKind, VotingConfiguration};
 use crate::configuration::Configuration;
 use crate::utils::consts;
 use crate::utils::ContractCall;
 use crate::utils::Error;
+use crate::voting::reputation_redistribution::RedistributionPolicyKind;
 use odra::call_contract;
 use odra::contract_env::revert;
 use odra::types::{Address, Balance, Bytes, CallArgs, OdraType};
@@ -56,6 +57,19 @@ impl ConfigurationBuilder {
                         INFORMAL_STAKE_REPUTATION,
                         variables,
                     ),
+                    secret_formal_voting: Self::get_variable(SECRET_FORMAL_VOTING, variables),
+                    formal_voting_reveal_time: Self::get_variable(
+                        FORMAL_VOTING_REVEAL_TIME,
+                        variables,
+                    ),
+                    unrevealed_ballot_penalty: Self::get_variable(
+                        UNREVEALED_BALLOT_PENALTY,
+                        variables,
+                    ),
+                    timelock_delay: Self::get_variable(TIMELOCK_DELAY, variables),
+                    voting_extension_window: Self::get_variable(VOTING_EXTENSION_WINDOW, variables),
+                    voting_extension_time: Self::get_variable(VOTING_EXTENSION_TIME, variables),
+                    voting_extension_cap: Self::get_variable(VOTING_EXTENSION_CAP, variables),
                     time_between_informal_and_formal_voting: Self::get_variable(
                         TIME_BETWEEN_INFORMAL_AND_FORMAL_VOTING,
                         variables,
@@ -87,10 +101,63 @@ impl ConfigurationBuilder {
                     ),
                     informal_quorum_ratio: Self::get_variable(INFORMAL_QUORUM_RATIO, variables),
                     formal_quorum_ratio: Self::get_variable(FORMAL_QUORUM_RATIO, variables),
+                    stake_weighted_quorum: Self::get_variable(STAKE_WEIGHTED_QUORUM, variables),
+                    admin_pass_threshold: Self::get_variable(ADMIN_PASS_THRESHOLD, variables),
+                    repo_pass_threshold: Self::get_variable(REPO_PASS_THRESHOLD, variables),
+                    reputation_pass_threshold: Self::get_variable(
+                        REPUTATION_PASS_THRESHOLD,
+                        variables,
+                    ),
+                    slashing_pass_threshold: Self::get_variable(SLASHING_PASS_THRESHOLD, variables),
+                    kyc_pass_threshold: Self::get_variable(KYC_PASS_THRESHOLD, variables),
+                    simple_pass_threshold: Self::get_variable(SIMPLE_PASS_THRESHOLD, variables),
+                    bid_escrow_pass_threshold: Self::get_variable(
+                        BID_ESCROW_PASS_THRESHOLD,
+                        variables,
+                    ),
+                    simple_quadratic_voting: Self::get_variable(SIMPLE_QUADRATIC_VOTING, variables),
+                    repo_quadratic_voting: Self::get_variable(REPO_QUADRATIC_VOTING, variables),
                     bid_escrow_payment_ratio: Self::get_variable(
                         BID_ESCROW_PAYMENT_RATIO,
                         variables,
                     ),
+                    keeper_cspr_reward: Self::get_variable(KEEPER_CSPR_REWARD, variables),
+                    keeper_reputation_reward: Self::get_variable(
+                        KEEPER_REPUTATION_REWARD,
+                        variables,
+                    ),
+                    redistribution_policy: Self::get_variable(REDISTRIBUTION_POLICY, variables),
+                    redistribution_partial_loss_ratio: Self::get_variable(
+                        REDISTRIBUTION_PARTIAL_LOSS_RATIO,
+                        variables,
+                    ),
+                    treasury_address: Self::get_variable(TREASURY_ADDRESS, variables),
+                    signal_voting_time: Self::get_variable(SIGNAL_VOTING_TIME, variables),
+                    signal_quorum_ratio: Self::get_variable(SIGNAL_QUORUM_RATIO, variables),
+                    participation_epoch_length: Self::get_variable(
+                        PARTICIPATION_EPOCH_LENGTH,
+                        variables,
+                    ),
+                    participation_min_formal_votings: Self::get_variable(
+                        PARTICIPATION_MIN_FORMAL_VOTINGS,
+                        variables,
+                    ),
+                    participation_reward: Self::get_variable(PARTICIPATION_REWARD, variables),
+                    non_participation_decay: Self::get_variable(NON_PARTICIPATION_DECAY, variables),
+                    guardian_address: Self::get_variable(GUARDIAN_ADDRESS, variables),
+                    guardian_cancel_interval: Self::get_variable(
+                        GUARDIAN_CANCEL_INTERVAL,
+                        variables,
+                    ),
+                    veto_voter_address: Self::get_variable(VETO_VOTER_ADDRESS, variables),
+                    governance_executor_address: Self::get_variable(
+                        GOVERNANCE_EXECUTOR_ADDRESS,
+                        variables,
+                    ),
+                    bid_escrow_rationale_required: Self::get_variable(
+                        BID_ESCROW_RATIONALE_REQUIRED,
+                        variables,
+                    ),
                     voting_ids_address: Self::get_variable(VOTING_IDS_ADDRESS, variables),
                 },
                 VotingConfiguration {
@@ -100,6 +167,7 @@ impl ConfigurationBuilder {
                     contract_calls: Vec::new(),
                     only_va_can_create: true,
                     double_time_between_votings: false,
+                    voter_kind: VoterKind::Simple,
                 },
                 total_onboarded,
             ),
@@ -124,6 +192,12 @@ impl ConfigurationBuilder {
         self
     }
 
+    /// Sets the `voter_kind` field.
+    pub fn voter_kind(mut self, voter_kind: VoterKind) -> Self {
+        self.configuration.set_voter_kind(voter_kind);
+        self
+    }
+
     /// Sets the `is_bid_escrow` field and inits the fiat rate.
     pub fn set_is_bid_escrow(mut self, is_bid_escrow: bool) -> ConfigurationBuilder {
         let rate: Balance = call_contract(
@@ -146,7 +220,16 @@ impl ConfigurationBuilder {
     }
 
     /// Builds the final [Configuration].
+    ///
+    /// # Errors
+    /// * [`TreasuryAddressNotSet`](Error::TreasuryAddressNotSet) if the [Treasury](RedistributionPolicyKind::Treasury)
+    /// redistribution policy is selected, but the TreasuryAddress is not set.
     pub fn build(self) -> Configuration {
+        if self.configuration.redistribution_policy_kind() == RedistributionPolicyKind::Treasury
+            && self.configuration.treasury_address().is_none()
+        {
+            revert(Error::TreasuryAddressNotSet);
+        }
         self.configuration
     }
 
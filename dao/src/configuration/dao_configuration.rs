@@ -1,3 +1,4 @@
+use crate::voting::reputation_redistribution::RedistributionPolicyKind;
 use odra::types::{Address, Balance, BlockTime};
 use odra::OdraType;
 
@@ -15,11 +16,28 @@ pub struct DaoConfiguration {
     pub bid_escrow_formal_quorum_ratio: Balance,
     pub informal_quorum_ratio: Balance,
     pub formal_quorum_ratio: Balance,
+    pub stake_weighted_quorum: bool,
+    pub admin_pass_threshold: Balance,
+    pub repo_pass_threshold: Balance,
+    pub reputation_pass_threshold: Balance,
+    pub slashing_pass_threshold: Balance,
+    pub kyc_pass_threshold: Balance,
+    pub simple_pass_threshold: Balance,
+    pub bid_escrow_pass_threshold: Balance,
+    pub simple_quadratic_voting: bool,
+    pub repo_quadratic_voting: bool,
     pub bid_escrow_informal_voting_time: BlockTime,
     pub bid_escrow_formal_voting_time: BlockTime,
     pub informal_voting_time: BlockTime,
     pub formal_voting_time: BlockTime,
     pub informal_stake_reputation: bool,
+    pub secret_formal_voting: bool,
+    pub formal_voting_reveal_time: BlockTime,
+    pub unrevealed_ballot_penalty: Balance,
+    pub timelock_delay: BlockTime,
+    pub voting_extension_window: BlockTime,
+    pub voting_extension_time: BlockTime,
+    pub voting_extension_cap: BlockTime,
     pub time_between_informal_and_formal_voting: BlockTime,
     pub va_bid_acceptance_timeout: BlockTime,
     pub va_can_bid_on_public_auction: bool,
@@ -29,5 +47,21 @@ pub struct DaoConfiguration {
     pub voting_clearness_delta: Balance,
     pub voting_start_after_job_worker_submission: BlockTime,
     pub bid_escrow_payment_ratio: Balance,
+    pub keeper_cspr_reward: Balance,
+    pub keeper_reputation_reward: Balance,
+    pub redistribution_policy: RedistributionPolicyKind,
+    pub redistribution_partial_loss_ratio: Balance,
+    pub treasury_address: Option<Address>,
+    pub signal_voting_time: BlockTime,
+    pub signal_quorum_ratio: Balance,
+    pub participation_epoch_length: BlockTime,
+    pub participation_min_formal_votings: u64,
+    pub participation_reward: Balance,
+    pub non_participation_decay: Balance,
+    pub guardian_address: Option<Address>,
+    pub guardian_cancel_interval: BlockTime,
+    pub veto_voter_address: Option<Address>,
+    pub governance_executor_address: Option<Address>,
+    pub bid_escrow_rationale_required: bool,
     pub voting_ids_address: Address,
 }
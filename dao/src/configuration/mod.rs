@@ -12,9 +12,13 @@ mod voting_configuration;
 
 pub use builder::ConfigurationBuilder;
 pub use dao_configuration::DaoConfiguration;
-pub use voting_configuration::VotingConfiguration;
+pub use voting_configuration::{VoterKind, VotingConfiguration};
 
-use crate::utils::{per_mil_of, per_mil_of_as_u32, to_per_mils, ContractCall, Error};
+use crate::utils::{isqrt, per_mil_of, per_mil_of_as_u32, to_per_mils, ContractCall, Error};
+use crate::voting::reputation_redistribution::{
+    NoRedistribution, PartialLossRedistribution, ProRataRedistribution, RedistributionPolicy,
+    RedistributionPolicyKind, TreasuryRedistribution,
+};
 use odra::types::{Address, Balance, BlockTime};
 use odra::{OdraType, UnwrapOrRevert};
 
@@ -24,6 +28,7 @@ pub struct Configuration {
     dao_configuration: DaoConfiguration,
     voting_configuration: VotingConfiguration,
     total_onboarded: Balance,
+    total_reputation: Balance,
     fiat_rate: Option<Balance>,
 }
 
@@ -54,6 +59,10 @@ impl Configuration {
         self.voting_configuration.set_contract_calls(contract_calls);
     }
 
+    pub fn set_voter_kind(&mut self, voter_kind: VoterKind) {
+        self.voting_configuration.set_voter_kind(voter_kind);
+    }
+
     /// Indicates if the creator ballot should be bounded at the voting ends.
     pub fn should_bind_ballot_for_successful_voting(&self) -> bool {
         self.voting_configuration
@@ -74,6 +83,7 @@ impl Configuration {
             dao_configuration,
             voting_configuration,
             total_onboarded,
+            total_reputation: Balance::zero(),
             fiat_rate: None,
         }
     }
@@ -82,6 +92,10 @@ impl Configuration {
         self.fiat_rate = fiat_rate;
     }
 
+    pub fn set_total_reputation(&mut self, total_reputation: Balance) {
+        self.total_reputation = total_reputation;
+    }
+
     /// Sets the flag `bind_ballot_for_successful_voting` and the address of the voter.
     pub fn bind_ballot_for_successful_voting(&mut self, address: Address) {
         self.voting_configuration.bind_ballot_for_successful_voting = true;
@@ -125,23 +139,100 @@ impl Configuration {
 
     /// Gets informal voting quorum.
     ///
-    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalQuorumRatio/InformalQuorumRatio
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalQuorumRatio/InformalQuorumRatio/SignalQuorumRatio
     /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
     pub fn informal_voting_quorum(&self) -> u32 {
-        let ratio = match self.voting_configuration.is_bid_escrow {
-            true => self.dao_configuration.bid_escrow_informal_quorum_ratio,
-            false => self.dao_configuration.informal_quorum_ratio,
-        };
+        let ratio = self.informal_quorum_ratio();
 
         per_mil_of_as_u32(ratio, self.total_onboarded())
             .unwrap_or_revert_with(Error::ArithmeticOverflow)
     }
 
+    /// Indicates if the quorum is measured by the stake instead of the number of voters.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) StakeWeightedQuorum
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn is_stake_weighted_quorum(&self) -> bool {
+        self.dao_configuration.stake_weighted_quorum
+    }
+
+    /// Gets formal voting stake quorum - a per mil of the total reputation supply.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowFormalQuorumRatio/FormalQuorumRatio
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn formal_voting_stake_quorum(&self) -> Balance {
+        let ratio = match self.voting_configuration.is_bid_escrow {
+            true => self.dao_configuration.bid_escrow_formal_quorum_ratio,
+            false => self.dao_configuration.formal_quorum_ratio,
+        };
+
+        per_mil_of(ratio, self.total_reputation()).unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
+    /// Gets informal voting stake quorum - a per mil of the total reputation supply.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalQuorumRatio/InformalQuorumRatio/SignalQuorumRatio
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn informal_voting_stake_quorum(&self) -> Balance {
+        let ratio = self.informal_quorum_ratio();
+
+        per_mil_of(ratio, self.total_reputation()).unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
+    /// Gets the minimal per mil share of the stake in favor needed to pass voting.
+    ///
+    /// Signal votings use the SimplePassThreshold, Governance Executor votings use the AdminPassThreshold.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) AdminPassThreshold/RepoPassThreshold/
+    /// ReputationPassThreshold/SlashingPassThreshold/KycPassThreshold/SimplePassThreshold/BidEscrowPassThreshold
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn pass_threshold(&self) -> Balance {
+        if self.voting_configuration.is_bid_escrow {
+            return self.dao_configuration.bid_escrow_pass_threshold;
+        }
+        match self.voting_configuration.voter_kind {
+            VoterKind::Admin | VoterKind::Governance => self.dao_configuration.admin_pass_threshold,
+            VoterKind::Repo => self.dao_configuration.repo_pass_threshold,
+            VoterKind::Reputation => self.dao_configuration.reputation_pass_threshold,
+            VoterKind::Slashing => self.dao_configuration.slashing_pass_threshold,
+            VoterKind::Kyc => self.dao_configuration.kyc_pass_threshold,
+            VoterKind::Simple | VoterKind::Signal => self.dao_configuration.simple_pass_threshold,
+        }
+    }
+
+    /// Indicates if the vote power is the square root of the stake.
+    ///
+    /// Applies to Simple and Repo votings only.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) SimpleQuadraticVoting/RepoQuadraticVoting
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn is_quadratic_voting(&self) -> bool {
+        if self.voting_configuration.is_bid_escrow {
+            return false;
+        }
+        match self.voting_configuration.voter_kind {
+            VoterKind::Simple => self.dao_configuration.simple_quadratic_voting,
+            VoterKind::Repo => self.dao_configuration.repo_quadratic_voting,
+            _ => false,
+        }
+    }
+
+    /// Converts the `stake` into the vote power recorded in the voting stats.
+    pub fn ballot_weight(&self, stake: Balance) -> Balance {
+        match self.is_quadratic_voting() {
+            true => isqrt(stake),
+            false => stake,
+        }
+    }
+
     /// Gets informal voting time.
     ///
-    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalVotingTime/InformalVotingTime
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalVotingTime/InformalVotingTime/SignalVotingTime
     /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
     pub fn informal_voting_time(&self) -> BlockTime {
+        if self.is_signal() {
+            return self.dao_configuration.signal_voting_time;
+        }
         match self.voting_configuration.is_bid_escrow {
             true => self.dao_configuration.bid_escrow_informal_voting_time,
             false => self.dao_configuration.informal_voting_time,
@@ -153,6 +244,9 @@ impl Configuration {
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowInformalVotingTime/InformalVotingTime
     /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
     pub fn formal_voting_time(&self) -> BlockTime {
+        if self.is_signal() {
+            return 0;
+        }
         match self.voting_configuration.is_bid_escrow {
             true => self.dao_configuration.bid_escrow_formal_voting_time,
             false => self.dao_configuration.formal_voting_time,
@@ -167,12 +261,66 @@ impl Configuration {
         self.dao_configuration.informal_stake_reputation
     }
 
+    /// Indicates if the formal voting ballots are secret.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) SecretFormalVoting
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn is_secret_formal_voting(&self) -> bool {
+        self.dao_configuration.secret_formal_voting
+    }
+
+    /// Gets the time for revealing the secret ballots. Zero if the formal voting is not secret.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) FormalVotingRevealTime
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn formal_voting_reveal_time(&self) -> BlockTime {
+        if self.is_secret_formal_voting() && !self.is_signal() {
+            self.dao_configuration.formal_voting_reveal_time
+        } else {
+            0
+        }
+    }
+
+    /// Gets the time between passing the formal voting and the earliest execution of the voted action.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) TimelockDelay
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn timelock_delay(&self) -> BlockTime {
+        self.dao_configuration.timelock_delay
+    }
+
+    /// Gets the time before the end of a voting phase in which a late change of the result extends the phase.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VotingExtensionWindow
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn voting_extension_window(&self) -> BlockTime {
+        self.dao_configuration.voting_extension_window
+    }
+
+    /// Gets the time a voting phase is extended by after a late change of the result.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VotingExtensionTime
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn voting_extension_time(&self) -> BlockTime {
+        self.dao_configuration.voting_extension_time
+    }
+
+    /// Gets the maximal total extension of a voting phase.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VotingExtensionCap
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn voting_extension_cap(&self) -> BlockTime {
+        self.dao_configuration.voting_extension_cap
+    }
+
     /// Gets the time between informal and formal voting.
     ///
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) TimeBetweenInformalAndFormalVoting
     /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
     pub fn time_between_informal_and_formal_voting(&self) -> BlockTime {
-        if self.voting_configuration.double_time_between_votings {
+        if self.is_signal() {
+            0
+        } else if self.voting_configuration.double_time_between_votings {
             self.dao_configuration
                 .time_between_informal_and_formal_voting
                 * 2
@@ -198,6 +346,122 @@ impl Configuration {
         self.dao_configuration.default_reputation_slash
     }
 
+    /// Gets the amount of CSPR paid to a keeper.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) KeeperCSPRReward
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn keeper_cspr_reward(&self) -> Balance {
+        self.dao_configuration.keeper_cspr_reward
+    }
+
+    /// Gets the amount of reputation minted for a keeper.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) KeeperReputationReward
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn keeper_reputation_reward(&self) -> Balance {
+        self.dao_configuration.keeper_reputation_reward
+    }
+
+    /// Gets the kind of the losing stake redistribution policy.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) RedistributionPolicy
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn redistribution_policy_kind(&self) -> RedistributionPolicyKind {
+        self.dao_configuration.redistribution_policy
+    }
+
+    /// Gets the address the losing stake is minted to under the [Treasury](RedistributionPolicyKind::Treasury) policy, if set.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) TreasuryAddress
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn treasury_address(&self) -> Option<Address> {
+        self.dao_configuration.treasury_address
+    }
+
+    /// Gets the policy of the losing stake redistribution at the end of a formal voting.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) RedistributionPolicy/RedistributionPartialLossRatio
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn redistribution_policy(&self) -> Box<dyn RedistributionPolicy> {
+        match self.dao_configuration.redistribution_policy {
+            RedistributionPolicyKind::ProRata => Box::new(ProRataRedistribution),
+            RedistributionPolicyKind::PartialLoss => Box::new(PartialLossRedistribution {
+                loss_ratio: self.dao_configuration.redistribution_partial_loss_ratio,
+            }),
+            RedistributionPolicyKind::Treasury => Box::new(TreasuryRedistribution {
+                treasury: self
+                    .treasury_address()
+                    .unwrap_or_revert_with(Error::TreasuryAddressNotSet),
+            }),
+            RedistributionPolicyKind::NoRedistribution => Box::new(NoRedistribution),
+        }
+    }
+
+    /// Gets the length of a participation epoch.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) ParticipationEpochLength
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn participation_epoch_length(&self) -> BlockTime {
+        self.dao_configuration.participation_epoch_length
+    }
+
+    /// Gets the number of Formal Votings a VA has to vote in during an epoch to get the participation reward.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) ParticipationMinFormalVotings
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn participation_min_formal_votings(&self) -> u64 {
+        self.dao_configuration.participation_min_formal_votings
+    }
+
+    /// Gets the amount of reputation minted for a VA who participated in an epoch.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) ParticipationReward
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn participation_reward(&self) -> Balance {
+        self.dao_configuration.participation_reward
+    }
+
+    /// Gets the address of the guardian, if set.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) GuardianAddress
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn guardian_address(&self) -> Option<Address> {
+        self.dao_configuration.guardian_address
+    }
+
+    /// Gets the minimal time between two votings canceled by the guardian.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) GuardianCancelInterval
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn guardian_cancel_interval(&self) -> BlockTime {
+        self.dao_configuration.guardian_cancel_interval
+    }
+
+    /// Gets the address of the voting contract whose passed votings can cancel queued actions, if set.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VetoVoterAddress
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn veto_voter_address(&self) -> Option<Address> {
+        self.dao_configuration.veto_voter_address
+    }
+
+    /// Gets the address of the Governance Executor whose allowlist limits the contract calls
+    /// of multi-option votings, if set.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) GovernanceExecutorAddress
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn governance_executor_address(&self) -> Option<Address> {
+        self.dao_configuration.governance_executor_address
+    }
+
+    /// Indicates if a voter has to attach a rationale when voting against in a Bid Escrow voting.
+    ///
+    /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) BidEscrowRationaleRequired
+    /// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+    pub fn bid_escrow_rationale_required(&self) -> bool {
+        self.dao_configuration.bid_escrow_rationale_required
+    }
+
     /// Gets the voting clearness delta.
     ///
     /// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) VotingClearnessDelta
@@ -268,6 +532,11 @@ impl Configuration {
         self.total_onboarded
     }
 
+    /// Returns the total reputation supply at the moment of voting creation.
+    pub fn total_reputation(&self) -> Balance {
+        self.total_reputation
+    }
+
     /// Returns a vec of calls to be performed once voting is finished.
     pub fn contract_calls(&self) -> &Vec<ContractCall> {
         &self.voting_configuration.contract_calls
@@ -288,6 +557,14 @@ impl Configuration {
         self.dao_configuration.voting_ids_address
     }
 
+    /// Indicates if the voting is a Signal Voting - a single phase, non-binding poll.
+    ///
+    /// A Signal Voting has no break and no formal phase, never stakes reputation and never performs any action.
+    pub fn is_signal(&self) -> bool {
+        !self.voting_configuration.is_bid_escrow
+            && self.voting_configuration.voter_kind == VoterKind::Signal
+    }
+
     /// Indicates if the stake of the voting creator should be converted to a ballot.
     pub fn should_cast_first_vote(&self) -> bool {
         !self.is_bid_escrow()
@@ -311,12 +588,34 @@ impl Configuration {
             .unwrap_or_revert_with(Error::ArithmeticOverflow)
     }
 
+    /// Applies the value of `UnrevealedBallotPenalty` variable to a given amount.
+    pub fn apply_unrevealed_ballot_penalty_to(&self, amount: Balance) -> Balance {
+        per_mil_of(amount, self.dao_configuration.unrevealed_ballot_penalty)
+            .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
     /// Applies the value of `DefaultReputationSlash` variable to a given amount.
     pub fn apply_default_reputation_slash_to(&self, amount: Balance) -> Balance {
         per_mil_of(amount, self.dao_configuration.default_reputation_slash)
             .unwrap_or_revert_with(Error::ArithmeticOverflow)
     }
 
+    fn informal_quorum_ratio(&self) -> Balance {
+        if self.is_signal() {
+            return self.dao_configuration.signal_quorum_ratio;
+        }
+        match self.voting_configuration.is_bid_escrow {
+            true => self.dao_configuration.bid_escrow_informal_quorum_ratio,
+            false => self.dao_configuration.informal_quorum_ratio,
+        }
+    }
+
+    /// Applies the value of `NonParticipationDecay` variable to a given amount.
+    pub fn apply_non_participation_decay_to(&self, amount: Balance) -> Balance {
+        per_mil_of(amount, self.dao_configuration.non_participation_decay)
+            .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
     /// Gets the current CSPR:Fiat rate.
     pub fn fiat_rate(&self) -> Option<Balance> {
         self.fiat_rate
@@ -11,6 +11,22 @@ pub struct VotingConfiguration {
     pub contract_calls: Vec<ContractCall>,
     pub only_va_can_create: bool,
     pub double_time_between_votings: bool,
+    pub voter_kind: VoterKind,
+}
+
+/// The kind of a voter contract that created voting.
+///
+/// Determines which pass threshold applies to a non-bid escrow voting.
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub enum VoterKind {
+    Admin,
+    Repo,
+    Reputation,
+    Slashing,
+    Kyc,
+    Simple,
+    Signal,
+    Governance,
 }
 
 impl VotingConfiguration {
@@ -37,6 +53,10 @@ impl VotingConfiguration {
         self.contract_calls = contract_calls;
     }
 
+    pub fn set_voter_kind(&mut self, voter_kind: VoterKind) {
+        self.voter_kind = voter_kind;
+    }
+
     pub fn should_bind_ballot_for_successful_voting(&self) -> bool {
         self.bind_ballot_for_successful_voting
     }
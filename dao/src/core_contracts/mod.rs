@@ -13,6 +13,7 @@ pub use reputation::token::{
     events::*, ReputationContract, ReputationContractComposer, ReputationContractDeployer,
     ReputationContractRef,
 };
+pub use reputation::Delegation;
 pub use va_nft::{VaNftContract, VaNftContractComposer, VaNftContractDeployer, VaNftContractRef};
 pub use variable_repository::{
     VariableRepositoryContract, VariableRepositoryContractComposer,
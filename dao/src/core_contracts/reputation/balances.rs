@@ -4,11 +4,11 @@ use std::{
 };
 
 use crate::modules::AccessControl;
-use crate::utils::Error;
+use crate::utils::{page_range, Error};
 use odra::{
     contract_env,
-    types::{event::OdraEvent, Address, Balance},
-    Iter, List, Mapping, UnwrapOrRevert, Variable,
+    types::{event::OdraEvent, Address, Balance, BlockTime},
+    Iter, List, Mapping, OdraType, UnwrapOrRevert, Variable,
 };
 
 use super::token::events::{Burn, Mint};
@@ -21,9 +21,13 @@ use super::token::events::{Burn, Mint};
 /// If an Address owns a "passive token", it means he's impacted the system (eg. have done a job).
 ///
 /// Having both types of balances allows for keeping track of the total value of the system.
+///
+/// Each balance change is recorded as a [Checkpoint], so the balance at any moment in the past
+/// can be queried using [`balance_of_at()`](Self::balance_of_at).
 #[odra::module(events = [Mint, Burn])]
 pub struct BalanceStorage {
     balances: Mapping<Address, Balance>,
+    checkpoints: Mapping<Address, List<Checkpoint>>,
     holders: List<Address>,
     total_supply: TotalSupply,
     access_control: AccessControl,
@@ -131,11 +135,63 @@ impl BalanceStorage {
         self.holders.iter()
     }
 
+    /// Returns the number of entries in the token holders list.
+    ///
+    /// An address is added to the list on each mint, so it may appear more than once.
+    pub fn holders_count(&self) -> u32 {
+        self.holders.len()
+    }
+
+    /// Returns at most `limit` entries of the token holders list, skipping the first `offset` ones.
+    pub fn holders_page(&self, offset: u32, limit: u32) -> Vec<Address> {
+        page_range(offset, limit, self.holders.len())
+            .filter_map(|index| self.holders.get(index))
+            .collect()
+    }
+
     /// Returns the current balance of the given account address.
     pub fn balance_of(&self, address: Address) -> Balance {
         self.balances.get(&address).unwrap_or_default()
     }
 
+    /// Returns the balance of the given account address at the given moment.
+    ///
+    /// If the balance has changed several times at the same block time, the last value is returned.
+    /// If the account had no balance at the given moment, returns zero.
+    ///
+    /// A balance that has not changed since the checkpoints were introduced has no checkpoints,
+    /// so the current balance is returned.
+    pub fn balance_of_at(&self, address: Address, timestamp: BlockTime) -> Balance {
+        let checkpoints = self.checkpoints.get_instance(&address);
+        if checkpoints.len() == 0 {
+            return self.balance_of(address);
+        }
+
+        // Binary search for the number of checkpoints recorded not later than `timestamp`.
+        let mut low = 0;
+        let mut high = checkpoints.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let is_before = checkpoints
+                .get(mid)
+                .map(|checkpoint| checkpoint.time <= timestamp)
+                .unwrap_or_default();
+            if is_before {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            return Balance::zero();
+        }
+        checkpoints
+            .get(low - 1)
+            .map(|checkpoint| checkpoint.balance)
+            .unwrap_or_default()
+    }
+
     /// Returns the total token supply.
     pub fn total_supply(&self) -> Balance {
         self.total_supply.value()
@@ -144,7 +200,34 @@ impl BalanceStorage {
 
 impl BalanceStorage {
     fn set_balance(&mut self, owner: &Address, new_balance: Balance) {
+        let old_balance = self.balance_of(*owner);
+        if self.checkpoints.get_instance(owner).len() == 0 && !old_balance.is_zero() {
+            // The balance was set before the checkpoints were introduced, keep it as the initial one.
+            self.checkpoints.get_instance(owner).push(Checkpoint {
+                time: 0,
+                balance: old_balance,
+            });
+        }
         self.balances.set(owner, new_balance);
+        self.write_checkpoint(owner, new_balance);
+    }
+
+    fn write_checkpoint(&mut self, owner: &Address, balance: Balance) {
+        let time = contract_env::get_block_time();
+        let checkpoint = Checkpoint { time, balance };
+        let mut checkpoints = self.checkpoints.get_instance(owner);
+
+        let len = checkpoints.len();
+        let is_same_time = len > 0
+            && checkpoints
+                .get(len - 1)
+                .map(|last| last.time == time)
+                .unwrap_or_default();
+        if is_same_time {
+            checkpoints.replace(len - 1, checkpoint);
+        } else {
+            checkpoints.push(checkpoint);
+        }
     }
 
     fn inc_balance(&mut self, owner: &Address, amount: Balance) {
@@ -166,6 +249,13 @@ impl BalanceStorage {
     }
 }
 
+/// A serializable data structure that represents the balance of an account since the given time.
+#[derive(OdraType)]
+pub struct Checkpoint {
+    pub time: BlockTime,
+    pub balance: Balance,
+}
+
 /// Wraps `total_supply` and some operations for convenience.
 #[odra::module]
 pub struct TotalSupply {
@@ -0,0 +1,130 @@
+use crate::utils::Error;
+use odra::{
+    contract_env,
+    types::{event::OdraEvent, Address, Balance},
+    Mapping, OdraType,
+};
+
+use super::token::events::{DelegationChanged, DelegationDisabled};
+
+/// A delegation of voting power.
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub struct Delegation {
+    /// The address that votes on behalf of the delegator.
+    pub delegate: Address,
+    /// The maximum amount of reputation the delegate can stake on behalf of the delegator in a single ballot.
+    pub max_stake: Balance,
+}
+
+/// A module that stores information about voting power delegations.
+///
+/// A delegator may hand his voting power to a delegate globally - in every voting contract -
+/// or in a single voting contract. A contract-specific delegation takes precedence over the global one.
+/// A delegator can also disable delegation in a single voting contract, regardless of the global delegation.
+#[odra::module(events = [DelegationChanged, DelegationDisabled])]
+pub struct DelegationStorage {
+    global_delegations: Mapping<Address, Option<Delegation>>,
+    contract_delegations: Mapping<(Address, Address), Option<Delegation>>,
+    disabled_delegations: Mapping<(Address, Address), bool>,
+}
+
+impl DelegationStorage {
+    /// Sets the `delegate` of the `delegator`, who can stake at most `max_stake` of the delegator's reputation
+    /// in a single ballot.
+    ///
+    /// If `voting_contract` is `None`, the delegation applies to all the voting contracts.
+    ///
+    /// # Errors
+    /// * [`CannotDelegateToSelf`](Error::CannotDelegateToSelf) if the delegator and the delegate are the same.
+    pub fn delegate(
+        &mut self,
+        delegator: Address,
+        delegate: Address,
+        voting_contract: Option<Address>,
+        max_stake: Balance,
+    ) {
+        if delegator == delegate {
+            contract_env::revert(Error::CannotDelegateToSelf)
+        }
+        let delegation = Delegation {
+            delegate,
+            max_stake,
+        };
+        self.set_delegation(delegator, Some(delegation), voting_contract);
+    }
+
+    /// Removes the delegation of the `delegator`.
+    ///
+    /// If `voting_contract` is `None`, the global delegation is removed. Otherwise, the contract-specific
+    /// delegation is removed and the global delegation applies in the given contract again.
+    pub fn undelegate(&mut self, delegator: Address, voting_contract: Option<Address>) {
+        self.set_delegation(delegator, None, voting_contract);
+    }
+
+    /// Disables delegation of the `delegator` in the given voting contract, so the global delegation
+    /// does not apply there. Any contract-specific delegation is removed.
+    ///
+    /// [`delegate()`](Self::delegate()) and [`undelegate()`](Self::undelegate()) in the contract enable it again.
+    pub fn disable_delegation(&mut self, delegator: Address, voting_contract: Address) {
+        self.contract_delegations
+            .set(&(delegator, voting_contract), None);
+        self.disabled_delegations
+            .set(&(delegator, voting_contract), true);
+
+        DelegationDisabled {
+            delegator,
+            voting_contract,
+        }
+        .emit();
+    }
+
+    /// Returns the delegation of the `delegator` in the given voting contract.
+    pub fn get_delegation(
+        &self,
+        delegator: Address,
+        voting_contract: Address,
+    ) -> Option<Delegation> {
+        let key = (delegator, voting_contract);
+        if let Some(delegation) = self.contract_delegations.get(&key).flatten() {
+            return Some(delegation);
+        }
+        if self.disabled_delegations.get_or_default(&key) {
+            return None;
+        }
+        self.global_delegations.get(&delegator).flatten()
+    }
+
+    /// Returns the delegate of the `delegator` in the given voting contract.
+    pub fn get_delegate(&self, delegator: Address, voting_contract: Address) -> Option<Address> {
+        self.get_delegation(delegator, voting_contract)
+            .map(|delegation| delegation.delegate)
+    }
+}
+
+impl DelegationStorage {
+    fn set_delegation(
+        &mut self,
+        delegator: Address,
+        delegation: Option<Delegation>,
+        voting_contract: Option<Address>,
+    ) {
+        match voting_contract {
+            Some(contract) => {
+                self.contract_delegations
+                    .set(&(delegator, contract), delegation);
+                self.disabled_delegations.set(&(delegator, contract), false);
+            }
+            None => self.global_delegations.set(&delegator, delegation),
+        }
+
+        DelegationChanged {
+            delegator,
+            delegate: delegation.map(|delegation| delegation.delegate),
+            max_stake: delegation
+                .map(|delegation| delegation.max_stake)
+                .unwrap_or_default(),
+            voting_contract,
+        }
+        .emit();
+    }
+}
@@ -4,9 +4,12 @@
 
 mod agg;
 mod balances;
+mod delegations;
 mod stakes;
 pub mod token;
 
+pub use delegations::Delegation;
+
 // #[cfg(feature = "test-support")]
 // pub use token::ReputationContractTest;
 // pub use token::{
@@ -6,7 +6,7 @@ use crate::utils::Error;
 use odra::{
     contract_env,
     types::{Address, Balance, OdraType},
-    List, Mapping, UnwrapOrRevert,
+    List, Mapping, UnwrapOrRevert, Variable,
 };
 
 use super::balances::BalanceStorage;
@@ -15,6 +15,7 @@ use super::balances::BalanceStorage;
 #[odra::module]
 pub struct StakesStorage {
     stake: Mapping<Address, Balance>,
+    total_stake: Variable<Balance>,
     access_control: AccessControl,
     reputation_storage: BalanceStorage,
 }
@@ -45,6 +46,11 @@ impl StakesStorage {
     pub fn get_stake(&self, address: Address) -> Balance {
         self.stake.get(&address).unwrap_or_default()
     }
+
+    /// Returns the total stake of all the accounts.
+    pub fn get_total_stake(&self) -> Balance {
+        self.total_stake.get_or_default()
+    }
 }
 
 impl StakesStorage {
@@ -70,6 +76,7 @@ impl StakesStorage {
     fn inc_stake(&mut self, account: Address, amount: Balance) {
         let new_value = self.get_stake(account) + amount;
         self.stake.set(&account, new_value);
+        self.total_stake.set(self.get_total_stake() + amount);
     }
 
     fn dec_stake(&mut self, account: Address, amount: Balance) {
@@ -78,6 +85,9 @@ impl StakesStorage {
             .checked_sub(amount)
             .unwrap_or_revert_with(Error::CannotUnstakeMoreThanStaked);
         self.stake.set(&account, new_value);
+        // The stakes made before the total was tracked are not included in it.
+        self.total_stake
+            .set(self.get_total_stake().saturating_sub(amount));
     }
 }
 
@@ -3,13 +3,14 @@ use std::collections::BTreeMap;
 use crate::modules::{access_control::AccessControlComposer, AccessControl};
 use odra::{
     contract_env,
-    types::{Address, Balance},
+    types::{Address, Balance, BlockTime},
     Instance,
 };
 
 use super::{
     agg::{AggregatedBalance, BalanceAggregates, BalanceAggregatesComposer},
     balances::{BalanceStorage, BalanceStorageComposer},
+    delegations::{Delegation, DelegationStorage, DelegationStorageComposer},
     stakes::{StakesStorage, StakesStorageComposer},
 };
 
@@ -20,6 +21,7 @@ pub struct ReputationContract {
     passive_reputation_storage: BalanceStorage,
     stakes_storage: StakesStorage,
     aggregates: BalanceAggregates,
+    delegations: DelegationStorage,
     access_control: AccessControl,
 }
 
@@ -40,12 +42,14 @@ impl Instance for ReputationContract {
         let aggregates = BalanceAggregatesComposer::new(namespace, "aggregates")
             .with_reputation_storage(&reputation_storage)
             .compose();
+        let delegations = DelegationStorageComposer::new(namespace, "delegations").compose();
 
         ReputationContractComposer::new(namespace, "reputation")
             .with_reputation_storage(&reputation_storage)
             .with_passive_reputation_storage(&passive_reputation_storage)
             .with_stakes_storage(&stakes_storage)
             .with_aggregates(&aggregates)
+            .with_delegations(&delegations)
             .with_access_control(&access_control)
             .compose()
     }
@@ -100,6 +104,12 @@ impl ReputationContract {
             pub fn total_supply(&self) -> Balance;
             /// Returns the current token balance of the given address.
             pub fn balance_of(&self, address: Address) -> Balance;
+            /// Returns the token balance of the given address at the given moment.
+            pub fn balance_of_at(&self, address: Address, timestamp: BlockTime) -> Balance;
+            /// Returns the number of entries in the token holders list. An address may appear more than once.
+            pub fn holders_count(&self) -> u32;
+            /// Returns at most `limit` entries of the token holders list, skipping the first `offset` ones.
+            pub fn holders_page(&self, offset: u32, limit: u32) -> Vec<Address>;
             /// Redistributes the reputation based on the voting summary
             pub fn bulk_mint_burn(&mut self, mints: BTreeMap<Address, Balance>, burns: BTreeMap<Address, Balance>);
             /// Burns all the tokens of the `owner`.
@@ -114,6 +124,8 @@ impl ReputationContract {
             pub fn bulk_unstake(&mut self, stakes: Vec<(Address, Balance)>);
 
             pub fn get_stake(&self, address: Address) -> Balance;
+
+            pub fn get_total_stake(&self) -> Balance;
         }
 
         to self.aggregates {
@@ -122,6 +134,16 @@ impl ReputationContract {
             /// Gets balances of the given account addresses.
             pub fn partial_balances(&self, addresses: Vec<Address>) -> AggregatedBalance;
         }
+
+        to self.delegations {
+            /// Returns the delegate of the `delegator` in the given voting contract.
+            ///
+            /// A contract-specific delegation takes precedence over the global one.
+            pub fn get_delegate(&self, delegator: Address, voting_contract: Address) -> Option<Address>;
+            /// Returns the delegation of the `delegator` in the given voting contract,
+            /// including the maximum stake the delegate can use.
+            pub fn get_delegation(&self, delegator: Address, voting_contract: Address) -> Option<Delegation>;
+        }
     }
 
     /// Constructor method.
@@ -165,6 +187,52 @@ impl ReputationContract {
     pub fn passive_balance_of(&self, address: Address) -> Balance {
         self.passive_reputation_storage.balance_of(address)
     }
+
+    /// Hands the caller's voting power to the `delegate`, who can stake at most `max_stake`
+    /// of the caller's reputation in a single ballot.
+    ///
+    /// If `voting_contract` is `None`, the delegation applies to all the voting contracts,
+    /// otherwise only to the given one.
+    ///
+    /// Both the caller and the delegate must be VAs when the delegate votes.
+    ///
+    /// # Errors
+    /// * [`CannotDelegateToSelf`](crate::utils::Error::CannotDelegateToSelf) if the caller
+    /// is the delegate.
+    ///
+    /// # Events
+    /// * [`DelegationChanged`](events::DelegationChanged).
+    pub fn delegate(
+        &mut self,
+        delegate: Address,
+        voting_contract: Option<Address>,
+        max_stake: Balance,
+    ) {
+        self.delegations
+            .delegate(contract_env::caller(), delegate, voting_contract, max_stake);
+    }
+
+    /// Removes the caller's delegation.
+    ///
+    /// If `voting_contract` is `None`, the global delegation is removed. Otherwise, the contract-specific
+    /// delegation is removed and the global delegation applies in the given contract again.
+    ///
+    /// # Events
+    /// * [`DelegationChanged`](events::DelegationChanged).
+    pub fn undelegate(&mut self, voting_contract: Option<Address>) {
+        self.delegations
+            .undelegate(contract_env::caller(), voting_contract);
+    }
+
+    /// Disables the caller's delegation in the given voting contract, so the global delegation
+    /// does not apply there.
+    ///
+    /// # Events
+    /// * [`DelegationDisabled`](events::DelegationDisabled).
+    pub fn disable_delegation(&mut self, voting_contract: Address) {
+        self.delegations
+            .disable_delegation(contract_env::caller(), voting_contract);
+    }
 }
 
 pub mod events {
@@ -203,4 +271,20 @@ pub mod events {
         pub amount: Balance,
         pub bid_id: BidId,
     }
+
+    /// Event emitted when a delegation has been set or removed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct DelegationChanged {
+        pub delegator: Address,
+        pub delegate: Option<Address>,
+        pub max_stake: Balance,
+        pub voting_contract: Option<Address>,
+    }
+
+    /// Event emitted when a delegation has been disabled in a voting contract.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct DelegationDisabled {
+        pub delegator: Address,
+        pub voting_contract: Address,
+    }
 }
@@ -18,11 +18,28 @@
 //! | BidEscrowFormalQuorumRatio         | 0.5           | 500          | float   | How many holders of the Reputation tokens (VA’s) are needed for a bid escrow formal vote quorum. For example, if 100 accounts hold tokens, the quorum would be 51 votes.                                                        |
 //! | InformalQuorumRatio                | 0.5           | 500          | float   | How many holders of the Reputation tokens (VA’s) are needed for a regular informal voting quorum.                                                                                                                               |
 //! | FormalQuorumRatio                  | 0.5           | 500          | float   | How many holders of the Reputation tokens (VA’s) are needed for a regular formal voting quorum.                                                                                                                                 |
+//! | StakeWeightedQuorum                | false         | false        | bool    | If true, the quorum ratios are applied to the total Reputation supply and compared to the staked reputation, instead of the number of VA’s.                                                                                     |
+//! | AdminPassThreshold                 | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a Admin voting.                                                                                                                                      |
+//! | RepoPassThreshold                  | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a Repo voting.                                                                                                                                       |
+//! | ReputationPassThreshold            | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a Reputation voting.                                                                                                                                 |
+//! | SlashingPassThreshold              | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a Slashing voting.                                                                                                                                   |
+//! | KycPassThreshold                   | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a KYC voting.                                                                                                                                        |
+//! | SimplePassThreshold                | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a Simple voting.                                                                                                                                     |
+//! | BidEscrowPassThreshold             | 0.5           | 500          | float   | The minimal share of the stake in favor (excluding abstain) needed to pass a Bid Escrow and Onboarding voting.                                                                                                                  |
+//! | SimpleQuadraticVoting              | false         | false        | bool    | If true, the vote power in a Simple voting is the square root of the staked reputation. The reputation is redistributed by the raw stake.                                                                                       |
+//! | RepoQuadraticVoting                | false         | false        | bool    | If true, the vote power in a Repo voting is the square root of the staked reputation. The reputation is redistributed by the raw stake.                                                                                         |
 //! | BidEscrowInformalVotingTime        | 5 days        | 432000       | seconds | Time for the informal part of the Bid Escrow voting                                                                                                                                                                             |
 //! | BidEscrowFormalVotingTime          | 5 days        | 432000       | seconds | Time for the formal part of the Bid Escrow voting                                                                                                                                                                               |
 //! | InformalVotingTime                 | 5 days        | 432000       | seconds | Time for the informal part of other voting                                                                                                                                                                                      |
 //! | FormalVotingTime                   | 5 days        | 432000       | seconds | Time for the formal part of other voting                                                                                                                                                                                        |
 //! | InformalStakeReputation            | true          | true         | bool    | Tells if the Informal Voting should stake the reputation or only simulate it.                                                                                                                                                   |
+//! | SecretFormalVoting                 | false         | false        | bool    | If true, Formal Voting ballots are committed as hashes of the choice, the stake and a salt, and revealed after the Formal Voting.                                                                                               |
+//! | FormalVotingRevealTime             | 2 days        | 172800       | seconds | Time for revealing the secret ballots after the Formal Voting.                                                                                                                                                                  |
+//! | UnrevealedBallotPenalty            | 1             | 1000         | float   | How much of the stake of a secret ballot that has not been revealed is burned. The rest is returned to the voter.                                                                                                               |
+//! | TimelockDelay                      | 0             | 0            | seconds | Time between passing a Formal Voting and the earliest execution of the voted action. If zero, the action is performed when the voting ends.                                                                                     |
+//! | VotingExtensionWindow              | 0             | 0            | seconds | If a ballot cast within this time before the end of a voting phase changes the leading side or moves the result within VotingClearnessDelta, the phase is extended. Zero disables the extension.                                |
+//! | VotingExtensionTime                | 1 day         | 86400        | seconds | How much time a voting phase is extended by after a late change of the result.                                                                                                                                                  |
+//! | VotingExtensionCap                 | 2 days        | 172800       | seconds | The maximal total extension of a voting phase.                                                                                                                                                                                  |
 //! | TimeBetweenInformalAndFormalVoting | 1 day         | 86400        | seconds | Time between Informal and Formal Voting                                                                                                                                                                                         |
 //! | VABidAcceptanceTimeout             | 2 days        | 172800       | seconds | How much time the bid wait for the acceptance. After this time, the bid can be cancelled                                                                                                                                        |
 //! | VACanBidOnPublicAuction            | false         | false        | bool    | Whether or not VA’s can take part in the Public Auction part of the Bidding process.                                                                                                                                            |
@@ -31,6 +48,22 @@
 //! | VotingClearnessDelta               | 8             | 8000         | int     | If the difference between 50/50 and result of the Informal Voting is bigger than the value, the time between votings should be doubled.                                                                                         |
 //! | VotingStartAfterJobWorkerSubmisson | 3 days        | 259200       | seconds | Time between the worker job submission and the internal voting start.                                                                                                                                                           |
 //! | BidEscrowPaymentRatio              | 0.1           | 100          | float   | How much CSPR is sent to GovernanceWallet after the Job is finished                                                                                                                                                             |
+//! | KeeperCSPRReward                   | 0             | 0            | motes   | How much CSPR is paid from the contract's keeper reward pool to the keeper who finishes an overdue voting or cancels an expired job or job offer.                                                                               |
+//! | KeeperReputationReward             | 0             | 0            | int     | How much reputation is minted for the keeper (if a VA) who finishes an overdue voting or cancels an expired job or job offer.                                                                                                   |
+//! | RedistributionPolicy               | ProRata       | ProRata      | enum    | What happens to the reputation staked on the losing side of a Formal Voting: ProRata - burned and minted to the winners pro rata, PartialLoss - only a part is redistributed, Treasury - minted to TreasuryAddress, NoRedistribution - returned to the losers. |
+//! | RedistributionPartialLossRatio     | 0.5           | 500          | float   | How much of each losing stake is redistributed under the PartialLoss redistribution policy. The rest is returned to the voter.                                                                                                  |
+//! | TreasuryAddress                    | None          | None         | address | An address the reputation staked on the losing side is minted to under the Treasury redistribution policy. Required by that policy.                                                                                             |
+//! | SignalVotingTime                   | 1 day         | 86400        | seconds | Time for voting in a Signal Voting. A Signal Voting has no Formal Voting, so it ends after this time.                                                                                                                           |
+//! | SignalQuorumRatio                  | 0.3           | 300          | float   | How many holders of the Reputation tokens (VA’s) are needed for a Signal Voting quorum.                                                                                                                                         |
+//! | ParticipationEpochLength           | 30 days       | 2592000      | seconds | The length of a participation epoch. Epochs are counted from the deployment of the Participation Contract.                                                                                                                      |
+//! | ParticipationMinFormalVotings      | 1             | 1            | int     | How many Formal Votings a VA has to vote in during an epoch to get the ParticipationReward.                                                                                                                                     |
+//! | ParticipationReward                | 0             | 0            | int     | How much reputation is minted for a VA who voted in at least ParticipationMinFormalVotings Formal Votings during an epoch.                                                                                                      |
+//! | NonParticipationDecay              | 0             | 0            | float   | How much of the unstaked reputation of a VA who voted in no Formal Voting during an epoch is burned.                                                                                                                            |
+//! | GuardianAddress                    | None          | None         | address | An address of a multisig wallet that can cancel any active voting in an emergency. Rotated by a Repo Voting.                                                                                                                   |
+//! | GuardianCancelInterval             | 7 days        | 604800       | seconds | The minimal time between two votings canceled by the guardian, shared by all the voting contracts.                                                                                                                               |
+//! | VetoVoterAddress                   | None          | None         | address | An address of a voting contract, eg. the Governance Executor, whose passed votings cancel actions queued in the timelock right away.                                                                                           |
+//! | GovernanceExecutorAddress          | None          | None         | address | An address of the Governance Executor. Only the calls on its allowlist can be proposed as multi-option voting options.                                                                                                         |
+//! | BidEscrowRationaleRequired         | false         | false        | bool    | If true, a ballot against in a Bid Escrow voting has to include a rationale.                                                                                                                                                     |
 //! | BidEscrowWalletAddress             |               |              | address | An address of a multisig wallet of the DAO.
 //!
 //!  [Repo Voting]: crate::voting_contracts::RepoVoterContract
@@ -0,0 +1,41 @@
+//! Dust ledger module.
+use odra::types::Balance;
+use odra::Variable;
+
+/// The Dust Ledger module.
+///
+/// Redistributions are split exactly, so nothing is lost to the rounding. However, if there is nobody
+/// to split an amount among (eg. all the winning ballots are unbound), the amount is recorded as dust.
+///
+/// The reputation dust is burned but not minted. The CSPR dust remains on the contract balance.
+#[odra::module]
+pub struct DustLedger {
+    reputation: Variable<Balance>,
+    cspr: Variable<Balance>,
+}
+
+impl DustLedger {
+    /// Adds the `amount` to the reputation dust.
+    pub fn record_reputation_dust(&mut self, amount: Balance) {
+        if !amount.is_zero() {
+            self.reputation.set(self.reputation_dust() + amount);
+        }
+    }
+
+    /// Adds the `amount` to the CSPR dust.
+    pub fn record_cspr_dust(&mut self, amount: Balance) {
+        if !amount.is_zero() {
+            self.cspr.set(self.cspr_dust() + amount);
+        }
+    }
+
+    /// Returns the total reputation that couldn't be redistributed.
+    pub fn reputation_dust(&self) -> Balance {
+        self.reputation.get_or_default()
+    }
+
+    /// Returns the total CSPR that couldn't be redistributed.
+    pub fn cspr_dust(&self) -> Balance {
+        self.cspr.get_or_default()
+    }
+}
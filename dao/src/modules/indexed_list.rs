@@ -0,0 +1,85 @@
+//! Indexed list module.
+use odra::{Mapping, Variable};
+
+use crate::utils::page_range;
+
+/// The Indexed List module.
+///
+/// Stores a set of ids in a [Mapping] from a position to an id, together with the reverse mapping
+/// from an id to its position.
+///
+/// Unlike a `Variable<Vec<u32>>`, which has to be read and written as a whole, inserting and removing
+/// an id touches a constant number of entries, and reading a page touches only the ids on that page,
+/// so the cost does not grow with the size of the list or the offset of the page.
+///
+/// The ids are kept in the insertion order until one is removed - the last id is then moved
+/// to the freed position. Because of that, the pages are not stable - if an id is removed while the list
+/// is being read page by page, the last id may be moved to an already read page and skipped.
+#[odra::module]
+pub struct IndexedList {
+    ids: Mapping<u32, u32>,
+    positions: Mapping<u32, Option<u32>>,
+    len: Variable<u32>,
+}
+
+impl IndexedList {
+    /// Appends the `id` to the end of the list. Does nothing if the `id` is already in the list.
+    pub fn push(&mut self, id: u32) {
+        if self.contains(id) {
+            return;
+        }
+
+        let len = self.len();
+        self.ids.set(&len, id);
+        self.positions.set(&id, Some(len));
+        self.len.set(len + 1);
+    }
+
+    /// Removes the `id` from the list. Does nothing if the `id` is not in the list.
+    ///
+    /// The last id of the list takes the position of the removed one.
+    pub fn remove(&mut self, id: u32) {
+        let position = match self.positions.get_or_default(&id) {
+            Some(position) => position,
+            None => return,
+        };
+
+        let last = self.len() - 1;
+        if position != last {
+            let last_id = self.ids.get_or_default(&last);
+            self.ids.set(&position, last_id);
+            self.positions.set(&last_id, Some(position));
+        }
+        self.positions.set(&id, None);
+        self.len.set(last);
+    }
+
+    /// Checks if the `id` is in the list.
+    pub fn contains(&self, id: u32) -> bool {
+        self.positions.get_or_default(&id).is_some()
+    }
+
+    /// Returns the number of ids in the list.
+    pub fn len(&self) -> u32 {
+        self.len.get_or_default()
+    }
+
+    /// Checks if the list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns at most `limit` ids, skipping the first `offset` ones.
+    ///
+    /// The page is not stable across removals, see [IndexedList].
+    pub fn page(&self, offset: u32, limit: u32) -> Vec<u32> {
+        page_range(offset, limit, self.len())
+            .map(|position| self.ids.get_or_default(&position))
+            .collect()
+    }
+
+    /// Returns all the ids.
+    pub fn to_vec(&self) -> Vec<u32> {
+        self.page(0, self.len())
+    }
+}
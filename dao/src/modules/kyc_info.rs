@@ -32,7 +32,7 @@ impl KycInfo {
 
     /// Indicates whether there is ongoing voting for the given `address`.
     pub fn exists_ongoing_voting(&self, address: &Address) -> bool {
-        self.votings.get(address).is_some()
+        self.votings.get_or_default(address).is_some()
     }
 
     /// Gets the address of the voting subject.
@@ -9,3 +9,9 @@ pub mod repository;
 pub use repository::{Record, Repository, RepositoryRef};
 pub mod kyc_info;
 pub mod refs;
+pub mod timelock;
+pub use timelock::Timelock;
+pub mod dust_ledger;
+pub use dust_ledger::DustLedger;
+pub mod indexed_list;
+pub use indexed_list::IndexedList;
@@ -2,6 +2,7 @@
 use crate::modules::repository::events::ValueUpdated;
 use crate::utils::consts;
 use crate::utils::Error::{ActivationTimeInPast, KeyValueStorageError};
+use crate::voting::reputation_redistribution::RedistributionPolicyKind;
 use odra::contract_env::{get_block_time, revert};
 use odra::types::event::OdraEvent;
 use odra::types::{Address, Balance, Bytes, OdraType as OdraTyped};
@@ -152,11 +153,28 @@ impl Default for RepositoryDefaults {
         items.push(consts::BID_ESCROW_FORMAL_QUORUM_RATIO, Balance::from(500));
         items.push(consts::INFORMAL_QUORUM_RATIO, Balance::from(500));
         items.push(consts::FORMAL_QUORUM_RATIO, Balance::from(500));
+        items.push(consts::STAKE_WEIGHTED_QUORUM, false);
+        items.push(consts::ADMIN_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::REPO_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::REPUTATION_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::SLASHING_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::KYC_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::SIMPLE_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::BID_ESCROW_PASS_THRESHOLD, Balance::from(500));
+        items.push(consts::SIMPLE_QUADRATIC_VOTING, false);
+        items.push(consts::REPO_QUADRATIC_VOTING, false);
         items.push(consts::BID_ESCROW_INFORMAL_VOTING_TIME, 432000000u64);
         items.push(consts::BID_ESCROW_FORMAL_VOTING_TIME, 432000000u64);
         items.push(consts::INFORMAL_VOTING_TIME, 432000000u64);
         items.push(consts::FORMAL_VOTING_TIME, 432000000u64);
         items.push(consts::INFORMAL_STAKE_REPUTATION, true);
+        items.push(consts::SECRET_FORMAL_VOTING, false);
+        items.push(consts::FORMAL_VOTING_REVEAL_TIME, 172800000u64);
+        items.push(consts::UNREVEALED_BALLOT_PENALTY, Balance::from(1000));
+        items.push(consts::TIMELOCK_DELAY, 0u64);
+        items.push(consts::VOTING_EXTENSION_WINDOW, 0u64);
+        items.push(consts::VOTING_EXTENSION_TIME, 86400000u64);
+        items.push(consts::VOTING_EXTENSION_CAP, 172800000u64);
         items.push(consts::TIME_BETWEEN_INFORMAL_AND_FORMAL_VOTING, 86400000u64);
         items.push(consts::VA_BID_ACCEPTANCE_TIMEOUT, 172800000u64);
         items.push(consts::VA_CAN_BID_ON_PUBLIC_AUCTION, false);
@@ -168,6 +186,28 @@ impl Default for RepositoryDefaults {
             259200000u64,
         );
         items.push(consts::BID_ESCROW_PAYMENT_RATIO, Balance::from(100));
+        items.push(consts::KEEPER_CSPR_REWARD, Balance::zero());
+        items.push(consts::KEEPER_REPUTATION_REWARD, Balance::zero());
+        items.push(
+            consts::REDISTRIBUTION_POLICY,
+            RedistributionPolicyKind::ProRata,
+        );
+        items.push(
+            consts::REDISTRIBUTION_PARTIAL_LOSS_RATIO,
+            Balance::from(500),
+        );
+        items.push(consts::TREASURY_ADDRESS, Option::<Address>::None);
+        items.push(consts::SIGNAL_VOTING_TIME, 86400000u64);
+        items.push(consts::SIGNAL_QUORUM_RATIO, Balance::from(300));
+        items.push(consts::PARTICIPATION_EPOCH_LENGTH, 2592000000u64);
+        items.push(consts::PARTICIPATION_MIN_FORMAL_VOTINGS, 1u64);
+        items.push(consts::PARTICIPATION_REWARD, Balance::zero());
+        items.push(consts::NON_PARTICIPATION_DECAY, Balance::zero());
+        items.push(consts::GUARDIAN_ADDRESS, Option::<Address>::None);
+        items.push(consts::GUARDIAN_CANCEL_INTERVAL, 604800000u64);
+        items.push(consts::VETO_VOTER_ADDRESS, Option::<Address>::None);
+        items.push(consts::GOVERNANCE_EXECUTOR_ADDRESS, Option::<Address>::None);
+        items.push(consts::BID_ESCROW_RATIONALE_REQUIRED, false);
         items
     }
 }
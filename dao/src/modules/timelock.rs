@@ -0,0 +1,196 @@
+//! Timelock module.
+use crate::configuration::Configuration;
+use crate::modules::timelock::events::{ActionCancelled, ActionExecuted, ActionQueued};
+use crate::utils::{consts, ContractCall, Error};
+use crate::voting::ids::is_voting_contract;
+use crate::voting::types::VotingId;
+use odra::contract_env::{caller, get_block_time, revert, self_address};
+use odra::types::event::OdraEvent;
+use odra::types::BlockTime;
+use odra::{Mapping, OdraType, UnwrapOrRevert};
+
+/// A status of a [QueuedAction].
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub enum QueuedActionStatus {
+    /// The action waits for the execution.
+    Queued,
+    /// The action has been executed.
+    Executed,
+    /// The action has been cancelled and will never be executed.
+    Cancelled,
+}
+
+/// A serializable data structure that represents a list of contract calls waiting for the execution.
+#[derive(OdraType)]
+pub struct QueuedAction {
+    pub voting_id: VotingId,
+    pub contract_calls: Vec<ContractCall>,
+    pub eta: BlockTime,
+    pub status: QueuedActionStatus,
+}
+
+/// The Timelock module.
+///
+/// Holds actions of passed votings for a period of time, so they can be reviewed and cancelled
+/// before they take effect.
+#[odra::module(events = [ActionQueued, ActionExecuted, ActionCancelled])]
+pub struct Timelock {
+    actions: Mapping<VotingId, Option<QueuedAction>>,
+}
+
+impl Timelock {
+    /// Performs the `contract_calls` of a passed voting or, if the `TimelockDelay` is set,
+    /// queues them for the later execution.
+    ///
+    /// A veto is performed right away, so it can take effect before the vetoed action is executed.
+    /// A voting is a veto if it is held by the `VetoVoterAddress` contract and all of its calls
+    /// are calls to the `cancel_action` entry point of voting contracts.
+    pub fn perform_or_queue(
+        &mut self,
+        voting_id: VotingId,
+        contract_calls: &[ContractCall],
+        configuration: &Configuration,
+    ) {
+        let timelock_delay = configuration.timelock_delay();
+        if timelock_delay == 0 || Self::is_veto(contract_calls, configuration) {
+            for contract_call in contract_calls {
+                contract_call.call();
+            }
+        } else if !contract_calls.is_empty() {
+            self.queue(
+                voting_id,
+                contract_calls.to_vec(),
+                get_block_time() + timelock_delay,
+            );
+        }
+    }
+
+    /// Queues the `contract_calls` of a passed voting. The calls can be executed after `eta`.
+    ///
+    /// # Events
+    /// * [`ActionQueued`]
+    pub fn queue(
+        &mut self,
+        voting_id: VotingId,
+        contract_calls: Vec<ContractCall>,
+        eta: BlockTime,
+    ) {
+        self.actions.set(
+            &voting_id,
+            Some(QueuedAction {
+                voting_id,
+                contract_calls,
+                eta,
+                status: QueuedActionStatus::Queued,
+            }),
+        );
+
+        ActionQueued { voting_id, eta }.emit();
+    }
+
+    /// Performs the queued contract calls. Can be called by anyone once the eta has passed.
+    ///
+    /// # Events
+    /// * [`ActionExecuted`]
+    ///
+    /// # Errors
+    /// * [`QueuedActionNotFound`](Error::QueuedActionNotFound) if there is no action queued for the voting.
+    /// * [`QueuedActionNotPending`](Error::QueuedActionNotPending) if the action has been executed or cancelled.
+    /// * [`TimelockNotExpired`](Error::TimelockNotExpired) if the eta has not passed yet.
+    pub fn execute(&mut self, voting_id: VotingId) {
+        let mut action = self.get_pending_action_or_revert(voting_id);
+        if get_block_time() < action.eta {
+            revert(Error::TimelockNotExpired);
+        }
+
+        action.status = QueuedActionStatus::Executed;
+        self.actions.set(&voting_id, Some(action.clone()));
+
+        for contract_call in action.contract_calls.iter() {
+            contract_call.call();
+        }
+
+        ActionExecuted {
+            voting_id,
+            executor: caller(),
+        }
+        .emit();
+    }
+
+    /// Cancels the queued action, so it will never be executed.
+    ///
+    /// # Events
+    /// * [`ActionCancelled`]
+    ///
+    /// # Errors
+    /// * [`QueuedActionNotFound`](Error::QueuedActionNotFound) if there is no action queued for the voting.
+    /// * [`QueuedActionNotPending`](Error::QueuedActionNotPending) if the action has been executed or cancelled.
+    pub fn cancel(&mut self, voting_id: VotingId) {
+        let mut action = self.get_pending_action_or_revert(voting_id);
+        action.status = QueuedActionStatus::Cancelled;
+        self.actions.set(&voting_id, Some(action));
+
+        ActionCancelled {
+            voting_id,
+            canceller: caller(),
+        }
+        .emit();
+    }
+
+    /// Returns the action queued by the voting with the given id.
+    pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction> {
+        self.actions.get_or_default(&voting_id)
+    }
+
+    fn get_pending_action_or_revert(&self, voting_id: VotingId) -> QueuedAction {
+        let action = self
+            .get_queued_action(voting_id)
+            .unwrap_or_revert_with(Error::QueuedActionNotFound);
+        if action.status != QueuedActionStatus::Queued {
+            revert(Error::QueuedActionNotPending);
+        }
+        action
+    }
+
+    fn is_veto(contract_calls: &[ContractCall], configuration: &Configuration) -> bool {
+        if configuration.veto_voter_address() != Some(self_address()) {
+            return false;
+        }
+        !contract_calls.is_empty()
+            && contract_calls.iter().all(|contract_call| {
+                contract_call.entry_point() == consts::EP_CANCEL_ACTION
+                    && is_voting_contract(
+                        configuration.voting_ids_address(),
+                        *contract_call.address(),
+                    )
+            })
+    }
+}
+
+pub mod events {
+    //! Events definitions.
+    use crate::voting::types::VotingId;
+    use odra::types::{Address, BlockTime};
+    use odra::Event;
+
+    /// Event emitted when the action of a passed voting has been queued.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct ActionQueued {
+        pub voting_id: VotingId,
+        pub eta: BlockTime,
+    }
+
+    /// Event emitted when the queued action has been executed.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct ActionExecuted {
+        pub voting_id: VotingId,
+        pub executor: Address,
+    }
+
+    /// Event emitted when the queued action has been cancelled.
+    #[derive(Debug, PartialEq, Eq, Event)]
+    pub struct ActionCancelled {
+        pub voting_id: VotingId,
+        pub canceller: Address,
+    }
+}
@@ -8,7 +8,7 @@ use crate::utils::types::DocumentHash;
 use crate::utils::{withdraw, Error};
 use crate::voting::ballot::Choice;
 use crate::voting::cspr_redistribution::{
-    redistribute_cspr_to_all_vas, redistribute_to_governance,
+    redistribute_cspr_to_all_vas, redistribute_to_governance, split_by_balances,
 };
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
@@ -17,11 +17,12 @@ use crate::voting::voting_engine::voting_state_machine::{
 };
 use crate::voting::voting_engine::VotingEngine;
 use odra::contract_env::{caller, revert};
-use odra::types::{Address, Balance};
-use odra::{Mapping, UnwrapOrRevert};
+use odra::types::{event::OdraEvent, Address, Balance, Bytes};
+use odra::{Event, Mapping, UnwrapOrRevert};
+use std::collections::BTreeMap;
 
 /// Onboarding voting module.
-#[odra::module]
+#[odra::module(events = [OnboardingRequestCanceled])]
 pub struct Onboarding {
     requests: Mapping<VotingId, Request>,
     configurations: Mapping<VotingId, Configuration>,
@@ -90,9 +91,83 @@ impl Onboarding {
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     ) {
         self.voting
-            .vote(caller(), voting_id, voting_type, choice, stake);
+            .vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        self.voting.vote_batch(caller(), ballots);
+    }
+
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        self.voting
+            .vote_as_delegate(caller(), voting_id, voting_type, choice, stake, delegators);
+    }
+
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting
+            .change_vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting.withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
+        self.voting
+            .commit_vote(caller(), voting_id, commitment, stake);
+    }
+
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
+    }
+
+    /// Cancels the voting on behalf of the guardian. See [VotingEngine::guardian_cancel_voting()].
+    ///
+    /// The CSPR deposit is returned to the requestor.
+    ///
+    /// # Events
+    /// * [`VotingCanceled`](crate::voting::voting_engine::events::VotingCanceled) with the `reason`.
+    /// * [`OnboardingRequestCanceled`]
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        let request = self
+            .requests
+            .get(&voting_id)
+            .unwrap_or_revert_with(Error::OnboardingRequestNotFound);
+        self.voting
+            .guardian_cancel_voting(caller(), voting_id, reason.clone());
+        self.on_quorum_not_reached(&request);
+
+        OnboardingRequestCanceled {
+            voting_id,
+            requestor: request.creator(),
+            cspr_deposit: request.cspr_deposit(),
+            reason,
+        }
+        .emit();
     }
 
     fn get_user_voting(&self, address: &Address) -> Option<VotingStateMachine> {
@@ -130,6 +205,7 @@ impl Onboarding {
             Choice::InFavor,
             stake,
             true,
+            None,
             &mut voting,
             &configuration,
         );
@@ -247,20 +323,31 @@ impl Onboarding {
 
     fn mint_reputation_for_voters(&mut self, voting: &VotingStateMachine, amount: Balance) {
         let voting_id = voting.voting_id();
+        let mut stakes = BTreeMap::new();
 
         for i in 0..self.voting.voters_count(voting_id, VotingType::Formal) {
             let ballot = self.voting.get_ballot_at(voting_id, VotingType::Formal, i);
-            if ballot.unbound {
+            if ballot.unbound || ballot.canceled {
                 continue;
             }
-            let to_transfer = ballot.stake * amount / voting.total_bound_stake();
-            self.refs.reputation_token().mint(ballot.voter, to_transfer);
+            for (owner, stake) in ballot.stakes() {
+                *stakes.entry(owner).or_insert_with(Balance::zero) += stake;
+            }
+        }
+
+        let (shares, dust) = split_by_balances(amount, &stakes);
+        self.voting.record_reputation_dust(dust);
+        for (owner, to_transfer) in shares {
+            if !to_transfer.is_zero() {
+                self.refs.reputation_token().mint(owner, to_transfer);
+            }
         }
     }
 
     fn redistribute_cspr(&mut self, configuration: &Configuration, amount: Balance) {
         let to_redistribute = redistribute_to_governance(amount, configuration);
-        redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+        let dust = redistribute_cspr_to_all_vas(to_redistribute, &self.refs);
+        self.voting.record_cspr_dust(dust);
     }
 
     fn burn_requestor_reputation(&self, request: &Request) {
@@ -269,3 +356,12 @@ impl Onboarding {
             .burn(request.creator(), request.rep_stake());
     }
 }
+
+/// Event emitted when the guardian has canceled the voting over an onboarding request.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct OnboardingRequestCanceled {
+    pub voting_id: VotingId,
+    pub requestor: Address,
+    pub cspr_deposit: Balance,
+    pub reason: DocumentHash,
+}
@@ -3,17 +3,19 @@ use crate::utils::Error;
 use macros::Rule;
 use odra::types::Address;
 
-/// Makes sure the job poster is the one who picks the [`Job Offer`](crate::bid_escrow::job_offer::JobOffer).
-/// May return [Error::OnlyJobPosterCanPickABid].
+/// Makes sure the job poster is the one who cancels the [`Job Offer`](crate::bid_escrow::job_offer::JobOffer),
+/// unless the offer has expired - then anyone can cancel it.
+/// May return [Error::CannotCancelNotOwnedJobOffer].
 #[derive(Rule)]
 pub struct HasPermissionsToCancelJobOffer {
     pub canceller: Address,
     pub job_offer_poster: Address,
+    pub is_expired: bool,
 }
 
 impl Validation for HasPermissionsToCancelJobOffer {
     fn validate(&self) -> Result<(), Error> {
-        if self.canceller != self.job_offer_poster {
+        if !self.is_expired && self.canceller != self.job_offer_poster {
             return Err(Error::CannotCancelNotOwnedJobOffer);
         }
         Ok(())
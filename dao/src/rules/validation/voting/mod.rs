@@ -1,10 +1,12 @@
 //! Groups voting related validations.
 mod after_formal_voting;
 mod can_create_voting;
+mod reveal_in_time;
 mod vote_in_time;
 mod voting_not_completed;
 
 pub use after_formal_voting::AfterFormalVoting;
 pub use can_create_voting::CanCreateVoting;
+pub use reveal_in_time::RevealInTime;
 pub use vote_in_time::VoteInTime;
 pub use voting_not_completed::VotingNotCompleted;
@@ -0,0 +1,27 @@
+use crate::configuration::Configuration;
+use crate::rules::validation::VotingValidation;
+use crate::utils::Error;
+use crate::voting::voting_engine::voting_state_machine::{VotingState, VotingStateMachine};
+use macros::Rule;
+use odra::types::BlockTime;
+
+/// Verifies if a secret ballot is revealed in the reveal phase. May return [Error::RevealNotInProgress].
+#[derive(Rule)]
+pub struct RevealInTime {
+    block_time: BlockTime,
+}
+
+impl VotingValidation for RevealInTime {
+    fn validate(
+        &self,
+        voting_state_machine: &VotingStateMachine,
+        configuration: &Configuration,
+    ) -> Result<(), Error> {
+        if voting_state_machine.state_in_time(self.block_time, configuration) == VotingState::Reveal
+        {
+            return Ok(());
+        }
+
+        Err(Error::RevealNotInProgress)
+    }
+}
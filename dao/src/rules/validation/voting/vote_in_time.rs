@@ -6,7 +6,8 @@ use macros::Rule;
 use odra::types::BlockTime;
 
 /// Verifies if a ballot is cast in the right time. May return [Error::InformalVotingNotStarted],
-/// [Error::VotingDuringTimeBetweenVotingsNotAllowed] or [Error::VoteOnCompletedVotingNotAllowed].
+/// [Error::VotingDuringTimeBetweenVotingsNotAllowed], [Error::VotingDuringRevealNotAllowed]
+/// or [Error::VoteOnCompletedVotingNotAllowed].
 #[derive(Rule)]
 pub struct VoteInTime {
     block_time: BlockTime,
@@ -21,6 +22,7 @@ impl VotingValidation for VoteInTime {
         match voting_state_machine.state_in_time(self.block_time, configuration) {
             VotingState::Created => Err(Error::InformalVotingNotStarted),
             VotingState::BetweenVotings => Err(Error::VotingDuringTimeBetweenVotingsNotAllowed),
+            VotingState::Reveal => Err(Error::VotingDuringRevealNotAllowed),
             VotingState::Finished => Err(Error::VoteOnCompletedVotingNotAllowed),
             _ => Ok(()),
         }
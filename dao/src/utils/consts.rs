@@ -23,6 +23,12 @@ pub const EP_SET_OR_UPDATE: &str = "set_or_update";
 pub const EP_GET: &str = "get";
 /// The name of `update_at` entry point.
 pub const EP_UPDATE_AT: &str = "update_at";
+/// The name of `cancel_action` entry point.
+pub const EP_CANCEL_ACTION: &str = "cancel_action";
+/// The name of `allow_call` entry point.
+pub const EP_ALLOW_CALL: &str = "allow_call";
+/// The name of `disallow_call` entry point.
+pub const EP_DISALLOW_CALL: &str = "disallow_call";
 
 /// The name of `recipient` entry point argument.
 pub const ARG_RECIPIENT: &str = "recipient";
@@ -32,6 +38,8 @@ pub const ARG_AMOUNT: &str = "amount";
 pub const ARG_OWNER: &str = "owner";
 /// The name of `address` entry point argument.
 pub const ARG_ADDRESS: &str = "address";
+/// The name of `entry_point` entry point argument.
+pub const ARG_ENTRY_POINT: &str = "entry_point";
 /// The name of `key` entry point argument.
 pub const ARG_KEY: &str = "key";
 /// The name of `value` entry point argument.
@@ -80,6 +88,40 @@ pub const INFORMAL_VOTING_TIME: &str = "InformalVotingTime";
 pub const FORMAL_QUORUM_RATIO: &str = "FormalQuorumRatio";
 /// How many `VA’s` are needed for an informal voting quorum.
 pub const INFORMAL_QUORUM_RATIO: &str = "InformalQuorumRatio";
+/// Tells if the quorum is reached by the stake share of the total reputation supply instead of the number of `VA’s`.
+pub const STAKE_WEIGHTED_QUORUM: &str = "StakeWeightedQuorum";
+/// The minimal share of the in favor stake needed to pass an `Admin` voting.
+pub const ADMIN_PASS_THRESHOLD: &str = "AdminPassThreshold";
+/// The minimal share of the in favor stake needed to pass a `Repo` voting.
+pub const REPO_PASS_THRESHOLD: &str = "RepoPassThreshold";
+/// The minimal share of the in favor stake needed to pass a `Reputation` voting.
+pub const REPUTATION_PASS_THRESHOLD: &str = "ReputationPassThreshold";
+/// The minimal share of the in favor stake needed to pass a `Slashing` voting.
+pub const SLASHING_PASS_THRESHOLD: &str = "SlashingPassThreshold";
+/// The minimal share of the in favor stake needed to pass a `KYC` voting.
+pub const KYC_PASS_THRESHOLD: &str = "KycPassThreshold";
+/// The minimal share of the in favor stake needed to pass a `Simple` voting.
+pub const SIMPLE_PASS_THRESHOLD: &str = "SimplePassThreshold";
+/// The minimal share of the in favor stake needed to pass a `Bid Escrow` voting.
+pub const BID_ESCROW_PASS_THRESHOLD: &str = "BidEscrowPassThreshold";
+/// Tells if the vote power in Simple votings is the square root of the stake.
+pub const SIMPLE_QUADRATIC_VOTING: &str = "SimpleQuadraticVoting";
+/// Tells if the vote power in Repo votings is the square root of the stake.
+pub const REPO_QUADRATIC_VOTING: &str = "RepoQuadraticVoting";
+/// Tells if the Formal Voting ballots are secret - committed as hashes and revealed after the voting time.
+pub const SECRET_FORMAL_VOTING: &str = "SecretFormalVoting";
+/// Time for revealing the secret ballots after the Formal Voting.
+pub const FORMAL_VOTING_REVEAL_TIME: &str = "FormalVotingRevealTime";
+/// How much of the stake of a secret ballot that has not been revealed is burned.
+pub const UNREVEALED_BALLOT_PENALTY: &str = "UnrevealedBallotPenalty";
+/// If a ballot cast within this time before the end of a voting phase changes the leading side or makes the result close, the phase is extended.
+pub const VOTING_EXTENSION_WINDOW: &str = "VotingExtensionWindow";
+/// How much time a voting phase is extended by after a late change of the result.
+pub const VOTING_EXTENSION_TIME: &str = "VotingExtensionTime";
+/// The maximal total extension of a voting phase.
+pub const VOTING_EXTENSION_CAP: &str = "VotingExtensionCap";
+/// Time between passing a Formal Voting and the earliest execution of the voted action.
+pub const TIMELOCK_DELAY: &str = "TimelockDelay";
 /// Tells if the Informal Voting should stake the reputation or only simulate it.
 pub const INFORMAL_STAKE_REPUTATION: &str = "InformalStakeReputation";
 /// Determines if the Payment for the Job should be distributed between all VA’s or only to those who voted.
@@ -100,6 +142,38 @@ pub const VOTING_CLEARNESS_DELTA: &str = "VotingClearnessDelta";
 pub const VOTING_START_AFTER_JOB_WORKER_SUBMISSION: &str = "VotingStartAfterJobSubmission";
 /// How much reputation of an Internal Worker is slashed after not completing a Job.
 pub const DEFAULT_REPUTATION_SLASH: &str = "DefaultReputationSlash";
+/// How much CSPR is paid to the keeper who finishes an overdue voting or cancels an expired job or job offer.
+pub const KEEPER_CSPR_REWARD: &str = "KeeperCSPRReward";
+/// How much reputation is minted for the keeper who finishes an overdue voting or cancels an expired job or job offer.
+pub const KEEPER_REPUTATION_REWARD: &str = "KeeperReputationReward";
+/// Determines what happens to the reputation staked on the losing side of a Formal Voting.
+pub const REDISTRIBUTION_POLICY: &str = "RedistributionPolicy";
+/// How much of the losing stake is redistributed under the partial loss redistribution policy.
+pub const REDISTRIBUTION_PARTIAL_LOSS_RATIO: &str = "RedistributionPartialLossRatio";
+/// An address the losing stake is minted to under the treasury redistribution policy.
+pub const TREASURY_ADDRESS: &str = "TreasuryAddress";
+/// Time for voting in a Signal Voting.
+pub const SIGNAL_VOTING_TIME: &str = "SignalVotingTime";
+/// Quorum ratio for a Signal Voting.
+pub const SIGNAL_QUORUM_RATIO: &str = "SignalQuorumRatio";
+/// The length of a participation epoch.
+pub const PARTICIPATION_EPOCH_LENGTH: &str = "ParticipationEpochLength";
+/// How many Formal Votings a VA has to vote in during an epoch to get the participation reward.
+pub const PARTICIPATION_MIN_FORMAL_VOTINGS: &str = "ParticipationMinFormalVotings";
+/// How much reputation is minted for a VA who voted in enough Formal Votings during an epoch.
+pub const PARTICIPATION_REWARD: &str = "ParticipationReward";
+/// How much reputation of a VA who voted in no Formal Voting during an epoch is burned.
+pub const NON_PARTICIPATION_DECAY: &str = "NonParticipationDecay";
+/// An address of a multisig wallet that can cancel votings in an emergency.
+pub const GUARDIAN_ADDRESS: &str = "GuardianAddress";
+/// The minimal time between two votings canceled by the guardian.
+pub const GUARDIAN_CANCEL_INTERVAL: &str = "GuardianCancelInterval";
+/// An address of a voting contract whose passed votings can cancel actions queued in the timelock.
+pub const VETO_VOTER_ADDRESS: &str = "VetoVoterAddress";
+/// An address of the Governance Executor whose allowlist limits the contract calls of multi-option votings.
+pub const GOVERNANCE_EXECUTOR_ADDRESS: &str = "GovernanceExecutorAddress";
+/// Whether a voter has to attach a rationale when voting against in a Bid Escrow voting.
+pub const BID_ESCROW_RATIONALE_REQUIRED: &str = "BidEscrowRationaleRequired";
 /// An address of a contract that generates a next voting id.
 pub const VOTING_IDS_ADDRESS: &str = "VotingIdsAddress";
 
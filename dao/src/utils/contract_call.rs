@@ -2,7 +2,7 @@ use odra::types::{Address, Balance, CallArgs};
 use odra::{call_contract, OdraType};
 
 /// A serializable data structure that represent a contract call.
-#[derive(OdraType)]
+#[derive(OdraType, PartialEq, Eq, Debug)]
 pub struct ContractCall {
     pub address: Address,
     pub entry_point: String,
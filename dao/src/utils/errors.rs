@@ -1,4 +1,5 @@
 use odra::execution_error;
+use odra::types::ExecutionError;
 execution_error! {
     pub enum Error {
         InsufficientAllowance => 401,
@@ -43,6 +44,35 @@ execution_error! {
         FormalVotingNotCompleted => 2110,
         InformalVotingNotStarted => 2111,
         ConfigurationNotFound => 2112,
+        CannotDelegateToSelf => 2113,
+        NotADelegate => 2114,
+        CannotChangeCreatorsBallot => 2115,
+        SecretBallotRequired => 2116,
+        SecretVotingDisabled => 2117,
+        VotingDuringRevealNotAllowed => 2118,
+        RevealNotInProgress => 2119,
+        InvalidBallotCommitment => 2120,
+        SealedBallotDoesNotExist => 2121,
+        TooFewVotingOptions => 2122,
+        InvalidRanking => 2123,
+        QueuedActionNotFound => 2124,
+        TimelockNotExpired => 2125,
+        QueuedActionNotPending => 2126,
+        InvalidSignature => 2127,
+        SignatureExpired => 2128,
+        InvalidNonce => 2129,
+        StakeExceedsSnapshotBalance => 2130,
+        EpochNotEnded => 2131,
+        CallNotAllowed => 2132,
+        NotGuardian => 2133,
+        GuardianCancelTooEarly => 2134,
+        NotVotingCreator => 2135,
+        VotingHasOtherBallots => 2136,
+        VoteBatchTooLarge => 2137,
+        CannotCancelAction => 2138,
+        DelegatedStakeExceedsLimit => 2139,
+        NotGovernanceVoting => 2140,
+        TreasuryAddressNotSet => 2141,
         VaOnboardedAlready => 2201,
         OnboardingAlreadyInProgress => 2202,
         NotOnboarded => 2203,
@@ -102,6 +132,7 @@ execution_error! {
         JobCannotBeYetCanceled => 4032,
         FiatRateNotSet => 4033,
         OnlyJobPosterCanModifyJobOffer => 4034,
+        RationaleRequired => 4035,
 
         // Reputation Token Errors.
         CannotStakeTwice => 4500,
@@ -122,3 +153,63 @@ execution_error! {
         ExpectedFormalToBeOn => 7001,
     }
 }
+
+/// The first error code reserved for failed vote batch items. See [vote_batch_error()].
+///
+/// The codes from `VOTE_BATCH_ERROR_OFFSET` to `VOTE_BATCH_ERROR_OFFSET + 9999` must not be used by [Error].
+pub const VOTE_BATCH_ERROR_OFFSET: u16 = 10000;
+
+/// The maximal number of items in a vote batch, so the item index fits in the reserved error codes.
+pub const MAX_VOTE_BATCH_SIZE: usize = 100;
+
+/// The errors a vote batch item can fail with. The position in the list encodes the error in [vote_batch_error()].
+const VOTE_BATCH_ITEM_ERRORS: [Error; 17] = [
+    Error::Unknown,
+    Error::VotingDoesNotExist,
+    Error::ConfigurationNotFound,
+    Error::SecretBallotRequired,
+    Error::VotingWithGivenTypeNotInProgress,
+    Error::InformalVotingNotStarted,
+    Error::VotingDuringTimeBetweenVotingsNotAllowed,
+    Error::VotingDuringRevealNotAllowed,
+    Error::VoteOnCompletedVotingNotAllowed,
+    Error::CannotVoteTwice,
+    Error::ZeroStake,
+    Error::InsufficientBalance,
+    Error::StakeExceedsSnapshotBalance,
+    Error::SubjectOfSlashing,
+    Error::VotingIdNotFound,
+    Error::CannotVoteOnOwnJob,
+    Error::RationaleRequired,
+];
+
+/// Encodes the index of a failed vote batch item and the reason it failed in a single error code:
+/// `VOTE_BATCH_ERROR_OFFSET + index * 100 + reason`, where the reason is the position of the `error`
+/// in the list of errors a ballot can fail with. An error outside of the list is encoded as [Error::Unknown].
+///
+/// See [decode_vote_batch_error()].
+pub fn vote_batch_error(index: usize, error: Error) -> ExecutionError {
+    if index >= MAX_VOTE_BATCH_SIZE {
+        return Error::VoteBatchTooLarge.into();
+    }
+    let reason = VOTE_BATCH_ITEM_ERRORS
+        .iter()
+        .position(|item_error| *item_error == error)
+        .unwrap_or_default();
+    let code = VOTE_BATCH_ERROR_OFFSET + (index * 100 + reason) as u16;
+    ExecutionError::new(code, "VoteBatchItemFailed")
+}
+
+/// Decodes the index of the failed vote batch item and the reason it failed from an error code.
+///
+/// Returns `None` if the code is not a [vote_batch_error()] code.
+pub fn decode_vote_batch_error(code: u16) -> Option<(usize, Error)> {
+    let encoded = code.checked_sub(VOTE_BATCH_ERROR_OFFSET)? as usize;
+    let (index, reason) = (encoded / 100, encoded % 100);
+    if index >= MAX_VOTE_BATCH_SIZE {
+        return None;
+    }
+    VOTE_BATCH_ITEM_ERRORS
+        .get(reason)
+        .map(|error| (index, *error))
+}
@@ -1,6 +1,7 @@
 //! Calculation utility functions.
 use crate::utils::Error;
 use odra::types::Balance;
+use std::ops::Range;
 
 const RATIO_DIVISOR: u32 = 1000;
 
@@ -31,6 +32,93 @@ pub fn per_mil_of_as_u32<T: Into<Balance>, R: Into<Balance>>(
     per_mil_of(number, other).and_then(|n| u32::try_from(n).map_err(|_| Error::ArithmeticOverflow))
 }
 
+/// Checks if `part` is at least the given per mil `ratio` of `whole`.
+///
+/// The comparison is exact - no rounding is applied.
+pub fn is_per_mil_reached<T: Into<Balance>, W: Into<Balance>, R: Into<Balance>>(
+    part: T,
+    whole: W,
+    ratio: R,
+) -> Result<bool, Error> {
+    let part = part
+        .into()
+        .checked_mul(Balance::from(RATIO_DIVISOR))
+        .ok_or(Error::ArithmeticOverflow)?;
+    let required = whole
+        .into()
+        .checked_mul(ratio.into())
+        .ok_or(Error::ArithmeticOverflow)?;
+    Ok(part >= required)
+}
+
+/// Returns the integer square root of the `value` - the largest number whose square does not exceed the `value`.
+pub fn isqrt(value: Balance) -> Balance {
+    let two = Balance::from(2);
+    if value < two {
+        return value;
+    }
+    // Newton's method, starting from ceil(value / 2) to avoid an overflow.
+    let mut x = value;
+    let mut y = value / two + value % two;
+    while y < x {
+        x = y;
+        y = (x + value / x) / two;
+    }
+    x
+}
+
+/// Splits the `amount` proportionally to the `weights` using the largest remainder method.
+///
+/// Each share is first rounded down. The units lost by the rounding are then given one by one
+/// to the shares with the largest remainders (ties are resolved in favor of the earlier weights),
+/// so the shares sum up to the `amount` exactly.
+///
+/// If the total weight is zero, the amount can't be split and all the shares are zero.
+///
+/// Returns [`ArithmeticOverflow`](Error::ArithmeticOverflow) if the total weight or the product
+/// of the `amount` and a weight overflows.
+pub fn split_by_largest_remainder(
+    amount: Balance,
+    weights: &[Balance],
+) -> Result<Vec<Balance>, Error> {
+    let total_weight = weights
+        .iter()
+        .try_fold(Balance::zero(), |acc, weight| acc.checked_add(*weight))
+        .ok_or(Error::ArithmeticOverflow)?;
+    if total_weight.is_zero() {
+        return Ok(vec![Balance::zero(); weights.len()]);
+    }
+
+    let mut shares = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+    let mut distributed = Balance::zero();
+    for (index, weight) in weights.iter().enumerate() {
+        let product = amount
+            .checked_mul(*weight)
+            .ok_or(Error::ArithmeticOverflow)?;
+        let share = product / total_weight;
+        distributed += share;
+        shares.push(share);
+        remainders.push((product % total_weight, index));
+    }
+
+    // The rounding loses less than one unit per share.
+    let left = (amount - distributed).as_usize();
+    remainders.sort_by(|(a, a_index), (b, b_index)| b.cmp(a).then(a_index.cmp(b_index)));
+    for (_, index) in remainders.into_iter().take(left) {
+        shares[index] += Balance::one();
+    }
+    Ok(shares)
+}
+
+/// Returns the range of indices of a page of `limit` items starting at `offset`
+/// in a collection of `len` items. The range is empty if the offset exceeds the collection.
+pub fn page_range(offset: u32, limit: u32, len: u32) -> Range<u32> {
+    let start = offset.min(len);
+    let end = offset.saturating_add(limit).min(len);
+    start..end
+}
+
 fn per_mil_of_ordered(number: Balance, other: Balance) -> Result<Balance, Error> {
     match number.checked_mul(other) {
         // if the result is lower than Balance::MAX, divide by the ratio.
@@ -55,4 +143,99 @@ mod tests {
         assert_eq!(per_mil_of(6, 333).unwrap(), 1.into());
         assert_eq!(per_mil_of(10, 750).unwrap(), 7.into());
     }
+
+    #[test]
+    fn test_is_per_mil_reached() {
+        assert!(is_per_mil_reached(1, 2, 500).unwrap());
+        assert!(!is_per_mil_reached(1, 3, 500).unwrap());
+        assert!(is_per_mil_reached(2, 3, 666).unwrap());
+        assert!(!is_per_mil_reached(2, 3, 667).unwrap());
+        assert!(is_per_mil_reached(0, 0, 500).unwrap());
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0.into()), 0.into());
+        assert_eq!(isqrt(1.into()), 1.into());
+        assert_eq!(isqrt(2.into()), 1.into());
+        assert_eq!(isqrt(3.into()), 1.into());
+        assert_eq!(isqrt(4.into()), 2.into());
+        assert_eq!(isqrt(99.into()), 9.into());
+        assert_eq!(isqrt(100.into()), 10.into());
+        assert_eq!(isqrt(1000.into()), 31.into());
+        let root = isqrt(Balance::MAX);
+        assert!(root.checked_mul(root).is_some());
+        let next = root + Balance::from(1);
+        assert!(next.checked_mul(next).is_none());
+    }
+
+    #[test]
+    fn test_page_range() {
+        assert_eq!(page_range(0, 10, 5), 0..5);
+        assert_eq!(page_range(2, 2, 5), 2..4);
+        assert_eq!(page_range(4, 10, 5), 4..5);
+        assert_eq!(page_range(5, 10, 5), 5..5);
+        assert_eq!(page_range(7, 10, 5), 5..5);
+        assert_eq!(page_range(1, 0, 5), 1..1);
+        assert_eq!(page_range(1, u32::MAX, 5), 1..5);
+    }
+
+    #[test]
+    fn test_split_by_largest_remainder() {
+        let split = |amount: u32, weights: &[u32]| {
+            let weights: Vec<Balance> = weights.iter().map(|w| Balance::from(*w)).collect();
+            split_by_largest_remainder(amount.into(), &weights).unwrap()
+        };
+        let balances =
+            |values: &[u32]| -> Vec<Balance> { values.iter().map(|v| Balance::from(*v)).collect() };
+
+        assert_eq!(split(250, &[100, 500, 200]), balances(&[31, 156, 63]));
+        assert_eq!(split(10, &[1, 1, 1]), balances(&[4, 3, 3]));
+        assert_eq!(split(2, &[1, 1, 1]), balances(&[1, 1, 0]));
+        assert_eq!(split(100, &[0, 3, 0]), balances(&[0, 100, 0]));
+        assert_eq!(split(100, &[0, 0]), balances(&[0, 0]));
+        assert_eq!(split(100, &[]), balances(&[]));
+        assert_eq!(
+            split_by_largest_remainder(Balance::MAX, &[Balance::one(), Balance::from(2)]),
+            Err(Error::ArithmeticOverflow)
+        );
+        assert_eq!(
+            split_by_largest_remainder(Balance::one(), &[Balance::MAX, Balance::one()]),
+            Err(Error::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_split_by_largest_remainder_sums_up_to_amount() {
+        // A simple linear congruential generator keeps the test deterministic.
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |max: u64| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (seed >> 33) % max
+        };
+
+        for _ in 0..1000 {
+            let amount = Balance::from(next(1_000_000_000_000));
+            let weights: Vec<Balance> = (0..next(20) + 1)
+                .map(|_| Balance::from(next(1_000_000_000)))
+                .collect();
+            let total_weight = weights.iter().fold(Balance::zero(), |acc, w| acc + *w);
+            let shares = split_by_largest_remainder(amount, &weights).unwrap();
+
+            assert_eq!(shares.len(), weights.len());
+            let total_share = shares.iter().fold(Balance::zero(), |acc, s| acc + *s);
+            if total_weight.is_zero() {
+                assert!(total_share.is_zero());
+                continue;
+            }
+            assert_eq!(total_share, amount);
+            // Each share differs from the exact proportional share by less than one unit.
+            for (share, weight) in shares.iter().zip(weights.iter()) {
+                let floor = amount * *weight / total_weight;
+                assert!(*share == floor || *share == floor + Balance::one());
+            }
+        }
+    }
 }
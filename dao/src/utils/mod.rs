@@ -6,6 +6,8 @@ mod math;
 mod transfer;
 pub mod types;
 pub use contract_call::ContractCall;
-pub use errors::Error;
+pub use errors::{
+    decode_vote_batch_error, vote_batch_error, Error, MAX_VOTE_BATCH_SIZE, VOTE_BATCH_ERROR_OFFSET,
+};
 pub use math::*;
 pub use transfer::withdraw;
@@ -2,9 +2,17 @@
 //!
 //! There is one continuous indexation of votes in the system.
 //! Each new voting gets a unique across-the-system id generated by the contract.
+//!
+//! The contract also records when the guardian last canceled a voting, so the `GuardianCancelInterval`
+//! applies across all the voting contracts.
 
 use crate::modules::AccessControl;
-use odra::{contract_env, types::Address, Sequence};
+use crate::utils::Error;
+use odra::{
+    contract_env,
+    types::{Address, BlockTime},
+    Sequence, Variable,
+};
 
 /// Dao Ids contract manages voting ids in the system.
 /// Only a whitelisted account is eligible to generate ids.
@@ -14,6 +22,7 @@ use odra::{contract_env, types::Address, Sequence};
 pub struct DaoIdsContract {
     access_control: AccessControl,
     voting_id_seq: Sequence<u32>,
+    last_guardian_cancel: Variable<BlockTime>,
 }
 
 #[odra::module]
@@ -62,4 +71,26 @@ impl DaoIdsContract {
         self.access_control.ensure_whitelisted();
         self.voting_id_seq.next_value()
     }
+
+    /// Records that the guardian has canceled a voting.
+    ///
+    /// # Errors
+    /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the caller is not whitelisted.
+    /// * [`GuardianCancelTooEarly`](crate::utils::Error::GuardianCancelTooEarly) if the previous cancel
+    /// was recorded less than `interval` ago.
+    pub fn record_guardian_cancel(&mut self, interval: BlockTime) {
+        self.access_control.ensure_whitelisted();
+        let now = contract_env::get_block_time();
+        if let Some(last_cancel) = self.last_guardian_cancel.get() {
+            if now < last_cancel + interval {
+                contract_env::revert(Error::GuardianCancelTooEarly);
+            }
+        }
+        self.last_guardian_cancel.set(now);
+    }
+
+    /// Returns the time the guardian last canceled a voting.
+    pub fn get_last_guardian_cancel(&self) -> Option<BlockTime> {
+        self.last_guardian_cancel.get()
+    }
 }
@@ -1,5 +1,6 @@
 //! Module with utilities contracts used by DAO.
 mod ids;
+mod participation;
 mod rate_provider;
 
 pub use rate_provider::{
@@ -8,3 +9,8 @@ pub use rate_provider::{
 };
 
 pub use ids::{DaoIdsContract, DaoIdsContractComposer, DaoIdsContractDeployer, DaoIdsContractRef};
+
+pub use participation::{
+    EpochSettled, ParticipationContract, ParticipationContractComposer,
+    ParticipationContractDeployer, ParticipationContractRef, ParticipationSettled,
+};
@@ -0,0 +1,311 @@
+//! Contains Participation Contract definition and related abstractions.
+//!
+//! The time is divided into epochs of `ParticipationEpochLength`, counted from the contract deployment.
+//! Once an epoch has ended, anyone can settle it. The ballots stored in the registered voting contracts
+//! are used to determine how many Formal Votings each VA has voted in during the epoch:
+//! * a VA who voted in at least `ParticipationMinFormalVotings` votings gets `ParticipationReward` reputation minted,
+//! * a VA who voted in none loses `NonParticipationDecay` of their unstaked reputation.
+//!
+//! A ballot cast by a delegate counts as participation of the delegate and of each delegator
+//! whose reputation is staked in the ballot.
+//!
+//! Epochs are settled one by one, in order. Each epoch starts where the previous one ended, and its length
+//! is the value of `ParticipationEpochLength` when its settlement starts, so changing the length never makes
+//! epochs overlap or leaves gaps between them.
+//!
+//! The participation is collected and the reputation holders are settled in batches, so the cost of a single call
+//! does not grow with the number of votings or holders. At first, at most `limit` finished votings of each voting
+//! contract are counted per call. Each contract is read from where the previous epoch stopped, so no voting is
+//! counted twice. Once all the votings are counted, the holders are settled.
+//!
+//! The decay is applied to the balance at the end of the epoch, or to the current balance if it is lower.
+//! Stakes have no history, so the reputation staked at the time of the settlement is not decayed.
+use crate::configuration::ConfigurationBuilder;
+use crate::modules::refs::ContractRefs;
+use crate::modules::AccessControl;
+use crate::utils::Error;
+use odra::contract_env::{caller, get_block_time, revert};
+use odra::types::event::OdraEvent;
+use odra::types::{Address, Balance, BlockTime};
+use odra::{Event, Mapping, OdraType, UnwrapOrRevert, Variable};
+use std::collections::BTreeMap;
+
+/// Participation Contract rewards VAs for voting in Formal Votings and decays the reputation
+/// of the VAs who did not vote at all.
+///
+/// The contract needs to be whitelisted in the [Reputation Token](crate::core_contracts::ReputationContract).
+///
+/// For details see [the module docs](self).
+#[odra::module(events = [ParticipationSettled, EpochSettled])]
+pub struct ParticipationContract {
+    refs: ContractRefs,
+    access_control: AccessControl,
+    voting_contracts: Variable<Vec<Address>>,
+    settled_until: Variable<BlockTime>,
+    settled_epochs: Variable<u64>,
+    pending_epoch: Variable<Option<PendingEpoch>>,
+    settled_holders: Mapping<(u64, Address), bool>,
+    participation: Mapping<(u64, Address), u32>,
+    voting_cursors: Mapping<Address, u32>,
+}
+
+#[odra::module]
+impl ParticipationContract {
+    delegate! {
+        to self.access_control {
+            /// Changes the ownership of the contract. Transfers the ownership to the `owner`.
+            /// Only the current owner is permitted to call this method.
+            ///
+            /// [`Read more`](AccessControl::change_ownership())
+            pub fn change_ownership(&mut self, owner: Address);
+            /// Adds a new address to the whitelist.
+            ///
+            /// [`Read more`](AccessControl::add_to_whitelist())
+            pub fn add_to_whitelist(&mut self, address: Address);
+            /// Remove address from the whitelist.
+            ///
+            /// [`Read more`](AccessControl::remove_from_whitelist())
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            /// Checks whether the given address is added to the whitelist.
+            ///
+            /// [`Read more`](AccessControl::is_whitelisted()).
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            /// Returns the address of the current owner.
+            ///
+            /// [`Read more`](AccessControl::get_owner()).
+            pub fn get_owner(&self) -> Option<Address>;
+        }
+
+        to self.refs {
+            pub fn variable_repository_address(&self) -> Address;
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    /// Initializes a contract. Sets the deployer as the owner and starts the first epoch.
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.settled_until.set(get_block_time());
+        self.access_control.init(caller());
+    }
+
+    /// Sets the contracts whose Formal Votings count towards the participation.
+    ///
+    /// Each contract has to expose `get_formal_participation` of its [VotingEngine](crate::voting::voting_engine::VotingEngine).
+    /// The contracts must not be changed while an epoch is being settled.
+    ///
+    /// # Errors
+    /// Throws [`NotWhitelisted`](Error::NotWhitelisted) if the caller is not whitelisted.
+    pub fn update_voting_contracts(&mut self, voting_contracts: Vec<Address>) {
+        self.access_control.ensure_whitelisted();
+        self.voting_contracts.set(voting_contracts);
+    }
+
+    /// Returns the registered voting contracts.
+    pub fn get_voting_contracts(&self) -> Vec<Address> {
+        self.voting_contracts.get_or_default()
+    }
+
+    /// Returns the number of the epochs settled so far - the number of the next epoch to settle.
+    pub fn get_settled_epochs(&self) -> u64 {
+        self.settled_epochs.get_or_default()
+    }
+
+    /// Returns the end of the last settled epoch - the start of the next epoch to settle.
+    pub fn get_settled_until(&self) -> BlockTime {
+        self.settled_until.get_or_default()
+    }
+
+    /// Settles at most `limit` entries of the reputation holders list for the next epoch, if it has ended.
+    ///
+    /// Before the holders are settled, the participation is collected - at most `limit` finished votings
+    /// of each voting contract are counted per call.
+    ///
+    /// Mints the participation reward to the VAs who voted in enough Formal Votings and burns the decayed
+    /// reputation of the VAs who voted in none. Once all the holders are settled, the epoch is settled.
+    ///
+    /// # Events
+    /// * [`ParticipationSettled`]
+    /// * [`EpochSettled`] when the last holder of the epoch is settled.
+    ///
+    /// # Errors
+    /// * [`EpochNotEnded`](Error::EpochNotEnded) if the epoch to settle has not ended yet.
+    pub fn settle_epoch(&mut self, limit: u32) {
+        let configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build();
+
+        let epoch = self.settled_epochs.get_or_default();
+        let mut pending = self
+            .pending_epoch
+            .get_or_default()
+            .unwrap_or_else(|| self.start_epoch(configuration.participation_epoch_length()));
+        if !self.collect_participation(epoch, &mut pending, limit) {
+            self.pending_epoch.set(Some(pending));
+            return;
+        }
+
+        let mut reputation = self.refs.reputation_token();
+        let va_token = self.refs.va_token();
+        let min_formal_votings = configuration.participation_min_formal_votings();
+        let reward = configuration.participation_reward();
+        let mut mints = BTreeMap::new();
+        let mut burns = BTreeMap::new();
+        let holders = reputation.holders_page(pending.next_holder, limit);
+        pending.next_holder += holders.len() as u32;
+        for address in holders {
+            // An address is listed once per mint, settle it only once.
+            if self.settled_holders.get_or_default(&(epoch, address)) {
+                continue;
+            }
+            self.settled_holders.set(&(epoch, address), true);
+            if va_token.balance_of(address).is_zero() {
+                continue;
+            }
+            let votings = self.participation.get_or_default(&(epoch, address));
+            if votings == 0 {
+                let balance = reputation
+                    .balance_of_at(address, pending.to)
+                    .min(reputation.balance_of(address));
+                let unstaked = balance.saturating_sub(reputation.get_stake(address));
+                let decay = configuration.apply_non_participation_decay_to(unstaked);
+                if !decay.is_zero() {
+                    burns.insert(address, decay);
+                }
+            } else if u64::from(votings) >= min_formal_votings && !reward.is_zero() {
+                mints.insert(address, reward);
+            }
+        }
+
+        if !mints.is_empty() || !burns.is_empty() {
+            reputation.bulk_mint_burn(mints.clone(), burns.clone());
+        }
+        ParticipationSettled {
+            epoch,
+            mints,
+            burns,
+        }
+        .emit();
+
+        if pending.next_holder < reputation.holders_count() {
+            self.pending_epoch.set(Some(pending));
+            return;
+        }
+
+        let from = self.settled_until.get_or_default();
+        self.settled_until.set(pending.to);
+        self.settled_epochs.set(epoch + 1);
+        self.pending_epoch.set(None);
+
+        EpochSettled {
+            epoch,
+            from,
+            to: pending.to,
+        }
+        .emit();
+    }
+}
+
+impl ParticipationContract {
+    fn start_epoch(&self, epoch_length: BlockTime) -> PendingEpoch {
+        let from = self
+            .settled_until
+            .get()
+            .unwrap_or_revert_with(Error::VariableValueNotSet);
+        let to = from + epoch_length;
+        if get_block_time() < to {
+            revert(Error::EpochNotEnded);
+        }
+
+        PendingEpoch {
+            to,
+            next_contract: 0,
+            next_holder: 0,
+        }
+    }
+
+    /// Counts at most `limit` finished votings of each voting contract not collected yet.
+    ///
+    /// Returns true if the votings of all the contracts finished before the end of the epoch are counted.
+    fn collect_participation(
+        &mut self,
+        epoch: u64,
+        pending: &mut PendingEpoch,
+        limit: u32,
+    ) -> bool {
+        let from = self.settled_until.get_or_default();
+        let voting_contracts = self.voting_contracts.get_or_default();
+        while let Some(contract) = voting_contracts.get(pending.next_contract as usize) {
+            let mut cursor = self.voting_cursors.get_or_default(contract);
+            let votings =
+                ParticipationSourceRef::at(contract).get_formal_participation(cursor, limit);
+            let mut collected = votings.len() < limit as usize;
+            for (finished_at, participants) in votings {
+                // The voting counts towards the next epoch.
+                if finished_at >= pending.to {
+                    collected = true;
+                    break;
+                }
+                cursor += 1;
+                // The voting finished before the first epoch started.
+                if finished_at < from {
+                    continue;
+                }
+                for participant in participants {
+                    let key = (epoch, participant);
+                    self.participation
+                        .set(&key, self.participation.get_or_default(&key) + 1);
+                }
+            }
+            self.voting_cursors.set(contract, cursor);
+
+            if !collected {
+                return false;
+            }
+            pending.next_contract += 1;
+        }
+        true
+    }
+}
+
+#[odra::external_contract]
+trait ParticipationSource {
+    fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
+}
+
+/// An epoch whose settlement has started.
+#[derive(OdraType)]
+pub struct PendingEpoch {
+    /// The end of the epoch.
+    pub to: BlockTime,
+    /// The position of the next voting contract to collect the participation from.
+    pub next_contract: u32,
+    /// The position of the next entry of the reputation holders list to settle.
+    pub next_holder: u32,
+}
+
+/// Event emitted when a batch of reputation holders has been settled.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct ParticipationSettled {
+    pub epoch: u64,
+    pub mints: BTreeMap<Address, Balance>,
+    pub burns: BTreeMap<Address, Balance>,
+}
+
+/// Event emitted when a participation epoch has been settled.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct EpochSettled {
+    pub epoch: u64,
+    pub from: BlockTime,
+    pub to: BlockTime,
+}
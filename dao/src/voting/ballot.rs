@@ -1,8 +1,10 @@
 //! Ballot struct and its related types.
+use crate::utils::types::DocumentHash;
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::voting_state_machine::VotingType;
-use odra::types::{Address, Balance};
-use odra::OdraType;
+use odra::contract_env;
+use odra::types::{Address, Balance, BlockTime, Bytes, OdraType as OdraTyped, PublicKey};
+use odra::{OdraType, UnwrapOrRevert};
 
 /// Represents user's vote.
 #[derive(OdraType)]
@@ -15,12 +17,20 @@ pub struct Ballot {
     pub voting_type: VotingType,
     /// Selected option.
     pub choice: Choice,
-    /// Vote power.
+    /// Vote power, including the reputation staked on behalf of the delegators.
     pub stake: Balance,
+    /// Effective vote power recorded in the voting stats.
+    ///
+    /// Equals the `stake` unless [quadratic voting](crate::configuration::Configuration::is_quadratic_voting()) is enabled.
+    pub weight: Balance,
     /// Indicates if the vote counts in the total voting stake.
     pub unbound: bool,
     /// Indicates if it reverts the previous ballot casted by the voter.
     pub canceled: bool,
+    /// Reputation staked by the voter on behalf of his delegators.
+    pub delegated: Vec<(Address, Balance)>,
+    /// An optional document explaining the choice.
+    pub rationale: Option<DocumentHash>,
 }
 
 impl Ballot {
@@ -39,31 +49,185 @@ impl Ballot {
             voting_type,
             choice,
             stake,
+            weight: stake,
             unbound,
             canceled,
+            delegated: Vec::new(),
+            rationale: None,
+        }
+    }
+
+    /// Returns the reputation staked by the voter himself.
+    pub fn own_stake(&self) -> Balance {
+        self.delegated
+            .iter()
+            .fold(self.stake, |acc, (_, stake)| acc - *stake)
+    }
+
+    /// Splits the ballot stake into the stakes of the voter and his delegators.
+    pub fn stakes(&self) -> Vec<(Address, Balance)> {
+        let mut stakes = Vec::new();
+        let own_stake = self.own_stake();
+        if !own_stake.is_zero() {
+            stakes.push((self.voter, own_stake));
         }
+        stakes.extend(self.delegated.iter().cloned());
+        stakes
     }
 }
 
-/// Choice enum, can be converted to bool using `is_in_favor()`
+/// Represents user's vote in a multi-option voting.
+#[derive(OdraType)]
+pub struct RankedBallot {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type.
+    pub voting_type: VotingType,
+    /// Indexes of the selected options, from the most preferred one.
+    pub ranking: Vec<u32>,
+    /// Vote power.
+    pub stake: Balance,
+    /// An optional document explaining the ranking.
+    pub rationale: Option<DocumentHash>,
+    /// Indicates if the ballot has been canceled.
+    pub canceled: bool,
+}
+
+impl RankedBallot {
+    pub fn new(
+        voter: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: Balance,
+    ) -> Self {
+        Self {
+            voter,
+            voting_id,
+            voting_type,
+            ranking,
+            stake,
+            rationale: None,
+            canceled: false,
+        }
+    }
+}
+
+/// Represents a secret user's vote in the formal phase of voting.
+///
+/// Only the commitment and the stake are known until the ballot is revealed.
+#[derive(OdraType)]
+pub struct SealedBallot {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// A hash of the choice, the stake and a salt - see [`ballot_commitment()`].
+    pub commitment: Bytes,
+    /// Staked reputation.
+    pub stake: Balance,
+    /// Indicates if the ballot has been revealed.
+    pub revealed: bool,
+    /// Indicates if the ballot has been canceled.
+    pub canceled: bool,
+}
+
+impl SealedBallot {
+    pub fn new(voter: Address, voting_id: VotingId, commitment: Bytes, stake: Balance) -> Self {
+        Self {
+            voter,
+            voting_id,
+            commitment,
+            stake,
+            revealed: false,
+            canceled: false,
+        }
+    }
+
+    /// Checks if the ballot can be settled at the end of voting - it was neither revealed nor canceled.
+    pub fn is_pending(&self) -> bool {
+        !self.revealed && !self.canceled
+    }
+}
+
+/// A ballot signed off-chain by the voter and submitted by any account (a relayer).
+#[derive(OdraType)]
+pub struct SignedBallot {
+    /// The voter's public key, the signature is verified against.
+    pub public_key: PublicKey,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type.
+    pub voting_type: VotingType,
+    /// Selected option.
+    pub choice: Choice,
+    /// Staked reputation.
+    pub stake: Balance,
+    /// An optional document explaining the choice.
+    pub rationale: Option<DocumentHash>,
+    /// The voter's next nonce, prevents replaying the ballot.
+    pub nonce: u64,
+    /// The time after which the ballot cannot be submitted.
+    pub deadline: BlockTime,
+    /// The signature of the [message](SignedBallot::message()).
+    pub signature: Bytes,
+}
+
+impl SignedBallot {
+    /// Returns the voter's address derived from the public key.
+    pub fn voter(&self) -> Address {
+        Address::Account(self.public_key.to_account_hash())
+    }
+
+    /// Computes the message the voter signs - a hash of the serialized `contract` address
+    /// and all the ballot fields except for the signature.
+    ///
+    /// The contract address is a part of the message, so the ballot cannot be submitted to another contract.
+    pub fn message(&self, contract: Address) -> Bytes {
+        let mut preimage = contract.serialize().unwrap_or_revert();
+        preimage.extend(self.voting_id.serialize().unwrap_or_revert());
+        preimage.extend(self.voting_type.serialize().unwrap_or_revert());
+        preimage.extend(self.choice.serialize().unwrap_or_revert());
+        preimage.extend(self.stake.serialize().unwrap_or_revert());
+        preimage.extend(self.rationale.serialize().unwrap_or_revert());
+        preimage.extend(self.nonce.serialize().unwrap_or_revert());
+        preimage.extend(self.deadline.serialize().unwrap_or_revert());
+        Bytes::from(contract_env::hash(preimage))
+    }
+}
+
+/// Computes the commitment of a secret ballot - a hash of the serialized `choice`, `stake` and `salt`.
+pub fn ballot_commitment(choice: Choice, stake: Balance, salt: &Bytes) -> Bytes {
+    let mut preimage = choice.serialize().unwrap_or_revert();
+    preimage.extend(stake.serialize().unwrap_or_revert());
+    preimage.extend(salt.serialize().unwrap_or_revert());
+    Bytes::from(contract_env::hash(preimage))
+}
+
+/// Choice enum, can be converted to bool using `is_in_favor()`, `is_against()` or `is_abstain()`.
 #[derive(OdraType, Copy, PartialEq, Eq, Debug)]
 pub enum Choice {
     /// `No` vote.
     Against,
     /// `Yes` vote.
     InFavor,
+    /// Counts toward the quorum, but does not affect the result.
+    Abstain,
 }
 
 impl Choice {
     pub fn is_in_favor(&self) -> bool {
-        match self {
-            Choice::InFavor => true,
-            Choice::Against => false,
-        }
+        matches!(self, Choice::InFavor)
     }
 
     pub fn is_against(&self) -> bool {
-        !self.is_in_favor()
+        matches!(self, Choice::Against)
+    }
+
+    pub fn is_abstain(&self) -> bool {
+        matches!(self, Choice::Abstain)
     }
 }
 
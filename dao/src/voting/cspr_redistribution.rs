@@ -1,19 +1,48 @@
 //! CSPR redistribution helper functions.
 use crate::configuration::Configuration;
 use crate::modules::refs::ContractRefs;
+use crate::utils::split_by_largest_remainder;
 use odra::contract_env::transfer_tokens;
 use odra::types::{Address, Balance};
+use odra::UnwrapOrRevert;
+use std::collections::BTreeMap;
 
 /// Transfers CSPRs to all VAs'. Each VA gets the amount of CSPR proportionally to their reputation.
 ///
 /// Interacts with [`Reputation Token Contract`](crate::core_contracts::ReputationContract) to get balances information.
-pub fn redistribute_cspr_to_all_vas(to_redistribute: Balance, refs: &ContractRefs) {
+///
+/// Returns the dust - the amount that couldn't be split, see [split_by_balances()].
+pub fn redistribute_cspr_to_all_vas(to_redistribute: Balance, refs: &ContractRefs) -> Balance {
     let all_balances = refs.reputation_token().all_balances();
-    let total_supply = all_balances.total_supply();
-    for (address, balance) in all_balances.balances() {
-        let amount = to_redistribute * *balance / total_supply;
-        transfer_tokens(address, amount);
+    let (shares, dust) = split_by_balances(to_redistribute, all_balances.balances());
+    for (address, amount) in shares {
+        transfer_tokens(&address, amount);
     }
+    dust
+}
+
+/// Splits the `amount` among the owners of the `balances` proportionally to their balances.
+///
+/// The shares are rounded using [the largest remainder method](split_by_largest_remainder), so they sum up
+/// to the `amount` exactly. If the balances are all zero, the amount can't be split and is returned as the dust.
+///
+/// Reverts with [`ArithmeticOverflow`](crate::utils::Error::ArithmeticOverflow) if the shares can't be calculated.
+///
+/// Returns a tuple of shares and the dust.
+pub fn split_by_balances(
+    amount: Balance,
+    balances: &BTreeMap<Address, Balance>,
+) -> (Vec<(Address, Balance)>, Balance) {
+    let weights: Vec<Balance> = balances.values().copied().collect();
+    let shares: Vec<(Address, Balance)> = balances
+        .keys()
+        .copied()
+        .zip(split_by_largest_remainder(amount, &weights).unwrap_or_revert())
+        .collect();
+    let distributed = shares
+        .iter()
+        .fold(Balance::zero(), |acc, (_, share)| acc + *share);
+    (shares, amount - distributed)
 }
 
 /// Transfers some part of a given amount to `Bid Escrow Wallet` and returns the remaining amount.
@@ -0,0 +1,31 @@
+//! Guardian helper functions.
+//!
+//! The guardian is a multisig wallet set as `GuardianAddress` in the Variable Repository. It can cancel any active voting
+//! in an emergency, at most once per `GuardianCancelInterval` across all the voting contracts.
+use crate::configuration::Configuration;
+use crate::utils::Error;
+use odra::call_contract;
+use odra::contract_env::revert;
+use odra::types::{Address, CallArgs};
+
+/// Checks if the `caller` is the guardian and records the cancel in the [DaoIdsContract](crate::utils_contracts::DaoIdsContract),
+/// which is shared by all the voting contracts.
+///
+/// # Errors
+/// * [`NotGuardian`](Error::NotGuardian) if the `caller` is not the guardian.
+/// * [`GuardianCancelTooEarly`](Error::GuardianCancelTooEarly) if the guardian has canceled a voting
+/// in any contract less than `GuardianCancelInterval` ago.
+pub fn record_guardian_cancel(caller: Address, configuration: &Configuration) {
+    if configuration.guardian_address() != Some(caller) {
+        revert(Error::NotGuardian);
+    }
+
+    let mut args = CallArgs::new();
+    args.insert("interval", configuration.guardian_cancel_interval());
+    call_contract::<()>(
+        configuration.voting_ids_address(),
+        "record_guardian_cancel",
+        &args,
+        None,
+    );
+}
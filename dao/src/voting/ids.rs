@@ -9,3 +9,12 @@ use odra::types::{Address, CallArgs};
 pub fn get_next_voting_id(voting_ids_address: Address) -> VotingId {
     call_contract(voting_ids_address, "next_voting_id", &CallArgs::new(), None)
 }
+
+/// Calls a contract at `voting_ids_address` to check if the `address` is a voting contract.
+///
+/// Each voting contract must be whitelisted in the ids contract to generate voting ids.
+pub fn is_voting_contract(voting_ids_address: Address, address: Address) -> bool {
+    let mut args = CallArgs::new();
+    args.insert("address", address);
+    call_contract(voting_ids_address, "is_whitelisted", &args, None)
+}
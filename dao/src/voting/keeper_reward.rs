@@ -0,0 +1,108 @@
+//! Keeper reward module.
+//!
+//! A keeper is anyone who performs the maintenance work nobody else is interested in - finishes an overdue
+//! voting or cancels an expired job or job offer.
+use crate::bid_escrow::events::TransferReason;
+use crate::configuration::Configuration;
+use crate::modules::refs::ContractRefs;
+use crate::utils::withdraw;
+use odra::contract_env::{attached_value, caller};
+use odra::types::{event::OdraEvent, Address, Balance};
+use odra::{Event, Variable};
+
+/// The Keeper Reward Pool module.
+///
+/// Holds the CSPR the keepers are paid from. The pool is funded explicitly and its balance is tracked
+/// separately from the contract balance, so the keepers are never paid with the funds the contract holds
+/// on behalf of its users (eg. DOS fees, job payments or CSPR stakes).
+#[odra::module(events = [KeeperRewarded, KeeperRewardPoolFunded])]
+pub struct KeeperRewardPool {
+    balance: Variable<Balance>,
+}
+
+impl KeeperRewardPool {
+    /// Adds the attached CSPR to the pool.
+    ///
+    /// # Events
+    /// * [`KeeperRewardPoolFunded`]
+    pub fn fund_keeper_reward_pool(&mut self) {
+        let amount = attached_value();
+        let balance = self.get_keeper_reward_pool() + amount;
+        self.balance.set(balance);
+
+        KeeperRewardPoolFunded {
+            funder: caller(),
+            amount,
+            balance,
+        }
+        .emit();
+    }
+
+    /// Returns the amount of CSPR left in the pool.
+    pub fn get_keeper_reward_pool(&self) -> Balance {
+        self.balance.get_or_default()
+    }
+
+    /// Rewards the `keeper` with the amounts set by the configuration.
+    ///
+    /// The [`KeeperCSPRReward`](Configuration::keeper_cspr_reward()) is paid from the pool,
+    /// if the pool balance is sufficient. The [`KeeperReputationReward`](Configuration::keeper_reputation_reward())
+    /// is minted only if the keeper is a VA.
+    ///
+    /// Interacts with [`Reputation Token Contract`](crate::core_contracts::ReputationContract) to mint reputation.
+    ///
+    /// # Events
+    /// * [`KeeperRewarded`] if any reward has been paid.
+    pub fn reward_keeper(
+        &mut self,
+        keeper: Address,
+        configuration: &Configuration,
+        refs: &ContractRefs,
+    ) {
+        let pool = self.get_keeper_reward_pool();
+        let mut cspr_amount = configuration.keeper_cspr_reward();
+        if cspr_amount > pool {
+            cspr_amount = Balance::zero();
+        }
+
+        let mut reputation_amount = configuration.keeper_reputation_reward();
+        if !reputation_amount.is_zero() && refs.va_token().balance_of(&keeper).is_zero() {
+            reputation_amount = Balance::zero();
+        }
+
+        if cspr_amount.is_zero() && reputation_amount.is_zero() {
+            return;
+        }
+
+        if !cspr_amount.is_zero() {
+            self.balance.set(pool - cspr_amount);
+            withdraw(&keeper, cspr_amount, TransferReason::KeeperReward);
+        }
+        if !reputation_amount.is_zero() {
+            refs.reputation_token().mint(keeper, reputation_amount);
+        }
+
+        KeeperRewarded {
+            keeper,
+            cspr_amount,
+            reputation_amount,
+        }
+        .emit();
+    }
+}
+
+/// Event emitted when a keeper has been rewarded.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct KeeperRewarded {
+    pub keeper: Address,
+    pub cspr_amount: Balance,
+    pub reputation_amount: Balance,
+}
+
+/// Event emitted when the keeper reward pool has been funded.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct KeeperRewardPoolFunded {
+    pub funder: Address,
+    pub amount: Balance,
+    pub balance: Balance,
+}
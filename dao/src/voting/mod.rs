@@ -1,6 +1,10 @@
 //! Voting module.
 pub mod ballot;
 pub mod cspr_redistribution;
+pub mod guardian;
 pub mod ids;
+pub mod keeper_reward;
+pub mod multi_option_engine;
+pub mod reputation_redistribution;
 pub mod types;
 pub mod voting_engine;
@@ -0,0 +1,781 @@
+//! Multi-option Voting Engine.
+use crate::configuration::{Configuration, ConfigurationBuilder};
+use crate::modules::refs::ContractRefs;
+use crate::modules::timelock::QueuedAction;
+use crate::modules::{IndexedList, Timelock};
+use crate::rules::validation::voting::CanCreateVoting;
+use crate::rules::RulesBuilder;
+use crate::utils::types::DocumentHash;
+use crate::utils::{page_range, Error};
+use crate::voting::ballot::RankedBallot;
+use crate::voting::guardian::record_guardian_cancel;
+use crate::voting::ids::get_next_voting_id;
+use crate::voting::keeper_reward::{KeeperRewardPool, KeeperRewarded};
+use crate::voting::multi_option_engine::events::{
+    MultiOptionVotingEnded, RankedBallotCanceled, RankedBallotCast, RankedBallotChanged,
+};
+use crate::voting::multi_option_engine::multi_option_voting::{
+    MultiOptionVoting, TallyMethod, VotingOption,
+};
+use crate::voting::reputation_redistribution::redistribute_losing_stakes;
+use crate::voting::types::VotingId;
+use crate::voting::voting_engine::events::{VotingCanceled, VotingCreatedInfo};
+use crate::voting::voting_engine::voting_state_machine::{
+    VotingResult, VotingState, VotingStateMachine, VotingSummary, VotingType,
+};
+use odra::contract_env::{caller, emit_event, get_block_time, revert};
+use odra::types::{Address, Balance};
+use odra::{List, Mapping, UnwrapOrRevert};
+use std::collections::BTreeMap;
+
+pub mod events;
+pub mod multi_option_voting;
+
+/// A generalization of the [VotingEngine](crate::voting::voting_engine::VotingEngine) for votings with more than two options.
+///
+/// Voters rank the options instead of voting `in favor` or `against`. The voting goes through the same phases:
+/// 1. Informal voting - if the quorum is reached, the formal voting starts.
+/// 2. Formal voting - the winning option is picked using the voting's [`TallyMethod`] and its contract calls are performed.
+///
+/// In the formal voting, the reputation staked on the losing options is redistributed to the voters
+/// who supported the winning one.
+///
+/// For example implementation see [MultiOptionVoterContract](crate::voting_contracts::MultiOptionVoterContract).
+#[odra::module(events = [RankedBallotCast, RankedBallotChanged, RankedBallotCanceled, MultiOptionVotingEnded, VotingCanceled, KeeperRewarded])]
+pub struct MultiOptionVotingEngine {
+    refs: ContractRefs,
+    voting_states: Mapping<VotingId, Option<VotingStateMachine>>,
+    votings: Mapping<VotingId, Option<MultiOptionVoting>>,
+    ballots: Mapping<(VotingId, VotingType, Address), Option<RankedBallot>>,
+    voters: Mapping<(VotingId, VotingType), List<Address>>,
+    creator_votings: Mapping<Address, List<VotingId>>,
+    voter_ballots: Mapping<Address, List<(VotingId, VotingType)>>,
+    configurations: Mapping<VotingId, Configuration>,
+    active_votings: IndexedList,
+    timelock: Timelock,
+    keeper_reward_pool: KeeperRewardPool,
+}
+
+impl MultiOptionVotingEngine {
+    /// Creates new informal multi-option voting.
+    ///
+    /// Depending on the configuration, the creator's `ranking` is cast as the first vote.
+    ///
+    /// # Errors
+    /// * [`TooFewVotingOptions`](Error::TooFewVotingOptions) if less than two options are given.
+    /// * [`InvalidRanking`](Error::InvalidRanking) if the creator's ranking is invalid.
+    /// * [`NotOnboarded`](Error::NotOnboarded) if the configuration requires the creator to be a VA but is not.
+    pub fn create_voting(
+        &mut self,
+        creator: Address,
+        options: Vec<VotingOption>,
+        tally_method: TallyMethod,
+        ranking: Vec<u32>,
+        stake: Balance,
+        mut configuration: Configuration,
+    ) -> (VotingCreatedInfo, VotingStateMachine) {
+        RulesBuilder::new()
+            .add_validation(CanCreateVoting::create(
+                self.is_va(&creator),
+                configuration.only_va_can_create(),
+            ))
+            .build()
+            .validate_generic_validations();
+
+        if options.len() < 2 {
+            revert(Error::TooFewVotingOptions)
+        }
+
+        configuration.set_total_reputation(self.refs.reputation_token().total_supply());
+        let voting_id = get_next_voting_id(configuration.voting_ids_address());
+        let mut voting = VotingStateMachine::new(voting_id, get_block_time(), creator);
+        let multi_option_voting = MultiOptionVoting::new(options, tally_method);
+
+        let mut used_stake = None;
+        if configuration.should_cast_first_vote() {
+            self.cast_ballot(
+                creator,
+                ranking,
+                stake,
+                None,
+                &mut voting,
+                &multi_option_voting,
+                &configuration,
+            );
+            used_stake = Some(stake);
+        }
+
+        let info = VotingCreatedInfo::new(creator, voting_id, used_stake, &configuration);
+        self.configurations.set(&voting_id, configuration);
+        self.votings.set(&voting_id, Some(multi_option_voting));
+        self.set_voting(voting.clone());
+
+        self.active_votings.push(voting_id);
+        self.creator_votings.get_instance(&creator).push(voting_id);
+
+        (info, voting)
+    }
+
+    /// Records the voter's ranking of the options and stakes the reputation.
+    ///
+    /// The voter may attach a `rationale` - a document explaining the ranking.
+    ///
+    /// # Events
+    /// * [`RankedBallotCast`](RankedBallotCast)
+    ///
+    /// # Errors
+    /// * [`CannotVoteTwice`](Error::CannotVoteTwice) if the voter already voted.
+    /// * [`InvalidRanking`](Error::InvalidRanking) if the ranking is empty, refers to a non-existing option or repeats an option.
+    /// * [`ZeroStake`](Error::ZeroStake) if the stake is zero.
+    /// * [`VoteInTime`](crate::rules::validation::voting::VoteInTime) errors if the voting phase is closed.
+    pub fn vote(
+        &mut self,
+        voter: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        let mut voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let multi_option_voting = self.get_multi_option_voting_or_revert(voting_id);
+        if voting.voting_type() != voting_type {
+            revert(Error::VotingWithGivenTypeNotInProgress)
+        }
+        voting.guard_vote(get_block_time(), &configuration);
+        if self.get_ballot(voting_id, voting_type, voter).is_some() {
+            revert(Error::CannotVoteTwice)
+        }
+        if stake.is_zero() {
+            revert(Error::ZeroStake)
+        }
+
+        self.cast_ballot(
+            voter,
+            ranking,
+            stake,
+            rationale,
+            &mut voting,
+            &multi_option_voting,
+            &configuration,
+        );
+        self.set_voting(voting);
+    }
+
+    /// Changes the ranking and/or the stake of an existing ballot.
+    ///
+    /// The difference between the new and the previous stake is staked or unstaked.
+    /// The `rationale` replaces the one attached to the ballot.
+    ///
+    /// # Events
+    /// * [`RankedBallotChanged`](RankedBallotChanged)
+    ///
+    /// # Errors
+    /// * [`BallotDoesNotExist`](Error::BallotDoesNotExist) if the voter has not voted or his ballot is canceled.
+    /// * [`CannotChangeCreatorsBallot`](Error::CannotChangeCreatorsBallot) if the ballot was cast automatically on voting creation.
+    /// * [`InvalidRanking`](Error::InvalidRanking) if the ranking is invalid.
+    /// * [`ZeroStake`](Error::ZeroStake) if the stake is zero.
+    /// * [`VoteInTime`](crate::rules::validation::voting::VoteInTime) errors if the voting phase is closed.
+    pub fn change_vote(
+        &mut self,
+        voter: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        let voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let multi_option_voting = self.get_multi_option_voting_or_revert(voting_id);
+        let mut ballot = self.get_changeable_ballot(&voting, &configuration, voting_type, voter);
+        if !multi_option_voting.is_valid_ranking(&ranking) {
+            revert(Error::InvalidRanking)
+        }
+        if stake.is_zero() {
+            revert(Error::ZeroStake)
+        }
+
+        let previous_stake = ballot.stake;
+        if !voting.is_informal_without_stake(&configuration) {
+            if stake > previous_stake {
+                self.refs
+                    .reputation_token()
+                    .stake(voter, stake - previous_stake);
+            } else if stake < previous_stake {
+                self.refs
+                    .reputation_token()
+                    .unstake(voter, previous_stake - stake);
+            }
+        }
+
+        let previous_ranking = std::mem::replace(&mut ballot.ranking, ranking);
+        ballot.stake = stake;
+        ballot.rationale = rationale;
+        emit_event(RankedBallotChanged::new(
+            &ballot,
+            previous_ranking,
+            previous_stake,
+        ));
+
+        self.ballots
+            .set(&(voting_id, voting_type, voter), Some(ballot));
+    }
+
+    /// Withdraws an existing ballot and unstakes the reputation.
+    ///
+    /// A withdrawn ballot does not count toward the quorum and the tally. The voter cannot vote again in the same phase.
+    ///
+    /// # Events
+    /// * [`RankedBallotChanged`](RankedBallotChanged)
+    ///
+    /// # Errors
+    /// * [`BallotDoesNotExist`](Error::BallotDoesNotExist) if the voter has not voted or his ballot is canceled.
+    /// * [`CannotChangeCreatorsBallot`](Error::CannotChangeCreatorsBallot) if the ballot was cast automatically on voting creation.
+    /// * [`VoteInTime`](crate::rules::validation::voting::VoteInTime) errors if the voting phase is closed.
+    pub fn withdraw_vote(&mut self, voter: Address, voting_id: VotingId, voting_type: VotingType) {
+        let voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let mut ballot = self.get_changeable_ballot(&voting, &configuration, voting_type, voter);
+
+        let previous_stake = ballot.stake;
+        if !voting.is_informal_without_stake(&configuration) {
+            self.refs.reputation_token().unstake(voter, previous_stake);
+        }
+
+        ballot.stake = Balance::zero();
+        ballot.canceled = true;
+        emit_event(RankedBallotChanged::new(
+            &ballot,
+            ballot.ranking.clone(),
+            previous_stake,
+        ));
+
+        self.ballots
+            .set(&(voting_id, voting_type, voter), Some(ballot));
+    }
+
+    /// Finishes voting.
+    ///
+    /// When the informal voting ends, the staked reputation is returned to the voters. If the quorum is reached,
+    /// the formal voting starts and the creator's ballot is cast again.
+    ///
+    /// When the formal voting ends and the quorum is reached, the winning option's contract calls are performed,
+    /// and the reputation staked on the losing options is redistributed to the voters who supported the winning one.
+    /// Otherwise, the reputation is returned.
+    ///
+    /// The result of the informal voting is [InFavor](VotingResult::InFavor) if the formal voting starts.
+    /// The result of the formal voting is [InFavor](VotingResult::InFavor) if there is a winner.
+    ///
+    /// The caller who finishes an overdue voting phase is rewarded as a keeper, see [KeeperRewardPool::reward_keeper()].
+    ///
+    /// # Events
+    /// * [`MultiOptionVotingEnded`](MultiOptionVotingEnded)
+    /// * [`KeeperRewarded`](KeeperRewarded)
+    ///
+    /// # Errors
+    /// * [`FinishingCompletedVotingNotAllowed`](Error::FinishingCompletedVotingNotAllowed) if trying to complete already finished voting.
+    /// * [`InformalVotingTimeNotReached`](Error::InformalVotingTimeNotReached) if informal voting time did not pass.
+    /// * [`FormalVotingTimeNotReached`](Error::FormalVotingTimeNotReached) if formal voting time did not pass.
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        let mut voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let mut multi_option_voting = self.get_multi_option_voting_or_revert(voting_id);
+        if voting.voting_type() != voting_type {
+            revert(Error::VotingWithGivenTypeNotInProgress)
+        }
+        if voting.completed() {
+            revert(Error::FinishingCompletedVotingNotAllowed)
+        }
+
+        let overdue = voting.is_overdue(get_block_time(), &configuration);
+        let ballots = self.all_ballots(voting_id, voting_type);
+        let total_stake = ballots
+            .iter()
+            .fold(Balance::zero(), |acc, ballot| acc + ballot.stake);
+        let quorum_reached =
+            voting.is_quorum_reached_by(ballots.len() as u32, total_stake, &configuration);
+        let tally = multi_option_voting.tally(
+            &ballots
+                .iter()
+                .map(|ballot| (ballot.ranking.clone(), ballot.stake))
+                .collect::<Vec<_>>(),
+        );
+
+        let mut unstakes = BTreeMap::new();
+        let mut mints = BTreeMap::new();
+        let mut burns = BTreeMap::new();
+
+        let voting_result = match voting_type {
+            VotingType::Informal => {
+                if !voting.is_in_time(get_block_time(), &configuration) {
+                    revert(Error::InformalVotingTimeNotReached)
+                }
+                if !voting.is_informal_without_stake(&configuration) {
+                    unstakes = self.unstake_ballots(&ballots);
+                }
+                if quorum_reached {
+                    voting.start_formal_voting();
+                    self.recast_creators_ballot_from_informal_to_formal(
+                        &mut voting,
+                        &multi_option_voting,
+                        &configuration,
+                    );
+                    VotingResult::InFavor
+                } else {
+                    voting.finish();
+                    VotingResult::QuorumNotReached
+                }
+            }
+            VotingType::Formal => {
+                voting.guard_finish_formal_voting(get_block_time(), &configuration);
+                voting.finish();
+                match tally.winner().filter(|_| quorum_reached) {
+                    Some(winner) => {
+                        let mut winning_stakes = Vec::new();
+                        let mut losing_stakes = Vec::new();
+                        for ballot in ballots.iter() {
+                            if tally.final_choice(&ballot.ranking) == Some(winner) {
+                                winning_stakes.push((ballot.voter, ballot.stake));
+                            } else {
+                                losing_stakes.push((ballot.voter, ballot.stake));
+                            }
+                        }
+                        self.refs
+                            .reputation_token()
+                            .bulk_unstake(winning_stakes.clone());
+                        self.refs
+                            .reputation_token()
+                            .bulk_unstake(losing_stakes.clone());
+                        (mints, burns) =
+                            redistribute_losing_stakes(&winning_stakes, &losing_stakes);
+                        self.refs
+                            .reputation_token()
+                            .bulk_mint_burn(mints.clone(), burns.clone());
+                        unstakes = winning_stakes.into_iter().collect();
+
+                        multi_option_voting.set_winner(winner);
+                        self.perform_action(
+                            voting_id,
+                            &multi_option_voting,
+                            winner,
+                            &configuration,
+                        );
+                        VotingResult::InFavor
+                    }
+                    None => {
+                        unstakes = self.unstake_ballots(&ballots);
+                        VotingResult::QuorumNotReached
+                    }
+                }
+            }
+        };
+
+        emit_event(MultiOptionVotingEnded {
+            voting_id,
+            voting_type,
+            voting_result: voting_result.clone(),
+            winner: multi_option_voting.winner(),
+            first_preferences: tally.first_preferences().clone(),
+            unstakes,
+            burns,
+            mints,
+        });
+
+        if voting.completed() {
+            self.active_votings.remove(voting_id);
+        }
+        self.votings.set(&voting_id, Some(multi_option_voting));
+        self.set_voting(voting);
+        if overdue {
+            self.keeper_reward_pool
+                .reward_keeper(caller(), &configuration, &self.refs);
+        }
+        VotingSummary::new(voting_result, voting_type, voting_id)
+    }
+
+    /// Cancels an active voting on behalf of the guardian. All the stakes are returned to the voters.
+    ///
+    /// Works the same way as [VotingEngine::guardian_cancel_voting()](crate::voting::voting_engine::VotingEngine::guardian_cancel_voting()).
+    ///
+    /// # Events
+    /// * [`VotingCanceled`] with the `reason`.
+    ///
+    /// # Errors
+    /// * [`NotGuardian`](Error::NotGuardian) if the `caller` is not the guardian.
+    /// * [`GuardianCancelTooEarly`](Error::GuardianCancelTooEarly) if the guardian has canceled a voting in any contract less than `GuardianCancelInterval` ago.
+    /// * [`VotingAlreadyCanceled`](Error::VotingAlreadyCanceled) if the voting has been canceled.
+    /// * [`VotingAlreadyFinished`](Error::VotingAlreadyFinished) if the voting has been finished.
+    pub fn guardian_cancel_voting(
+        &mut self,
+        caller: Address,
+        voting_id: VotingId,
+        reason: DocumentHash,
+    ) {
+        record_guardian_cancel(caller, &self.current_configuration());
+
+        let voting = self.get_voting_or_revert(voting_id);
+        match voting.state() {
+            VotingState::Canceled => revert(Error::VotingAlreadyCanceled),
+            VotingState::Finished => revert(Error::VotingAlreadyFinished),
+            _ => {}
+        }
+
+        self.cancel_voting(voting, Some(reason));
+    }
+
+    /// Erases a voter from all active votings.
+    ///
+    /// Works the same way as [VotingEngine::slash_voter()](crate::voting::voting_engine::VotingEngine::slash_voter()) -
+    /// the votings created by the voter are canceled, in other votings only his ballot is canceled.
+    ///
+    /// Returns a tuple of vectors listing canceled and affected votings.
+    ///
+    /// # Events
+    /// * [`VotingCanceled`] for each canceled voting.
+    /// * [`RankedBallotCanceled`] for each canceled ballot.
+    pub fn slash_voter(&mut self, voter: Address) -> (Vec<VotingId>, Vec<VotingId>) {
+        let created_votings: Vec<VotingId> =
+            self.creator_votings.get_instance(&voter).iter().collect();
+        let mut canceled_votings = vec![];
+        for voting_id in created_votings {
+            let voting = self.get_voting_or_revert(voting_id);
+            if !voting.completed() {
+                self.cancel_voting(voting, None);
+                canceled_votings.push(voting_id);
+            }
+        }
+
+        let cast_ballots: Vec<(VotingId, VotingType)> =
+            self.voter_ballots.get_instance(&voter).iter().collect();
+        let mut affected_votings = vec![];
+        for (voting_id, voting_type) in cast_ballots {
+            let voting = self.get_voting_or_revert(voting_id);
+            if voting.completed() || voting.voting_type() != voting_type {
+                continue;
+            }
+            if self.cancel_ballot(&voting, voter) {
+                affected_votings.push(voting_id);
+            }
+        }
+        (canceled_votings, affected_votings)
+    }
+
+    /// Returns the [Voting](VotingStateMachine) for a given id.
+    pub fn get_voting(&self, voting_id: VotingId) -> Option<VotingStateMachine> {
+        self.voting_states
+            .get(&voting_id)
+            .map(|x| x.unwrap_or_revert())
+    }
+
+    /// Returns the options, the tally method and the winner of voting with a given id.
+    pub fn get_multi_option_voting(&self, voting_id: VotingId) -> Option<MultiOptionVoting> {
+        self.votings.get_or_default(&voting_id)
+    }
+
+    /// Returns the Voter's [`RankedBallot`].
+    pub fn get_ballot(
+        &self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        address: Address,
+    ) -> Option<RankedBallot> {
+        self.ballots
+            .get_or_default(&(voting_id, voting_type, address))
+    }
+
+    /// Returns the address of the nth voter who voted on Voting with a given id.
+    pub fn get_voter(
+        &self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        at: u32,
+    ) -> Option<Address> {
+        self.voters.get_instance(&(voting_id, voting_type)).get(at)
+    }
+
+    /// Gets the total number of users participated in voting.
+    pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32 {
+        self.voters.get_instance(&(voting_id, voting_type)).len()
+    }
+
+    /// Returns a page of the ids of the votings that have not been finished yet.
+    ///
+    /// The pages are not stable - when a voting is removed from the list, the last one is moved to its position,
+    /// so a voting may be skipped if the list changes between the calls.
+    /// The pages of [votings_by_creator()](Self::votings_by_creator) and [ballots_of()](Self::ballots_of) are stable.
+    pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId> {
+        self.active_votings.page(offset, limit)
+    }
+
+    /// Returns a page of the ids of the votings created by the `creator`, in the order of creation.
+    pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId> {
+        let votings = self.creator_votings.get_instance(&creator);
+        page_range(offset, limit, votings.len())
+            .filter_map(|i| votings.get(i))
+            .collect()
+    }
+
+    /// Returns a page of the [RankedBallots](RankedBallot) cast by the `voter`, in the order of casting.
+    pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<RankedBallot> {
+        let ballots = self.voter_ballots.get_instance(&voter);
+        page_range(offset, limit, ballots.len())
+            .filter_map(|i| ballots.get(i))
+            .filter_map(|(voting_id, voting_type)| self.get_ballot(voting_id, voting_type, voter))
+            .collect()
+    }
+
+    /// Returns a page of the [RankedBallots](RankedBallot) cast in the voting with a given id and type,
+    /// in the order of casting.
+    pub fn ballots_page(
+        &self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<RankedBallot> {
+        let voters = self.voters.get_instance(&(voting_id, voting_type));
+        page_range(offset, limit, voters.len())
+            .filter_map(|i| voters.get(i))
+            .filter_map(|voter| self.get_ballot(voting_id, voting_type, voter))
+            .collect()
+    }
+
+    /// Checks if voting of a given type and id exists.
+    pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool {
+        match self.get_voting(voting_id) {
+            None => false,
+            Some(voting) => voting.voting_type() == voting_type,
+        }
+    }
+
+    /// Executes the action queued by a passed voting. See [Timelock::execute()].
+    pub fn execute_action(&mut self, voting_id: VotingId) {
+        self.timelock.execute(voting_id);
+    }
+
+    /// Cancels the action queued by a passed voting. See [Timelock::cancel()].
+    ///
+    /// Works the same way as [VotingEngine::cancel_action()](crate::voting::voting_engine::VotingEngine::cancel_action()) -
+    /// the action can be canceled by the guardian or by a passed veto voting.
+    ///
+    /// # Events
+    /// * [`ActionCancelled`](crate::modules::timelock::events::ActionCancelled)
+    ///
+    /// # Errors
+    /// * [`CannotCancelAction`](Error::CannotCancelAction) if the caller is neither the guardian nor the veto voter.
+    pub fn cancel_action(&mut self, voting_id: VotingId) {
+        let configuration = self.current_configuration();
+        let canceller = Some(caller());
+        if configuration.guardian_address() != canceller
+            && configuration.veto_voter_address() != canceller
+        {
+            revert(Error::CannotCancelAction);
+        }
+        self.timelock.cancel(voting_id);
+    }
+
+    /// Returns the action queued by the voting with the given id.
+    pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction> {
+        self.timelock.get_queued_action(voting_id)
+    }
+
+    /// Adds the attached CSPR to the pool the keepers are rewarded from, see [KeeperRewardPool].
+    pub fn fund_keeper_reward_pool(&mut self) {
+        self.keeper_reward_pool.fund_keeper_reward_pool();
+    }
+
+    /// Returns the CSPR left in the pool the keepers are rewarded from, see [KeeperRewardPool].
+    pub fn get_keeper_reward_pool(&self) -> Balance {
+        self.keeper_reward_pool.get_keeper_reward_pool()
+    }
+
+    fn cast_ballot(
+        &mut self,
+        voter: Address,
+        ranking: Vec<u32>,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+        voting: &mut VotingStateMachine,
+        multi_option_voting: &MultiOptionVoting,
+        configuration: &Configuration,
+    ) {
+        if !multi_option_voting.is_valid_ranking(&ranking) {
+            revert(Error::InvalidRanking)
+        }
+        if !voting.is_informal_without_stake(configuration) {
+            self.refs.reputation_token().stake(voter, stake);
+        }
+
+        let voting_id = voting.voting_id();
+        let voting_type = voting.voting_type();
+        let mut ballot = RankedBallot::new(voter, voting_id, voting_type, ranking, stake);
+        ballot.rationale = rationale;
+        emit_event(RankedBallotCast::new(&ballot));
+
+        self.voters
+            .get_instance(&(voting_id, voting_type))
+            .push(voter);
+        self.voter_ballots
+            .get_instance(&voter)
+            .push((voting_id, voting_type));
+        self.ballots
+            .set(&(voting_id, voting_type, voter), Some(ballot));
+    }
+
+    fn recast_creators_ballot_from_informal_to_formal(
+        &mut self,
+        voting: &mut VotingStateMachine,
+        multi_option_voting: &MultiOptionVoting,
+        configuration: &Configuration,
+    ) {
+        let creator = *voting.creator();
+        if let Some(ballot) = self.get_ballot(voting.voting_id(), VotingType::Informal, creator) {
+            self.cast_ballot(
+                creator,
+                ballot.ranking,
+                ballot.stake,
+                ballot.rationale,
+                voting,
+                multi_option_voting,
+                configuration,
+            );
+        }
+    }
+
+    fn cancel_voting(&mut self, mut voting: VotingStateMachine, reason: Option<DocumentHash>) {
+        let voting_id = voting.voting_id();
+        let voting_type = voting.voting_type();
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let unstakes = if voting.is_informal_without_stake(&configuration) {
+            BTreeMap::new()
+        } else {
+            self.unstake_ballots(&self.all_ballots(voting_id, voting_type))
+        };
+        voting.cancel();
+        self.set_voting(voting);
+        self.active_votings.remove(voting_id);
+
+        emit_event(VotingCanceled::new(
+            voting_id,
+            voting_type,
+            unstakes,
+            reason,
+        ));
+    }
+
+    /// Cancels voter's ballot in the given voting and returns the stake.
+    /// Returns true if the voter has voted in the voting and the ballot was canceled.
+    fn cancel_ballot(&mut self, voting: &VotingStateMachine, voter: Address) -> bool {
+        let voting_id = voting.voting_id();
+        let voting_type = voting.voting_type();
+        let mut ballot = match self.get_ballot(voting_id, voting_type, voter) {
+            Some(ballot) if !ballot.canceled => ballot,
+            _ => return false,
+        };
+
+        let configuration = self.get_configuration_or_revert(voting_id);
+        if !voting.is_informal_without_stake(&configuration) {
+            self.refs.reputation_token().unstake(voter, ballot.stake);
+        }
+        emit_event(RankedBallotCanceled::new(&ballot));
+
+        ballot.canceled = true;
+        self.ballots
+            .set(&(voting_id, voting_type, voter), Some(ballot));
+        true
+    }
+
+    fn get_changeable_ballot(
+        &self,
+        voting: &VotingStateMachine,
+        configuration: &Configuration,
+        voting_type: VotingType,
+        voter: Address,
+    ) -> RankedBallot {
+        if voting.voting_type() != voting_type {
+            revert(Error::VotingWithGivenTypeNotInProgress)
+        }
+        voting.guard_vote(get_block_time(), configuration);
+
+        if voting.creator() == &voter && configuration.should_cast_first_vote() {
+            revert(Error::CannotChangeCreatorsBallot)
+        }
+
+        let ballot = self
+            .get_ballot(voting.voting_id(), voting_type, voter)
+            .unwrap_or_revert_with(Error::BallotDoesNotExist);
+        if ballot.canceled {
+            revert(Error::BallotDoesNotExist)
+        }
+        ballot
+    }
+
+    /// Returns the ballots that count in the voting - the canceled ones are skipped.
+    fn all_ballots(&self, voting_id: VotingId, voting_type: VotingType) -> Vec<RankedBallot> {
+        self.voters
+            .get_instance(&(voting_id, voting_type))
+            .iter()
+            .filter_map(|voter| self.get_ballot(voting_id, voting_type, voter))
+            .filter(|ballot| !ballot.canceled)
+            .collect()
+    }
+
+    fn unstake_ballots(&self, ballots: &[RankedBallot]) -> BTreeMap<Address, Balance> {
+        let stakes: Vec<(Address, Balance)> = ballots
+            .iter()
+            .map(|ballot| (ballot.voter, ballot.stake))
+            .collect();
+        self.refs.reputation_token().bulk_unstake(stakes.clone());
+        stakes.into_iter().collect()
+    }
+
+    /// Performs the winning option's contract calls or, if the `TimelockDelay` is set, queues them
+    /// for the later execution. See [Timelock::perform_or_queue()].
+    fn perform_action(
+        &mut self,
+        voting_id: VotingId,
+        multi_option_voting: &MultiOptionVoting,
+        winner: u32,
+        configuration: &Configuration,
+    ) {
+        let option = multi_option_voting
+            .option(winner)
+            .unwrap_or_revert_with(Error::InvalidRanking);
+        self.timelock
+            .perform_or_queue(voting_id, &option.contract_calls, configuration);
+    }
+
+    fn current_configuration(&self) -> Configuration {
+        ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build()
+    }
+
+    fn get_voting_or_revert(&self, voting_id: VotingId) -> VotingStateMachine {
+        self.get_voting(voting_id)
+            .unwrap_or_revert_with(Error::VotingDoesNotExist)
+    }
+
+    fn get_multi_option_voting_or_revert(&self, voting_id: VotingId) -> MultiOptionVoting {
+        self.get_multi_option_voting(voting_id)
+            .unwrap_or_revert_with(Error::VotingDoesNotExist)
+    }
+
+    fn get_configuration_or_revert(&self, voting_id: VotingId) -> Configuration {
+        self.configurations
+            .get(&voting_id)
+            .unwrap_or_revert_with(Error::ConfigurationNotFound)
+    }
+
+    fn set_voting(&mut self, voting: VotingStateMachine) {
+        self.voting_states.set(&voting.voting_id(), Some(voting))
+    }
+
+    fn is_va(&self, address: &Address) -> bool {
+        !self.refs.va_token().balance_of(address).is_zero()
+    }
+}
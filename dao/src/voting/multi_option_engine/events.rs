@@ -0,0 +1,113 @@
+//! Set of multi-option voting events.
+use crate::utils::types::DocumentHash;
+use crate::voting::ballot::RankedBallot;
+use crate::voting::types::VotingId;
+use crate::voting::voting_engine::voting_state_machine::{VotingResult, VotingType};
+use odra::types::{Address, Balance};
+use odra::Event;
+use std::collections::BTreeMap;
+
+/// Event thrown after a ranked ballot is cast.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct RankedBallotCast {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type (Formal/Informal).
+    pub voting_type: VotingType,
+    /// Indexes of the selected options, from the most preferred one.
+    pub ranking: Vec<u32>,
+    /// Vote power.
+    pub stake: Balance,
+    /// An optional document explaining the ranking.
+    pub rationale: Option<DocumentHash>,
+}
+
+impl RankedBallotCast {
+    pub fn new(ballot: &RankedBallot) -> Self {
+        RankedBallotCast {
+            voter: ballot.voter,
+            voting_id: ballot.voting_id,
+            voting_type: ballot.voting_type,
+            ranking: ballot.ranking.clone(),
+            stake: ballot.stake,
+            rationale: ballot.rationale.clone(),
+        }
+    }
+}
+
+/// Event thrown after a ranked ballot is changed or withdrawn.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct RankedBallotChanged {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type (Formal/Informal).
+    pub voting_type: VotingType,
+    /// Previous ranking of the options.
+    pub previous_ranking: Vec<u32>,
+    /// Previous vote power.
+    pub previous_stake: Balance,
+    /// Indexes of the selected options, from the most preferred one.
+    pub ranking: Vec<u32>,
+    /// Vote power.
+    pub stake: Balance,
+    /// An optional document explaining the ranking.
+    pub rationale: Option<DocumentHash>,
+}
+
+impl RankedBallotChanged {
+    pub fn new(ballot: &RankedBallot, previous_ranking: Vec<u32>, previous_stake: Balance) -> Self {
+        Self {
+            voter: ballot.voter,
+            voting_id: ballot.voting_id,
+            voting_type: ballot.voting_type,
+            previous_ranking,
+            previous_stake,
+            ranking: ballot.ranking.clone(),
+            stake: ballot.stake,
+            rationale: ballot.rationale.clone(),
+        }
+    }
+}
+
+/// Event thrown after a ranked ballot is canceled during full slashing.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct RankedBallotCanceled {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type (Formal/Informal).
+    pub voting_type: VotingType,
+    /// Vote power.
+    pub stake: Balance,
+}
+
+impl RankedBallotCanceled {
+    pub fn new(ballot: &RankedBallot) -> Self {
+        Self {
+            voter: ballot.voter,
+            voting_id: ballot.voting_id,
+            voting_type: ballot.voting_type,
+            stake: ballot.stake,
+        }
+    }
+}
+
+/// Event thrown when a multi-option voting ends.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct MultiOptionVotingEnded {
+    pub voting_id: VotingId,
+    pub voting_type: VotingType,
+    pub voting_result: VotingResult,
+    /// The index of the winning option.
+    pub winner: Option<u32>,
+    /// The stake of the first preferences of each option.
+    pub first_preferences: Vec<Balance>,
+    pub unstakes: BTreeMap<Address, Balance>,
+    pub burns: BTreeMap<Address, Balance>,
+    pub mints: BTreeMap<Address, Balance>,
+}
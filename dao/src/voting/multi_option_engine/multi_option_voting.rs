@@ -0,0 +1,255 @@
+//! Multi-option voting data and the tally algorithms.
+use crate::utils::types::DocumentHash;
+use crate::utils::ContractCall;
+use odra::types::Balance;
+use odra::OdraType;
+
+/// An option to choose in a multi-option voting.
+#[derive(OdraType)]
+pub struct VotingOption {
+    /// The hash of a document describing the option.
+    pub document_hash: DocumentHash,
+    /// Calls performed if the option wins the formal voting.
+    pub contract_calls: Vec<ContractCall>,
+}
+
+/// The method of picking the winning option.
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub enum TallyMethod {
+    /// The option with the highest stake of the first preferences wins.
+    Plurality,
+    /// The option with the lowest stake of the first preferences is eliminated and its ballots
+    /// are transferred to the next preferred option, until an option gets the majority of the stake.
+    InstantRunoff,
+}
+
+/// Serializable multi-option voting metadata.
+#[derive(OdraType)]
+pub struct MultiOptionVoting {
+    options: Vec<VotingOption>,
+    tally_method: TallyMethod,
+    winner: Option<u32>,
+}
+
+impl MultiOptionVoting {
+    /// Creates a new instance of [`MultiOptionVoting`].
+    pub fn new(options: Vec<VotingOption>, tally_method: TallyMethod) -> Self {
+        Self {
+            options,
+            tally_method,
+            winner: None,
+        }
+    }
+
+    /// Gets the voting options.
+    pub fn options(&self) -> &Vec<VotingOption> {
+        &self.options
+    }
+
+    /// Gets the number of options.
+    pub fn options_count(&self) -> u32 {
+        self.options.len() as u32
+    }
+
+    /// Gets the option with a given index.
+    pub fn option(&self, index: u32) -> Option<&VotingOption> {
+        self.options.get(index as usize)
+    }
+
+    /// Gets the tally method.
+    pub fn tally_method(&self) -> TallyMethod {
+        self.tally_method
+    }
+
+    /// Gets the index of the winning option, if the formal voting has ended with a winner.
+    pub fn winner(&self) -> Option<u32> {
+        self.winner
+    }
+
+    /// Sets the winning option.
+    pub fn set_winner(&mut self, winner: u32) {
+        self.winner = Some(winner);
+    }
+
+    /// Checks if the ranking is not empty, refers only to existing options and does not repeat any of them.
+    pub fn is_valid_ranking(&self, ranking: &[u32]) -> bool {
+        if ranking.is_empty() {
+            return false;
+        }
+        ranking.iter().enumerate().all(|(position, option)| {
+            *option < self.options_count() && !ranking[..position].contains(option)
+        })
+    }
+
+    /// Counts the ballots - `(ranking, stake)` pairs - using the voting's [`TallyMethod`].
+    pub fn tally(&self, ballots: &[(Vec<u32>, Balance)]) -> Tally {
+        match self.tally_method {
+            TallyMethod::Plurality => Tally::plurality(self.options_count(), ballots),
+            TallyMethod::InstantRunoff => Tally::instant_runoff(self.options_count(), ballots),
+        }
+    }
+}
+
+/// The result of counting the ballots.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Tally {
+    winner: Option<u32>,
+    eliminated: Vec<u32>,
+    first_preferences: Vec<Balance>,
+}
+
+impl Tally {
+    /// Gets the index of the winning option. `None` if no stake has been cast.
+    pub fn winner(&self) -> Option<u32> {
+        self.winner
+    }
+
+    /// Gets the stake of the first preferences of each option.
+    pub fn first_preferences(&self) -> &Vec<Balance> {
+        &self.first_preferences
+    }
+
+    /// Returns the option a ballot with a given ranking supports in the final round of counting -
+    /// the most preferred option that has not been eliminated.
+    pub fn final_choice(&self, ranking: &[u32]) -> Option<u32> {
+        ranking
+            .iter()
+            .find(|option| !self.eliminated.contains(option))
+            .copied()
+    }
+
+    /// Picks the option with the highest stake of the first preferences.
+    ///
+    /// In case of a tie, the option with the lowest index wins.
+    fn plurality(options_count: u32, ballots: &[(Vec<u32>, Balance)]) -> Self {
+        let first_preferences = Self::count(options_count, ballots, &[]);
+        let winner = Self::leader(&first_preferences, &[]);
+        Self {
+            winner,
+            // Only the first preference counts, so a ballot's final choice is the first option in the ranking.
+            eliminated: Vec::new(),
+            first_preferences,
+        }
+    }
+
+    /// Eliminates the weakest options one by one until an option gets more than a half of the stake.
+    ///
+    /// In case of a tie, the option with the highest index is eliminated.
+    fn instant_runoff(options_count: u32, ballots: &[(Vec<u32>, Balance)]) -> Self {
+        let first_preferences = Self::count(options_count, ballots, &[]);
+        let mut eliminated = Vec::new();
+        loop {
+            let stakes = Self::count(options_count, ballots, &eliminated);
+            let total_stake = stakes
+                .iter()
+                .fold(Balance::zero(), |acc, stake| acc + *stake);
+            let leader = match Self::leader(&stakes, &eliminated) {
+                Some(leader) => leader,
+                None => {
+                    return Self {
+                        winner: None,
+                        eliminated,
+                        first_preferences,
+                    }
+                }
+            };
+            let remaining = options_count - eliminated.len() as u32;
+            if remaining == 1 || stakes[leader as usize] * Balance::from(2) > total_stake {
+                return Self {
+                    winner: Some(leader),
+                    eliminated,
+                    first_preferences,
+                };
+            }
+            let weakest = (0..options_count)
+                .filter(|option| !eliminated.contains(option))
+                .rev()
+                .min_by_key(|option| stakes[*option as usize])
+                .unwrap_or(leader);
+            eliminated.push(weakest);
+        }
+    }
+
+    /// Sums up the stake of each option, transferring the ballots from the eliminated options.
+    fn count(
+        options_count: u32,
+        ballots: &[(Vec<u32>, Balance)],
+        eliminated: &[u32],
+    ) -> Vec<Balance> {
+        let mut stakes = vec![Balance::zero(); options_count as usize];
+        for (ranking, stake) in ballots {
+            let choice = ranking.iter().find(|option| !eliminated.contains(option));
+            if let Some(option) = choice {
+                stakes[*option as usize] += *stake;
+            }
+        }
+        stakes
+    }
+
+    /// Returns the not eliminated option with the highest non-zero stake, preferring the lowest index.
+    fn leader(stakes: &[Balance], eliminated: &[u32]) -> Option<u32> {
+        let mut leader: Option<u32> = None;
+        for (option, stake) in stakes.iter().enumerate() {
+            let option = option as u32;
+            if eliminated.contains(&option) || stake.is_zero() {
+                continue;
+            }
+            match leader {
+                Some(current) if stakes[current as usize] >= *stake => {}
+                _ => leader = Some(option),
+            }
+        }
+        leader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ballots(ballots: &[(&[u32], u32)]) -> Vec<(Vec<u32>, Balance)> {
+        ballots
+            .iter()
+            .map(|(ranking, stake)| (ranking.to_vec(), Balance::from(*stake)))
+            .collect()
+    }
+
+    #[test]
+    fn test_plurality() {
+        let ballots = ballots(&[(&[0, 1], 400), (&[1, 0], 300), (&[2, 1], 350)]);
+        let tally = Tally::plurality(3, &ballots);
+        assert_eq!(tally.winner(), Some(0));
+        assert_eq!(tally.final_choice(&[1, 0]), Some(1));
+        assert_eq!(Tally::plurality(3, &[]).winner(), None);
+    }
+
+    #[test]
+    fn test_plurality_tie() {
+        let ballots = ballots(&[(&[1], 300), (&[2], 300)]);
+        assert_eq!(Tally::plurality(3, &ballots).winner(), Some(1));
+    }
+
+    #[test]
+    fn test_instant_runoff() {
+        let ballots = ballots(&[(&[0, 1], 400), (&[1, 0], 300), (&[2, 1], 350)]);
+        let tally = Tally::instant_runoff(3, &ballots);
+        // Option 1 is eliminated and its stake goes to option 0.
+        assert_eq!(tally.winner(), Some(0));
+        assert_eq!(tally.final_choice(&[1, 0]), Some(0));
+
+        let ballots = self::ballots(&[(&[0], 400), (&[1, 2], 300), (&[2, 1], 350)]);
+        let tally = Tally::instant_runoff(3, &ballots);
+        // Option 1 is eliminated and its stake goes to option 2.
+        assert_eq!(tally.winner(), Some(2));
+        assert_eq!(tally.final_choice(&[1, 2]), Some(2));
+        assert_eq!(tally.final_choice(&[0]), Some(0));
+    }
+
+    #[test]
+    fn test_instant_runoff_exhausted_ballots() {
+        let ballots = ballots(&[(&[0], 400), (&[1], 300), (&[2], 350)]);
+        let tally = Tally::instant_runoff(3, &ballots);
+        assert_eq!(tally.winner(), Some(0));
+        assert_eq!(tally.final_choice(&[1]), None);
+    }
+}
@@ -0,0 +1,179 @@
+//! Reputation redistribution policies and helper functions.
+use crate::utils::{per_mil_of, split_by_largest_remainder, Error};
+use odra::types::{Address, Balance};
+use odra::{OdraType, UnwrapOrRevert};
+use std::collections::BTreeMap;
+
+/// Identifies the [RedistributionPolicy] applied at the end of a formal voting.
+///
+/// See [Variable Repository](crate::core_contracts::VariableRepositoryContract) RedistributionPolicy
+/// ([available keys](crate::core_contracts::VariableRepositoryContract#available-keys)).
+#[derive(OdraType, Copy, PartialEq, Eq, Debug)]
+pub enum RedistributionPolicyKind {
+    /// The losing stake is burned and minted to the winners pro rata - see [ProRataRedistribution].
+    ProRata,
+    /// Only a part of the losing stake is redistributed - see [PartialLossRedistribution].
+    PartialLoss,
+    /// The losing stake goes to the treasury - see [TreasuryRedistribution].
+    Treasury,
+    /// The losing stake is returned to the losers - see [NoRedistribution].
+    NoRedistribution,
+}
+
+/// Defines what happens to the reputation staked on the losing side of a formal voting.
+pub trait RedistributionPolicy {
+    /// Returns the kind of the policy.
+    fn kind(&self) -> RedistributionPolicyKind;
+
+    /// Calculates how the `losing_stakes` are redistributed.
+    ///
+    /// Returns a tuple of mints and burns. The stakes are unstaked by the caller.
+    fn redistribute(
+        &self,
+        winning_stakes: &[(Address, Balance)],
+        losing_stakes: &[(Address, Balance)],
+    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>);
+}
+
+/// The default policy - the losers' stake is burned and minted pro rata to the winners.
+pub struct ProRataRedistribution;
+
+impl RedistributionPolicy for ProRataRedistribution {
+    fn kind(&self) -> RedistributionPolicyKind {
+        RedistributionPolicyKind::ProRata
+    }
+
+    fn redistribute(
+        &self,
+        winning_stakes: &[(Address, Balance)],
+        losing_stakes: &[(Address, Balance)],
+    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
+        redistribute_losing_stakes(winning_stakes, losing_stakes)
+    }
+}
+
+/// Only the `loss_ratio` (in per mils) of each losing stake is burned and minted pro rata to the winners.
+/// The rest is returned to the losers.
+pub struct PartialLossRedistribution {
+    pub loss_ratio: Balance,
+}
+
+impl RedistributionPolicy for PartialLossRedistribution {
+    fn kind(&self) -> RedistributionPolicyKind {
+        RedistributionPolicyKind::PartialLoss
+    }
+
+    fn redistribute(
+        &self,
+        winning_stakes: &[(Address, Balance)],
+        losing_stakes: &[(Address, Balance)],
+    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
+        let lost_stakes: Vec<(Address, Balance)> = losing_stakes
+            .iter()
+            .map(|(owner, stake)| {
+                let lost = per_mil_of(*stake, self.loss_ratio)
+                    .unwrap_or_revert_with(Error::ArithmeticOverflow);
+                (*owner, lost)
+            })
+            .filter(|(_, lost)| !lost.is_zero())
+            .collect();
+        redistribute_losing_stakes(winning_stakes, &lost_stakes)
+    }
+}
+
+/// The losers' stake is burned and minted to the `treasury` - the winners get nothing.
+pub struct TreasuryRedistribution {
+    pub treasury: Address,
+}
+
+impl RedistributionPolicy for TreasuryRedistribution {
+    fn kind(&self) -> RedistributionPolicyKind {
+        RedistributionPolicyKind::Treasury
+    }
+
+    fn redistribute(
+        &self,
+        _winning_stakes: &[(Address, Balance)],
+        losing_stakes: &[(Address, Balance)],
+    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
+        let mut burns: BTreeMap<Address, Balance> = BTreeMap::new();
+        let mut mints: BTreeMap<Address, Balance> = BTreeMap::new();
+        for (owner, stake) in losing_stakes {
+            *burns.entry(*owner).or_default() += *stake;
+        }
+
+        let total_losing_stake = sum_stakes(losing_stakes);
+        if !total_losing_stake.is_zero() {
+            mints.insert(self.treasury, total_losing_stake);
+        }
+        (mints, burns)
+    }
+}
+
+/// The losers' stake is returned - useful for low-stakes signaling votings.
+pub struct NoRedistribution;
+
+impl RedistributionPolicy for NoRedistribution {
+    fn kind(&self) -> RedistributionPolicyKind {
+        RedistributionPolicyKind::NoRedistribution
+    }
+
+    fn redistribute(
+        &self,
+        _winning_stakes: &[(Address, Balance)],
+        _losing_stakes: &[(Address, Balance)],
+    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
+        (BTreeMap::new(), BTreeMap::new())
+    }
+}
+
+/// Splits the reputation staked on the losing side among the winning side.
+///
+/// All the `losing_stakes` are burned. Each owner of a winning stake gets minted a part of the total losing stake
+/// proportionally to his stake. The winning stakes remain untouched.
+///
+/// The parts are rounded using [the largest remainder method](split_by_largest_remainder),
+/// so the total minted is equal to the total burned, unless there is no winning stake.
+/// Reverts with [`ArithmeticOverflow`](Error::ArithmeticOverflow) if the parts can't be calculated.
+///
+/// Returns a tuple of mints and burns.
+pub fn redistribute_losing_stakes(
+    winning_stakes: &[(Address, Balance)],
+    losing_stakes: &[(Address, Balance)],
+) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
+    let total_losing_stake = sum_stakes(losing_stakes);
+    let mut burns: BTreeMap<Address, Balance> = BTreeMap::new();
+    let mut mints: BTreeMap<Address, Balance> = BTreeMap::new();
+
+    for (owner, stake) in losing_stakes {
+        *burns.entry(*owner).or_default() += *stake;
+    }
+
+    if sum_stakes(winning_stakes).is_zero() {
+        return (mints, burns);
+    }
+
+    let weights: Vec<Balance> = winning_stakes.iter().map(|(_, stake)| *stake).collect();
+    let amounts = split_by_largest_remainder(total_losing_stake, &weights).unwrap_or_revert();
+    for ((owner, _), amount_to_mint) in winning_stakes.iter().zip(amounts) {
+        *mints.entry(*owner).or_default() += amount_to_mint;
+    }
+
+    (mints, burns)
+}
+
+/// Returns the amount burned but not minted - the reputation that couldn't be split among the recipients.
+pub fn redistribution_dust(
+    mints: &BTreeMap<Address, Balance>,
+    burns: &BTreeMap<Address, Balance>,
+) -> Balance {
+    let total_minted = mints.values().fold(Balance::zero(), |acc, v| acc + *v);
+    let total_burned = burns.values().fold(Balance::zero(), |acc, v| acc + *v);
+    total_burned.saturating_sub(total_minted)
+}
+
+fn sum_stakes(stakes: &[(Address, Balance)]) -> Balance {
+    stakes
+        .iter()
+        .fold(Balance::zero(), |acc, (_, stake)| acc + *stake)
+}
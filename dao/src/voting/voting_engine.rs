@@ -1,26 +1,46 @@
 //! Voting Engine.
-use crate::configuration::Configuration;
+use crate::configuration::{Configuration, ConfigurationBuilder};
 use crate::modules::refs::ContractRefs;
-use crate::rules::validation::voting::CanCreateVoting;
+use crate::modules::timelock::QueuedAction;
+use crate::modules::{DustLedger, IndexedList, Timelock};
+use crate::rules::validation::voting::{CanCreateVoting, VoteInTime};
+use crate::rules::validation::VotingValidation;
 use crate::rules::RulesBuilder;
-use crate::utils::Error;
-use crate::voting::ballot::{Ballot, Choice};
+use crate::utils::types::DocumentHash;
+use crate::utils::{page_range, vote_batch_error, ContractCall, Error, MAX_VOTE_BATCH_SIZE};
+use crate::voting::ballot::{ballot_commitment, Ballot, Choice, SealedBallot, SignedBallot};
+use crate::voting::guardian::record_guardian_cancel;
 use crate::voting::ids::get_next_voting_id;
+use crate::voting::keeper_reward::{KeeperRewardPool, KeeperRewarded};
+use crate::voting::reputation_redistribution::{redistribution_dust, RedistributionPolicy};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::{
-    BallotCanceled, BallotCast, Reason, VotingCanceled, VotingCreatedInfo, VotingEnded,
+    BallotCanceled, BallotCast, BallotChanged, BallotCommitted, DelegatedStakeWithdrawn, Reason,
+    VotingCanceled, VotingCreatedInfo, VotingEnded, VotingExtended, VotingWithdrawn,
 };
 use crate::voting::voting_engine::voting_state_machine::{
-    VotingResult, VotingStateMachine, VotingSummary, VotingType,
+    VotingOutcome, VotingResult, VotingState, VotingStateMachine, VotingSummary, VotingType,
 };
-use odra::contract_env::{emit_event, get_block_time, revert};
-use odra::types::{Address, Balance};
-use odra::{List, Mapping, UnwrapOrRevert, Variable};
+use odra::contract_env::{
+    caller, emit_event, get_block_time, revert, self_address, verify_signature,
+};
+use odra::types::{Address, Balance, BlockTime, Bytes};
+use odra::{List, Mapping, UnwrapOrRevert};
 use std::collections::BTreeMap;
 
 pub mod events;
 pub mod voting_state_machine;
 
+/// A validated item of a [VotingEngine::vote_batch()] call.
+type BatchBallot = (
+    VotingStateMachine,
+    Configuration,
+    VotingOutcome,
+    Choice,
+    Balance,
+    Option<DocumentHash>,
+);
+
 /// Governance voting is a struct that voting_contracts can use to implement voting.
 ///
 /// It consists of two phases:
@@ -30,20 +50,34 @@ pub mod voting_state_machine;
 /// Whether formal voting starts depends on informal voting results.
 ///
 /// When formal voting passes, an action can be performed - a contract can be called with voted arguments.
+/// If the `TimelockDelay` variable is set, the action is queued in the [Timelock] and can be executed once the delay has passed.
+/// In the meantime, the action can be canceled by the guardian or by a passed veto voting.
 ///
 /// Governance voting uses:
 /// 1. [Reputation Token](crate::core_contracts::ReputationContract) to handle reputation staking.
 /// 2. [Variable Repo](crate::core_contracts::VariableRepositoryContract) for reading voting configuration.
 ///
 /// For example implementation see [AdminContract](crate::voting_contracts::AdminContract).
-#[odra::module(events = [VotingCreatedInfo, BallotCast, VotingEnded, VotingCanceled, BallotCanceled])]
+#[odra::module(events = [VotingCreatedInfo, BallotCast, VotingEnded, VotingCanceled, VotingWithdrawn, BallotCanceled, DelegatedStakeWithdrawn, BallotChanged, BallotCommitted, VotingExtended, KeeperRewarded])]
 pub struct VotingEngine {
     refs: ContractRefs,
     voting_states: Mapping<VotingId, Option<VotingStateMachine>>,
     ballots: Mapping<(VotingId, VotingType, Address), Ballot>,
+    delegated_ballots: Mapping<(VotingId, VotingType, Address), Option<Address>>,
+    withdrawn_ballots: Mapping<(VotingId, VotingType), u32>,
+    sealed_ballots: Mapping<(VotingId, Address), Option<SealedBallot>>,
+    sealed_voters: Mapping<VotingId, List<Address>>,
+    nonces: Mapping<Address, u64>,
     voters: Mapping<(VotingId, VotingType), List<Address>>,
+    creator_votings: Mapping<Address, List<VotingId>>,
+    voter_ballots: Mapping<Address, List<(VotingId, VotingType)>>,
+    voter_stakes: Mapping<Address, List<(VotingId, VotingType)>>,
     configurations: Mapping<VotingId, Configuration>,
-    active_votings: Variable<Vec<VotingId>>,
+    active_votings: IndexedList,
+    finished_formal_votings: List<(VotingId, BlockTime)>,
+    timelock: Timelock,
+    dust_ledger: DustLedger,
+    keeper_reward_pool: KeeperRewardPool,
 }
 
 impl VotingEngine {
@@ -51,7 +85,7 @@ impl VotingEngine {
     ///
     /// `contract_to_call`, `entry_point` and `runtime_args` parameters define an action that will be performed when formal voting passes.
     ///
-    /// It collects configuration from [Variable Repo] and persists it, so they won't change during the voting process.
+    /// It collects configuration from [Variable Repo] and the total reputation supply and persists it, so they won't change during the voting process.
     ///
     /// Interacts with [Dao Ids Contract] to generate voting id.
     ///
@@ -70,7 +104,7 @@ impl VotingEngine {
         &mut self,
         creator: Address,
         stake: Balance,
-        configuration: Configuration,
+        mut configuration: Configuration,
     ) -> (VotingCreatedInfo, VotingStateMachine) {
         RulesBuilder::new()
             .add_validation(CanCreateVoting::create(
@@ -80,6 +114,7 @@ impl VotingEngine {
             .build()
             .validate_generic_validations();
 
+        configuration.set_total_reputation(self.refs.reputation_token().total_supply());
         let should_cast_first_vote = configuration.should_cast_first_vote();
 
         let voting_ids_address = configuration.voting_ids_address();
@@ -94,6 +129,7 @@ impl VotingEngine {
                 VotingType::Informal,
                 Choice::InFavor,
                 stake,
+                None,
                 &mut voting,
                 &configuration,
             );
@@ -105,6 +141,8 @@ impl VotingEngine {
 
         // Register voting in active votings list.
         self.add_to_active_list(voting_id);
+        let mut creator_votings = self.creator_votings.get_instance(&creator);
+        creator_votings.push(voting_id);
 
         (info, voting)
     }
@@ -116,12 +154,20 @@ impl VotingEngine {
     /// For informal voting a new formal voting can be created. Reputation staked for this voting is returned to the voters,
     /// except for the creator. When voting passes, it is used as a stake for a new voting, otherwise it is burned.
     ///
+    /// A [Signal Voting](Configuration::is_signal()) ends after the informal phase, no reputation is staked.
+    ///
     /// For formal voting an action will be performed if the result is `in favor`. Reputation is redistributed to the winning voters.
     /// When no quorum is reached, the reputation is returned, except for the creator - its reputation is then burned.
     ///
+    /// Formal voting can be finished before the end of the formal phase if the quorum is reached and the result
+    /// can no longer change. In such a case [`VotingEnded`](VotingEnded) event is marked as `ended_early`.
+    ///
+    /// The caller who finishes an overdue voting phase is rewarded as a keeper, see [KeeperRewardPool::reward_keeper()].
+    ///
     /// # Events
     /// * [`VotingEnded`](VotingEnded)
     /// * [`BallotCast`](BallotCast)
+    /// * [`KeeperRewarded`](KeeperRewarded)
     ///
     /// # Errors
     /// * [`FinishingCompletedVotingNotAllowed`](Error::FinishingCompletedVotingNotAllowed) if trying to complete already finished voting.
@@ -137,9 +183,11 @@ impl VotingEngine {
             revert(Error::FinishingCompletedVotingNotAllowed)
         }
 
+        let overdue = voting.is_overdue(get_block_time(), &configuration);
         let mut rep_unstakes = BTreeMap::new();
         let mut rep_burns = BTreeMap::new();
         let mut rep_mints = BTreeMap::new();
+        let mut redistribution_policy = None;
 
         let summary = match voting.voting_type() {
             VotingType::Informal => {
@@ -148,11 +196,20 @@ impl VotingEngine {
                 if !informal_without_stake {
                     let yes_unstakes = self.return_yes_voters_rep(voting_id, VotingType::Informal);
                     let no_unstakes = self.return_no_voters_rep(voting_id, VotingType::Informal);
+                    let abstain_unstakes =
+                        self.return_abstain_voters_rep(voting_id, VotingType::Informal);
                     add_to_map(&mut rep_unstakes, Reason::InformalFinished, yes_unstakes);
                     add_to_map(&mut rep_unstakes, Reason::InformalFinished, no_unstakes);
+                    add_to_map(
+                        &mut rep_unstakes,
+                        Reason::InformalFinished,
+                        abstain_unstakes,
+                    );
                 }
 
                 match voting_result.result() {
+                    // A Signal Voting has no formal phase.
+                    VotingResult::InFavor | VotingResult::Against if configuration.is_signal() => {}
                     VotingResult::InFavor | VotingResult::Against => {
                         // It emits BallotCast event, so no need to capture it in VotingEnded event.
                         self.recast_creators_ballot_from_informal_to_formal(
@@ -167,6 +224,19 @@ impl VotingEngine {
             }
             VotingType::Formal => {
                 let voting_result = self.finish_formal_voting(&mut voting, &configuration);
+                let policy = configuration.redistribution_policy();
+                // Abstain ballots never take part in the redistribution.
+                let abstain_unstakes =
+                    self.return_abstain_voters_rep(voting_id, VotingType::Formal);
+                add_to_map(&mut rep_unstakes, Reason::FormalFinished, abstain_unstakes);
+                let (unrevealed_unstakes, unrevealed_burns) =
+                    self.settle_unrevealed_ballots(voting_id, &configuration);
+                add_to_map(
+                    &mut rep_unstakes,
+                    Reason::BallotNotRevealed,
+                    unrevealed_unstakes,
+                );
+                add_to_map(&mut rep_burns, Reason::BallotNotRevealed, unrevealed_burns);
                 match voting_result.result() {
                     VotingResult::InFavor => {
                         if configuration.should_bind_ballot_for_successful_voting() {
@@ -177,16 +247,26 @@ impl VotingEngine {
                         }
                         let yes_unstakes =
                             self.return_yes_voters_rep(voting_id, VotingType::Formal);
-                        let (mints, burns) =
-                            self.redistribute_reputation_of_no_voters(&voting, VotingType::Formal);
+                        let (mints, burns) = self.redistribute_reputation_of_losers(
+                            &voting,
+                            VotingType::Formal,
+                            Choice::Against,
+                            policy.as_ref(),
+                        );
+                        redistribution_policy = Some(policy.kind());
                         add_to_map(&mut rep_unstakes, Reason::FormalFinished, yes_unstakes);
                         add_to_map(&mut rep_mints, Reason::FormalWon, mints);
                         add_to_map(&mut rep_burns, Reason::FormalLost, burns);
                     }
                     VotingResult::Against => {
                         let no_unstakes = self.return_no_voters_rep(voting_id, VotingType::Formal);
-                        let (mints, burns) =
-                            self.redistribute_reputation_of_yes_voters(&voting, VotingType::Formal);
+                        let (mints, burns) = self.redistribute_reputation_of_losers(
+                            &voting,
+                            VotingType::Formal,
+                            Choice::InFavor,
+                            policy.as_ref(),
+                        );
+                        redistribution_policy = Some(policy.kind());
                         add_to_map(&mut rep_unstakes, Reason::FormalFinished, no_unstakes);
                         add_to_map(&mut rep_mints, Reason::FormalWon, mints);
                         add_to_map(&mut rep_burns, Reason::FormalLost, burns);
@@ -214,13 +294,20 @@ impl VotingEngine {
             &voting,
             summary.result(),
             stats,
+            &configuration,
             rep_unstakes,
             BTreeMap::new(),
             rep_burns,
             rep_mints,
+            redistribution_policy,
         ));
 
         self.set_voting(voting);
+
+        if overdue {
+            self.keeper_reward_pool
+                .reward_keeper(caller(), &configuration, &self.refs);
+        }
         summary
     }
 
@@ -262,9 +349,14 @@ impl VotingEngine {
         }
 
         let voting_id = voting.voting_id();
-        let voters_count = self.voters_count(voting_id, voting.voting_type());
+        let voters_count = self.quorum_voters_count(voting_id, voting.voting_type());
         let voting_result = voting.get_result(voters_count, configuration);
         let double_time_between_votings = match voting_result {
+            VotingResult::InFavor | VotingResult::Against if configuration.is_signal() => {
+                self.remove_from_active_list(voting_id);
+                voting.finish();
+                false
+            }
             VotingResult::InFavor | VotingResult::Against => {
                 voting.complete_informal_voting(configuration)
             }
@@ -289,29 +381,108 @@ impl VotingEngine {
         voting: &mut VotingStateMachine,
         configuration: &Configuration,
     ) -> VotingSummary {
-        voting.guard_finish_formal_voting(get_block_time(), configuration);
+        let ended_early = voting.state_in_time(get_block_time(), configuration)
+            == VotingState::Formal
+            && self.is_result_locked(voting, configuration);
+        if !ended_early {
+            voting.guard_finish_formal_voting(get_block_time(), configuration);
+        }
         let voting_id = voting.voting_id();
-        let voters_count = self.voters_count(voting_id, VotingType::Formal);
+        let voters_count = self.quorum_voters_count(voting_id, VotingType::Formal);
         let voting_result = voting.get_result(voters_count, configuration);
 
         if voting_result == VotingResult::InFavor {
-            self.perform_action(configuration);
+            self.perform_action(voting_id, configuration);
         }
 
         self.remove_from_active_list(voting_id);
-        voting.finish();
+        self.finished_formal_votings
+            .push((voting_id, get_block_time()));
+        if ended_early {
+            voting.finish_early();
+        } else {
+            voting.finish();
+        }
 
         VotingSummary::new(voting_result, VotingType::Formal, voting_id)
     }
 
+    /// Checks if the result of the formal phase is locked in - the quorum is reached and neither the reputation
+    /// not staked yet nor the ballots that can still be changed can flip the result.
+    ///
+    /// In the worst case every ballot but the creator's one moves to the losing side, and so does all the reputation
+    /// that is not staked in any voting (the total supply of [Reputation Token Contract](crate::core_contracts::ReputationContract)
+    /// minus the total stake). The raw stakes are counted, as they bound the vote power in the quadratic voting.
+    ///
+    /// The quorum must hold even if every ballot that can still be withdrawn is gone - only the creator's ballot
+    /// and the unbound ballots count toward it, or the bound reputation minus the withdrawable stake
+    /// if the [stake weighted quorum](Configuration::is_stake_weighted_quorum()) is enabled.
+    fn is_result_locked(&self, voting: &VotingStateMachine, configuration: &Configuration) -> bool {
+        // Secret ballots are unknown until revealed.
+        if configuration.is_secret_formal_voting() {
+            return false;
+        }
+
+        let voting_id = voting.voting_id();
+        let voting_type = voting.voting_type();
+        let voters_count = self.quorum_voters_count(voting_id, voting_type);
+        if voting.get_result(voters_count, configuration) == VotingResult::QuorumNotReached {
+            return false;
+        }
+
+        let winning_choice = match voting.is_in_favor(configuration) {
+            true => Choice::InFavor,
+            false => Choice::Against,
+        };
+        let reputation_token = self.refs.reputation_token();
+        // overflow is not possible due to reputation token having Balance as max
+        let mut joinable = reputation_token
+            .total_supply()
+            .saturating_sub(reputation_token.get_total_stake());
+        let mut leavable = Balance::zero();
+        let mut withdrawable_stake = Balance::zero();
+        let mut immutable_voters_count = 0;
+        let creator_ballot_locked = configuration.should_cast_first_vote();
+        for voter in self.voters(voting_id, voting_type).iter() {
+            let ballot = match self.get_ballot(voting_id, voting_type, voter) {
+                Some(ballot) if !ballot.canceled => ballot,
+                _ => continue,
+            };
+            if ballot.unbound || (creator_ballot_locked && voting.creator() == &voter) {
+                immutable_voters_count += 1;
+                continue;
+            }
+            withdrawable_stake += ballot.stake;
+            if ballot.choice == winning_choice {
+                leavable += ballot.weight;
+                joinable += ballot.stake;
+            } else if ballot.choice == Choice::Abstain {
+                joinable += ballot.stake;
+            }
+        }
+
+        let remaining_stake = voting
+            .total_bound_reputation()
+            .saturating_sub(withdrawable_stake);
+        if !voting.is_quorum_reached_by(immutable_voters_count, remaining_stake, configuration) {
+            return false;
+        }
+
+        voting.is_result_locked(joinable, leavable, configuration)
+    }
+
     /// Writes a vote in the storage.
     ///
+    /// The voter may attach a `rationale` - a document explaining the choice. It is stored in the [`Ballot`].
+    ///
     /// # Events
     /// * [`BallotCast`](BallotCast)
     ///
     /// # Errors
     /// * [`VoteOnCompletedVotingNotAllowed`](Error::VoteOnCompletedVotingNotAllowed) if voting is completed.
     /// * [`CannotVoteTwice`](Error::CannotVoteTwice) if the voter already voted.
+    /// * [`StakeExceedsSnapshotBalance`](Error::StakeExceedsSnapshotBalance) if the stake exceeds
+    /// the voter's balance at the voting creation.
     pub fn vote(
         &mut self,
         voter: Address,
@@ -319,26 +490,272 @@ impl VotingEngine {
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     ) {
         let mut voting = self.get_voting_or_revert(voting_id);
         let configuration = self.get_configuration_or_revert(voting_id);
+        self.assert_ballot_not_secret(&voting, &configuration);
+        let outcome = voting.outcome(&configuration);
         self.cast_vote(
             voter,
             voting_type,
             choice,
             stake,
+            rationale,
             &mut voting,
             &configuration,
         );
+        self.extend_on_late_change(&mut voting, outcome, &configuration);
         self.set_voting(voting);
     }
 
+    /// Casts a ballot signed off-chain by the voter. The ballot can be submitted by any account.
+    ///
+    /// See [verify_signed_ballot()](Self::verify_signed_ballot) and [vote()](Self::vote).
+    pub fn vote_with_signature(&mut self, signed_ballot: SignedBallot) {
+        let voter = self.verify_signed_ballot(&signed_ballot);
+        self.vote(
+            voter,
+            signed_ballot.voting_id,
+            signed_ballot.voting_type,
+            signed_ballot.choice,
+            signed_ballot.stake,
+            signed_ballot.rationale,
+        );
+    }
+
+    /// Casts many ballots signed off-chain. If any of the ballots is invalid, none of them is cast.
+    ///
+    /// See [vote_with_signature()](Self::vote_with_signature).
+    pub fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>) {
+        for signed_ballot in signed_ballots {
+            self.vote_with_signature(signed_ballot);
+        }
+    }
+
+    /// Casts many ballots of the `voter` in one call.
+    ///
+    /// Each item is validated with the same rules as [vote()](Self::vote) before any ballot is cast,
+    /// then the reputation of all the ballots is staked with a single call to the [Reputation Token Contract].
+    /// Either all the ballots are cast or none of them.
+    ///
+    /// # Events
+    /// * [`BallotCast`](BallotCast) for each item.
+    ///
+    /// # Errors
+    /// * [`VoteBatchTooLarge`](Error::VoteBatchTooLarge) if there are more than [MAX_VOTE_BATCH_SIZE] items.
+    /// * [vote_batch_error()] with the index of the first invalid item and the reason it failed.
+    ///
+    /// [Reputation Token Contract]: crate::core_contracts::ReputationContract
+    pub fn vote_batch(
+        &mut self,
+        voter: Address,
+        ballots: Vec<(VotingId, VotingType, Choice, Balance)>,
+    ) {
+        self.vote_batch_with_rationale(voter, without_rationale(ballots));
+    }
+
+    /// Works like [vote_batch()](Self::vote_batch), but each item carries an optional rationale,
+    /// which is stored in the [`Ballot`].
+    pub fn vote_batch_with_rationale(
+        &mut self,
+        voter: Address,
+        ballots: Vec<(VotingId, VotingType, Choice, Balance, Option<DocumentHash>)>,
+    ) {
+        self.vote_batch_with(voter, ballots, |_, _, _, _| Ok(()));
+    }
+
+    /// Works like [vote_batch_with_rationale()](Self::vote_batch_with_rationale), but each item must also pass
+    /// the contract-specific `check`, which gets the voting id, the choice, the rationale and the voting configuration.
+    pub fn vote_batch_with<F>(
+        &mut self,
+        voter: Address,
+        ballots: Vec<(VotingId, VotingType, Choice, Balance, Option<DocumentHash>)>,
+        check: F,
+    ) where
+        F: Fn(VotingId, Choice, Option<&DocumentHash>, &Configuration) -> Result<(), Error>,
+    {
+        if ballots.len() > MAX_VOTE_BATCH_SIZE {
+            revert(Error::VoteBatchTooLarge)
+        }
+
+        let reputation = self.refs.reputation_token();
+        let mut available_balance = reputation
+            .balance_of(voter)
+            .saturating_sub(reputation.get_stake(voter));
+        let mut total_stake = Balance::zero();
+        let mut batch = Vec::new();
+        for (index, (voting_id, voting_type, choice, stake, rationale)) in
+            ballots.into_iter().enumerate()
+        {
+            let in_batch = batch
+                .iter()
+                .any(|(voting, ..): &BatchBallot| voting.voting_id() == voting_id);
+            if in_batch {
+                revert(vote_batch_error(index, Error::CannotVoteTwice))
+            }
+            let validated = self
+                .validate_batch_vote(voter, voting_id, voting_type, stake, &mut available_balance)
+                .and_then(|(voting, configuration)| {
+                    check(voting_id, choice, rationale.as_ref(), &configuration)?;
+                    Ok((voting, configuration))
+                });
+            let (voting, configuration) = match validated {
+                Ok(validated) => validated,
+                Err(error) => revert(vote_batch_error(index, error)),
+            };
+            if !voting.is_informal_without_stake(&configuration) {
+                total_stake += stake;
+            }
+            let outcome = voting.outcome(&configuration);
+            batch.push((voting, configuration, outcome, choice, stake, rationale));
+        }
+
+        // The delegated stakes are released first, so the voter can stake them again.
+        for (voting, configuration, ..) in batch.iter_mut() {
+            self.withdraw_delegated_stake(voting, voter, configuration);
+        }
+        if !total_stake.is_zero() {
+            self.refs.reputation_token().stake(voter, total_stake);
+        }
+        for (mut voting, configuration, outcome, choice, stake, rationale) in batch {
+            let mut ballot = Ballot::new(
+                voter,
+                voting.voting_id(),
+                voting.voting_type(),
+                choice,
+                stake,
+                false,
+                false,
+            );
+            ballot.rationale = rationale;
+            self.register_ballot(ballot, &mut voting, &configuration);
+            self.extend_on_late_change(&mut voting, outcome, &configuration);
+            self.set_voting(voting);
+        }
+    }
+
+    /// Checks if the `voter` can cast a ballot in a batch, with the same rules as [vote()](Self::vote).
+    ///
+    /// The stake is deducted from the `available_balance`, so the whole batch is checked against the voter's balance.
+    fn validate_batch_vote(
+        &self,
+        voter: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        stake: Balance,
+        available_balance: &mut Balance,
+    ) -> Result<(VotingStateMachine, Configuration), Error> {
+        let voting = self
+            .get_voting(voting_id)
+            .ok_or(Error::VotingDoesNotExist)?;
+        let configuration = self
+            .configurations
+            .get(&voting_id)
+            .ok_or(Error::ConfigurationNotFound)?;
+        if voting.voting_type() == VotingType::Formal && configuration.is_secret_formal_voting() {
+            return Err(Error::SecretBallotRequired);
+        }
+        if voting.voting_type() != voting_type {
+            return Err(Error::VotingWithGivenTypeNotInProgress);
+        }
+        VoteInTime::create(get_block_time()).validate(&voting, &configuration)?;
+        if self.ballots.get(&(voting_id, voting_type, voter)).is_some() {
+            return Err(Error::CannotVoteTwice);
+        }
+
+        if !voting.is_informal_without_stake(&configuration) {
+            if stake.is_zero() {
+                return Err(Error::ZeroStake);
+            }
+            *available_balance += self.delegated_stake(&voting, voter);
+            if stake > *available_balance {
+                return Err(Error::InsufficientBalance);
+            }
+            *available_balance -= stake;
+            let snapshot_balance = self
+                .refs
+                .reputation_token()
+                .balance_of_at(voter, voting.created_at());
+            if stake > snapshot_balance {
+                return Err(Error::StakeExceedsSnapshotBalance);
+            }
+        }
+        Ok((voting, configuration))
+    }
+
+    /// Verifies a ballot signed off-chain, consumes the voter's nonce and returns the voter's address.
+    ///
+    /// # Errors
+    /// * [`SignatureExpired`](Error::SignatureExpired) if the deadline has passed.
+    /// * [`InvalidNonce`](Error::InvalidNonce) if the nonce is not the voter's next nonce.
+    /// * [`InvalidSignature`](Error::InvalidSignature) if the signature does not match the voter's public key.
+    pub fn verify_signed_ballot(&mut self, signed_ballot: &SignedBallot) -> Address {
+        if get_block_time() > signed_ballot.deadline {
+            revert(Error::SignatureExpired);
+        }
+
+        let voter = signed_ballot.voter();
+        let nonce = self.get_nonce(voter);
+        if signed_ballot.nonce != nonce {
+            revert(Error::InvalidNonce);
+        }
+
+        let message = signed_ballot.message(self_address());
+        if !verify_signature(
+            &message,
+            &signed_ballot.signature,
+            &signed_ballot.public_key,
+        ) {
+            revert(Error::InvalidSignature);
+        }
+
+        self.nonces.set(&voter, nonce + 1);
+        voter
+    }
+
+    /// Returns the next nonce of the voter's signed ballot.
+    pub fn get_nonce(&self, voter: Address) -> u64 {
+        self.nonces.get_or_default(&voter)
+    }
+
+    /// Returns the total reputation burned but not minted in redistributions, see [DustLedger].
+    pub fn get_reputation_dust(&self) -> Balance {
+        self.dust_ledger.reputation_dust()
+    }
+
+    /// Returns the total CSPR left on the contract balance in redistributions, see [DustLedger].
+    pub fn get_cspr_dust(&self) -> Balance {
+        self.dust_ledger.cspr_dust()
+    }
+
+    /// Records the CSPR that couldn't be redistributed, see [DustLedger].
+    pub fn record_cspr_dust(&mut self, amount: Balance) {
+        self.dust_ledger.record_cspr_dust(amount);
+    }
+
+    /// Records the reputation that couldn't be redistributed, see [DustLedger].
+    pub fn record_reputation_dust(&mut self, amount: Balance) {
+        self.dust_ledger.record_reputation_dust(amount);
+    }
+
+    /// Adds the attached CSPR to the pool the keepers are rewarded from, see [KeeperRewardPool].
+    pub fn fund_keeper_reward_pool(&mut self) {
+        self.keeper_reward_pool.fund_keeper_reward_pool();
+    }
+
+    /// Returns the CSPR left in the pool the keepers are rewarded from, see [KeeperRewardPool].
+    pub fn get_keeper_reward_pool(&self) -> Balance {
+        self.keeper_reward_pool.get_keeper_reward_pool()
+    }
+
     fn cast_vote(
         &mut self,
         voter: Address,
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
         voting: &mut VotingStateMachine,
         configuration: &Configuration,
     ) {
@@ -346,7 +763,395 @@ impl VotingEngine {
         self.assert_voting_type(voting, voting_type);
         voting.guard_vote(get_block_time(), configuration);
         self.assert_vote_doesnt_exist(voting_id, voting.voting_type(), voter);
-        self.cast_ballot(voter, choice, stake, false, voting, configuration);
+        // A delegator overrides the delegate's vote by voting himself.
+        self.withdraw_delegated_stake(voting, voter, configuration);
+        self.cast_ballot(
+            voter,
+            choice,
+            stake,
+            false,
+            rationale,
+            voting,
+            configuration,
+        );
+    }
+
+    /// Casts a ballot on behalf of the delegate and his delegators.
+    ///
+    /// Each delegator must have delegated his voting power to the `delegate` in this contract
+    /// (see [Reputation Token Contract](crate::core_contracts::ReputationContract::delegate())),
+    /// and the delegate can stake at most the maximum stake set by the delegator.
+    /// Both the delegate and the delegators must be VAs.
+    /// The reputation is staked from the delegators' balances, so it is redistributed back to them pro rata
+    /// when the voting ends. A delegator can override the delegate's vote by voting himself.
+    ///
+    /// # Events
+    /// * [`BallotCast`](BallotCast)
+    ///
+    /// # Errors
+    /// * [`CannotVoteTwice`](Error::CannotVoteTwice) if the delegate or a delegator already voted.
+    /// * [`NotOnboarded`](Error::NotOnboarded) if the delegate or a delegator is not a VA.
+    /// * [`NotADelegate`](Error::NotADelegate) if a delegator has not delegated his voting power to the `delegate`.
+    /// * [`DelegatedStakeExceedsLimit`](Error::DelegatedStakeExceedsLimit) if the stake of a delegator exceeds
+    /// the maximum stake he allowed the `delegate` to use.
+    /// * [`ZeroStake`](Error::ZeroStake) if the total stake is zero.
+    /// * [`StakeExceedsSnapshotBalance`](Error::StakeExceedsSnapshotBalance) if the delegate or a delegator
+    /// stakes more than he owned when the voting was created.
+    pub fn vote_as_delegate(
+        &mut self,
+        delegate: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        let mut voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        self.assert_voting_type(&voting, voting_type);
+        self.assert_ballot_not_secret(&voting, &configuration);
+        voting.guard_vote(get_block_time(), &configuration);
+        self.assert_vote_doesnt_exist(voting_id, voting_type, delegate);
+        if !self.is_va(&delegate) {
+            revert(Error::NotOnboarded)
+        }
+        let outcome = voting.outcome(&configuration);
+
+        let should_stake = !voting.is_informal_without_stake(&configuration);
+        let voting_contract = self_address();
+        let mut total_stake = stake;
+        for (delegator, delegated_stake) in delegators.iter() {
+            let delegation = self
+                .refs
+                .reputation_token()
+                .get_delegation(*delegator, voting_contract)
+                .filter(|delegation| delegation.delegate == delegate)
+                .unwrap_or_revert_with(Error::NotADelegate);
+            if *delegated_stake > delegation.max_stake {
+                revert(Error::DelegatedStakeExceedsLimit)
+            }
+            if !self.is_va(delegator) {
+                revert(Error::NotOnboarded)
+            }
+            self.assert_vote_doesnt_exist(voting_id, voting_type, *delegator);
+            let key = (voting_id, voting_type, *delegator);
+            if self.delegated_ballots.get_or_default(&key).is_some() {
+                revert(Error::CannotVoteTwice)
+            }
+            if should_stake {
+                self.refs
+                    .reputation_token()
+                    .stake(*delegator, *delegated_stake);
+                self.assert_snapshot_balance(*delegator, *delegated_stake, &voting);
+            }
+            self.delegated_ballots.set(&key, Some(delegate));
+            self.voter_stakes
+                .get_instance(delegator)
+                .push((voting_id, voting_type));
+            total_stake += *delegated_stake;
+        }
+
+        if total_stake.is_zero() {
+            revert(Error::ZeroStake)
+        }
+        if should_stake && !stake.is_zero() {
+            self.refs.reputation_token().stake(delegate, stake);
+            self.assert_snapshot_balance(delegate, stake, &voting);
+        }
+
+        let mut ballot = Ballot::new(
+            delegate,
+            voting_id,
+            voting_type,
+            choice,
+            total_stake,
+            false,
+            false,
+        );
+        ballot.delegated = delegators;
+        self.register_ballot(ballot, &mut voting, &configuration);
+        self.extend_on_late_change(&mut voting, outcome, &configuration);
+        self.set_voting(voting);
+    }
+
+    /// Changes the choice and/or the stake of an existing ballot.
+    ///
+    /// The difference between the new and the previous stake is staked or unstaked.
+    /// The reputation staked on behalf of the delegators remains untouched.
+    /// The `rationale` replaces the one attached to the ballot.
+    ///
+    /// # Events
+    /// * [`BallotChanged`](BallotChanged)
+    ///
+    /// # Errors
+    /// * [`BallotDoesNotExist`](Error::BallotDoesNotExist) if the voter has not voted or his ballot is canceled.
+    /// * [`CannotChangeCreatorsBallot`](Error::CannotChangeCreatorsBallot) if the ballot was cast automatically on voting creation.
+    /// * [`ZeroStake`](Error::ZeroStake) if the resulting stake is zero.
+    /// * [`StakeExceedsSnapshotBalance`](Error::StakeExceedsSnapshotBalance) if the new stake exceeds
+    /// the voter's balance at the voting creation.
+    /// * [`VoteInTime`](crate::rules::validation::voting::VoteInTime) errors if the voting phase is closed.
+    pub fn change_vote(
+        &mut self,
+        voter: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        let mut voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let mut ballot = self.get_changeable_ballot(&voting, &configuration, voting_type, voter);
+        let outcome = voting.outcome(&configuration);
+
+        let own_stake = ballot.own_stake();
+        let new_total_stake = ballot.stake - own_stake + stake;
+        if new_total_stake.is_zero() {
+            revert(Error::ZeroStake)
+        }
+
+        if !voting.is_informal_without_stake(&configuration) {
+            if stake > own_stake {
+                self.refs.reputation_token().stake(voter, stake - own_stake);
+                self.assert_snapshot_balance(voter, stake, &voting);
+            } else if stake < own_stake {
+                self.refs
+                    .reputation_token()
+                    .unstake(voter, own_stake - stake);
+            }
+        }
+
+        let previous_choice = ballot.choice;
+        let previous_stake = ballot.stake;
+        let weight = configuration.ballot_weight(new_total_stake);
+        voting.remove_stake(previous_stake, ballot.weight, previous_choice);
+        voting.remove_vote(previous_choice);
+        voting.add_stake(new_total_stake, weight, choice);
+        voting.add_vote(choice);
+
+        ballot.choice = choice;
+        ballot.stake = new_total_stake;
+        ballot.weight = weight;
+        ballot.rationale = rationale;
+        emit_event(BallotChanged::new(&ballot, previous_choice, previous_stake));
+
+        self.ballots.set(&(voting_id, voting_type, voter), ballot);
+        self.extend_on_late_change(&mut voting, outcome, &configuration);
+        self.set_voting(voting);
+    }
+
+    /// Withdraws an existing ballot and unstakes the reputation, including the reputation staked on behalf of
+    /// the delegators - they are free to vote themselves afterwards.
+    ///
+    /// A withdrawn ballot does not count toward the quorum. The voter cannot vote again in the same phase.
+    ///
+    /// # Events
+    /// * [`BallotChanged`](BallotChanged)
+    ///
+    /// # Errors
+    /// * [`BallotDoesNotExist`](Error::BallotDoesNotExist) if the voter has not voted or his ballot is canceled.
+    /// * [`CannotChangeCreatorsBallot`](Error::CannotChangeCreatorsBallot) if the ballot was cast automatically on voting creation.
+    /// * [`VoteInTime`](crate::rules::validation::voting::VoteInTime) errors if the voting phase is closed.
+    pub fn withdraw_vote(&mut self, voter: Address, voting_id: VotingId, voting_type: VotingType) {
+        let mut voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        let mut ballot = self.get_changeable_ballot(&voting, &configuration, voting_type, voter);
+        let outcome = voting.outcome(&configuration);
+
+        if !voting.is_informal_without_stake(&configuration) {
+            self.refs.reputation_token().bulk_unstake(ballot.stakes());
+        }
+        for (delegator, _) in ballot.delegated.iter() {
+            self.delegated_ballots
+                .set(&(voting_id, voting_type, *delegator), None);
+        }
+
+        let previous_stake = ballot.stake;
+        voting.remove_stake(previous_stake, ballot.weight, ballot.choice);
+        voting.remove_vote(ballot.choice);
+
+        ballot.stake = Balance::zero();
+        ballot.weight = Balance::zero();
+        ballot.delegated = Vec::new();
+        ballot.canceled = true;
+        emit_event(BallotChanged::new(&ballot, ballot.choice, previous_stake));
+
+        self.count_withdrawn_ballot(voting_id, voting_type);
+        self.ballots.set(&(voting_id, voting_type, voter), ballot);
+        self.extend_on_late_change(&mut voting, outcome, &configuration);
+        self.set_voting(voting);
+    }
+
+    /// Commits a secret ballot in the formal phase and stakes the reputation.
+    ///
+    /// The `commitment` is a hash of the choice, the stake and a salt - see [`ballot_commitment()`].
+    /// The ballot must be revealed in the reveal phase, otherwise it is penalized when the voting ends.
+    ///
+    /// # Events
+    /// * [`BallotCommitted`](BallotCommitted)
+    ///
+    /// # Errors
+    /// * [`SecretVotingDisabled`](Error::SecretVotingDisabled) if the formal voting is not secret.
+    /// * [`CannotVoteTwice`](Error::CannotVoteTwice) if the voter already voted.
+    /// * [`ZeroStake`](Error::ZeroStake) if the stake is zero.
+    /// * [`StakeExceedsSnapshotBalance`](Error::StakeExceedsSnapshotBalance) if the stake exceeds
+    /// the voter's balance at the voting creation.
+    /// * [`VoteInTime`](crate::rules::validation::voting::VoteInTime) errors if the formal phase is closed.
+    pub fn commit_vote(
+        &mut self,
+        voter: Address,
+        voting_id: VotingId,
+        commitment: Bytes,
+        stake: Balance,
+    ) {
+        let voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        if !configuration.is_secret_formal_voting() {
+            revert(Error::SecretVotingDisabled)
+        }
+        self.assert_voting_type(&voting, VotingType::Formal);
+        voting.guard_vote(get_block_time(), &configuration);
+        self.assert_vote_doesnt_exist(voting_id, VotingType::Formal, voter);
+        let key = (voting_id, voter);
+        if self.sealed_ballots.get_or_default(&key).is_some() {
+            revert(Error::CannotVoteTwice)
+        }
+        if stake.is_zero() {
+            revert(Error::ZeroStake)
+        }
+
+        self.refs.reputation_token().stake(voter, stake);
+        self.assert_snapshot_balance(voter, stake, &voting);
+
+        let ballot = SealedBallot::new(voter, voting_id, commitment, stake);
+        emit_event(BallotCommitted::new(&ballot));
+        self.sealed_voters.get_instance(&voting_id).push(voter);
+        self.voter_stakes
+            .get_instance(&voter)
+            .push((voting_id, VotingType::Formal));
+        self.sealed_ballots.set(&key, Some(ballot));
+    }
+
+    /// Reveals a secret ballot and casts it.
+    ///
+    /// The voter may attach a `rationale` - a document explaining the choice. It is stored in the [`Ballot`].
+    ///
+    /// # Events
+    /// * [`BallotCast`](BallotCast)
+    ///
+    /// # Errors
+    /// * [`SealedBallotDoesNotExist`](Error::SealedBallotDoesNotExist) if the voter has not committed a ballot
+    /// or it has been revealed or canceled.
+    /// * [`InvalidBallotCommitment`](Error::InvalidBallotCommitment) if the choice and the salt do not match the commitment.
+    /// * [`RevealNotInProgress`](Error::RevealNotInProgress) if the reveal phase is not in progress.
+    pub fn reveal_vote(
+        &mut self,
+        voter: Address,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        let mut voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+        self.assert_voting_type(&voting, VotingType::Formal);
+        voting.guard_reveal(get_block_time(), &configuration);
+
+        let key = (voting_id, voter);
+        let mut sealed_ballot = self
+            .sealed_ballots
+            .get_or_default(&key)
+            .unwrap_or_revert_with(Error::SealedBallotDoesNotExist);
+        if !sealed_ballot.is_pending() {
+            revert(Error::SealedBallotDoesNotExist)
+        }
+        if ballot_commitment(choice, sealed_ballot.stake, &salt) != sealed_ballot.commitment {
+            revert(Error::InvalidBallotCommitment)
+        }
+
+        let stake = sealed_ballot.stake;
+        sealed_ballot.revealed = true;
+        self.sealed_ballots.set(&key, Some(sealed_ballot));
+
+        // The reputation has been staked on commit.
+        let mut ballot = Ballot::new(
+            voter,
+            voting_id,
+            VotingType::Formal,
+            choice,
+            stake,
+            false,
+            false,
+        );
+        ballot.rationale = rationale;
+        self.register_ballot(ballot, &mut voting, &configuration);
+        self.set_voting(voting);
+    }
+
+    /// Returns the voter's [`SealedBallot`].
+    pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot> {
+        self.sealed_ballots.get_or_default(&(voting_id, address))
+    }
+
+    /// Extends the current phase if the ballot has changed the result in the last moments of the phase.
+    ///
+    /// See [VotingStateMachine::extend_on_late_change()].
+    ///
+    /// # Events
+    /// * [`VotingExtended`](VotingExtended)
+    fn extend_on_late_change(
+        &self,
+        voting: &mut VotingStateMachine,
+        previous_outcome: VotingOutcome,
+        configuration: &Configuration,
+    ) {
+        if voting.extend_on_late_change(previous_outcome, get_block_time(), configuration) {
+            emit_event(VotingExtended::new(voting, configuration));
+        }
+    }
+
+    fn assert_ballot_not_secret(&self, voting: &VotingStateMachine, configuration: &Configuration) {
+        if voting.voting_type() == VotingType::Formal && configuration.is_secret_formal_voting() {
+            revert(Error::SecretBallotRequired)
+        }
+    }
+
+    fn get_changeable_ballot(
+        &self,
+        voting: &VotingStateMachine,
+        configuration: &Configuration,
+        voting_type: VotingType,
+        voter: Address,
+    ) -> Ballot {
+        self.assert_voting_type(voting, voting_type);
+        self.assert_ballot_not_secret(voting, configuration);
+        voting.guard_vote(get_block_time(), configuration);
+
+        if voting.creator() == &voter && configuration.should_cast_first_vote() {
+            revert(Error::CannotChangeCreatorsBallot)
+        }
+
+        let ballot = self
+            .get_ballot(voting.voting_id(), voting_type, voter)
+            .unwrap_or_revert_with(Error::BallotDoesNotExist);
+        if ballot.canceled || ballot.unbound {
+            revert(Error::BallotDoesNotExist)
+        }
+        ballot
+    }
+
+    /// Ensures the voter does not stake more reputation than he owned when the voting was created,
+    /// so reputation minted while the voting is in progress does not affect its outcome.
+    ///
+    /// Called after staking, so the balance errors of the Reputation Token take precedence.
+    fn assert_snapshot_balance(&self, voter: Address, stake: Balance, voting: &VotingStateMachine) {
+        let snapshot_balance = self
+            .refs
+            .reputation_token()
+            .balance_of_at(voter, voting.created_at());
+        if stake > snapshot_balance {
+            revert(Error::StakeExceedsSnapshotBalance)
+        }
     }
 
     fn assert_vote_doesnt_exist(
@@ -384,11 +1189,12 @@ impl VotingEngine {
         choice: Choice,
         stake: Balance,
         unbound: bool,
+        rationale: Option<DocumentHash>,
         voting: &mut VotingStateMachine,
         configuration: &Configuration,
     ) {
         let voting_id = voting.voting_id();
-        let ballot = Ballot::new(
+        let mut ballot = Ballot::new(
             voter,
             voting_id,
             voting.voting_type(),
@@ -397,17 +1203,38 @@ impl VotingEngine {
             unbound,
             false,
         );
+        ballot.rationale = rationale;
 
         if !unbound && !voting.is_informal_without_stake(configuration) {
             // Stake the reputation
             self.refs.reputation_token().stake(voter, stake);
+            self.assert_snapshot_balance(voter, stake, voting);
         }
 
+        self.register_ballot(ballot, voting, configuration);
+    }
+
+    fn register_ballot(
+        &mut self,
+        mut ballot: Ballot,
+        voting: &mut VotingStateMachine,
+        configuration: &Configuration,
+    ) {
+        let voting_id = voting.voting_id();
+        let voter = ballot.voter;
+        let stake = ballot.stake;
+        let weight = configuration.ballot_weight(stake);
+        ballot.weight = weight;
+        let choice = ballot.choice;
+        let unbound = ballot.unbound;
+
         emit_event(BallotCast::new(&ballot));
 
         // Add a voter to the list
         let mut voters = self.voters(voting_id, voting.voting_type());
         voters.push(voter);
+        let mut voter_ballots = self.voter_ballots.get_instance(&voter);
+        voter_ballots.push((voting_id, voting.voting_type()));
 
         // Update the votes list
         self.ballots
@@ -415,10 +1242,11 @@ impl VotingEngine {
 
         // update voting
         if unbound {
-            voting.add_unbound_stake(stake, choice)
+            voting.add_unbound_stake(weight, choice)
         } else {
-            voting.add_stake(stake, choice);
+            voting.add_stake(stake, weight, choice);
         }
+        voting.add_vote(choice);
     }
 
     /// Gets a vector of all voters' addresses.
@@ -455,6 +1283,47 @@ impl VotingEngine {
         self.voters(voting_id, voting_type).get(at)
     }
 
+    /// Returns a page of the ids of the votings that have not been finished or canceled yet.
+    ///
+    /// The pages are not stable - when a voting is removed from the list, the last one is moved to its position,
+    /// so a voting may be skipped if the list changes between the calls.
+    /// The pages of [votings_by_creator()](Self::votings_by_creator) and [ballots_of()](Self::ballots_of) are stable.
+    pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId> {
+        self.active_votings.page(offset, limit)
+    }
+
+    /// Returns a page of the ids of the votings created by the `creator`, in the order of creation.
+    pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId> {
+        let votings = self.creator_votings.get_instance(&creator);
+        page_range(offset, limit, votings.len())
+            .filter_map(|i| votings.get(i))
+            .collect()
+    }
+
+    /// Returns a page of the [Ballots](Ballot) cast by the `voter`, in the order of casting.
+    pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot> {
+        let ballots = self.voter_ballots.get_instance(&voter);
+        page_range(offset, limit, ballots.len())
+            .filter_map(|i| ballots.get(i))
+            .filter_map(|(voting_id, voting_type)| self.get_ballot(voting_id, voting_type, voter))
+            .collect()
+    }
+
+    /// Returns a page of the [Ballots](Ballot) cast in the voting with a given id and type, in the order of casting.
+    pub fn ballots_page(
+        &self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Ballot> {
+        let voters = self.voters(voting_id, voting_type);
+        page_range(offset, limit, voters.len())
+            .filter_map(|i| voters.get(i))
+            .filter_map(|voter| self.get_ballot(voting_id, voting_type, voter))
+            .collect()
+    }
+
     /// Returns the [Voting](VotingStateMachine) for a given id.
     pub fn get_voting(&self, voting_id: VotingId) -> Option<VotingStateMachine> {
         self.voting_states
@@ -481,15 +1350,201 @@ impl VotingEngine {
             .unwrap_or_revert_with(Error::ConfigurationNotFound)
     }
 
+    /// Returns a page of the finished Formal Votings, in the order of finishing.
+    ///
+    /// Each entry holds the time the voting finished and the addresses that participated in it.
+    /// Canceled ballots are not counted. A delegate's ballot counts for the delegate and for each delegator
+    /// whose reputation is staked in it.
+    pub fn get_formal_participation(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<(BlockTime, Vec<Address>)> {
+        page_range(offset, limit, self.finished_formal_votings.len())
+            .filter_map(|i| self.finished_formal_votings.get(i))
+            .map(|(voting_id, finished_at)| {
+                let mut participants = vec![];
+                for voter in self.voters(voting_id, VotingType::Formal).iter() {
+                    let ballot = match self.get_ballot(voting_id, VotingType::Formal, voter) {
+                        Some(ballot) if !ballot.canceled => ballot,
+                        _ => continue,
+                    };
+                    participants.push(voter);
+                    participants
+                        .extend(ballot.delegated.into_iter().map(|(delegator, _)| delegator));
+                }
+                (finished_at, participants)
+            })
+            .collect()
+    }
+
+    /// Returns the votings whose current phase has ended, so they can be finished by a keeper.
+    ///
+    /// Only a page of the active votings is checked, so the result may be shorter than the `limit`.
+    /// Like in [active_votings()](Self::active_votings), the pages are not stable across removals.
+    pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)> {
+        let block_time = get_block_time();
+        self.active_votings
+            .page(offset, limit)
+            .into_iter()
+            .filter_map(|voting_id| {
+                let voting = self.get_voting(voting_id)?;
+                let configuration = self.configurations.get(&voting_id)?;
+                voting
+                    .is_overdue(block_time, &configuration)
+                    .then(|| (voting_id, voting.voting_type()))
+            })
+            .collect()
+    }
+
     /// Updates voting storage.
     pub fn set_voting(&mut self, voting: VotingStateMachine) {
         self.voting_states.set(&voting.voting_id(), Some(voting))
     }
 
-    fn perform_action(&self, configuration: &Configuration) {
-        for contract_call in configuration.contract_calls() {
-            contract_call.call();
+    /// Performs the voted action or, if the `TimelockDelay` is set, queues it for the later execution.
+    /// See [Timelock::perform_or_queue()].
+    fn perform_action(&mut self, voting_id: VotingId, configuration: &Configuration) {
+        self.timelock
+            .perform_or_queue(voting_id, configuration.contract_calls(), configuration);
+    }
+
+    /// Drops the contract calls of the voting if any of them does not satisfy `is_allowed`,
+    /// so no action is performed once the voting passes.
+    ///
+    /// Returns the dropped calls.
+    pub fn drop_disallowed_calls<F>(
+        &mut self,
+        voting_id: VotingId,
+        is_allowed: F,
+    ) -> Vec<ContractCall>
+    where
+        F: Fn(&ContractCall) -> bool,
+    {
+        let mut configuration = match self.configurations.get(&voting_id) {
+            Some(configuration) => configuration,
+            None => return Vec::new(),
+        };
+        if configuration
+            .contract_calls()
+            .iter()
+            .all(|contract_call| is_allowed(contract_call))
+        {
+            return Vec::new();
+        }
+
+        let dropped = configuration.contract_calls().clone();
+        configuration.set_contract_calls(Vec::new());
+        self.configurations.set(&voting_id, configuration);
+        dropped
+    }
+
+    /// Executes the action queued by a passed voting. See [Timelock::execute()].
+    pub fn execute_action(&mut self, voting_id: VotingId) {
+        self.timelock.execute(voting_id);
+    }
+
+    /// Cancels the action queued by a passed voting. See [Timelock::cancel()].
+    ///
+    /// The action can be canceled by the guardian or by a passed veto voting, that is, by the contract
+    /// set as `VetoVoterAddress` in the [Variable Repo](crate::core_contracts::VariableRepositoryContract).
+    /// Both addresses are read when the action is canceled, so a rotation applies to the queued actions as well.
+    ///
+    /// # Events
+    /// * [`ActionCancelled`](crate::modules::timelock::events::ActionCancelled)
+    ///
+    /// # Errors
+    /// * [`CannotCancelAction`](Error::CannotCancelAction) if the caller is neither the guardian nor the veto voter.
+    pub fn cancel_action(&mut self, voting_id: VotingId) {
+        let configuration = self.current_configuration();
+        let canceller = Some(caller());
+        if configuration.guardian_address() != canceller
+            && configuration.veto_voter_address() != canceller
+        {
+            revert(Error::CannotCancelAction);
+        }
+        self.timelock.cancel(voting_id);
+    }
+
+    /// Returns the action queued by the voting with the given id.
+    pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction> {
+        self.timelock.get_queued_action(voting_id)
+    }
+
+    /// Cancels an active voting on behalf of the guardian. All the stakes are returned to the voters.
+    ///
+    /// The guardian is the `GuardianAddress` currently set in the [Variable Repo](crate::core_contracts::VariableRepositoryContract),
+    /// so a rotation applies to the votings in progress as well. The guardian can cancel one voting
+    /// per `GuardianCancelInterval` across all the voting contracts, see [record_guardian_cancel()].
+    ///
+    /// # Events
+    /// * [`VotingCanceled`] with the `reason`.
+    ///
+    /// # Errors
+    /// * [`NotGuardian`](Error::NotGuardian) if the `caller` is not the guardian.
+    /// * [`GuardianCancelTooEarly`](Error::GuardianCancelTooEarly) if the guardian has canceled a voting in any contract less than `GuardianCancelInterval` ago.
+    /// * [`VotingAlreadyCanceled`](Error::VotingAlreadyCanceled) if the voting has been canceled.
+    /// * [`VotingAlreadyFinished`](Error::VotingAlreadyFinished) if the voting has been finished.
+    pub fn guardian_cancel_voting(
+        &mut self,
+        caller: Address,
+        voting_id: VotingId,
+        reason: DocumentHash,
+    ) {
+        record_guardian_cancel(caller, &self.current_configuration());
+
+        let voting = self.get_voting_or_revert(voting_id);
+        match voting.state() {
+            VotingState::Canceled => revert(Error::VotingAlreadyCanceled),
+            VotingState::Finished => revert(Error::VotingAlreadyFinished),
+            _ => {}
+        }
+
+        self.cancel_voting(voting, Some(reason));
+    }
+
+    /// Cancels the voting on behalf of its `creator`. All the stakes are returned to the voters.
+    ///
+    /// A voting can be withdrawn only in the informal phase, as long as no one but the creator has voted.
+    ///
+    /// # Events
+    /// * [`VotingCanceled`].
+    /// * [`VotingWithdrawn`].
+    ///
+    /// # Errors
+    /// * [`NotVotingCreator`](Error::NotVotingCreator) if the `creator` has not created the voting.
+    /// * [`VotingAlreadyCanceled`](Error::VotingAlreadyCanceled) if the voting has been canceled.
+    /// * [`VotingAlreadyFinished`](Error::VotingAlreadyFinished) if the voting has been finished.
+    /// * [`VotingWithGivenTypeNotInProgress`](Error::VotingWithGivenTypeNotInProgress) if the informal phase has ended.
+    /// * [`VotingHasOtherBallots`](Error::VotingHasOtherBallots) if someone else has voted.
+    pub fn withdraw_voting(&mut self, creator: Address, voting_id: VotingId) {
+        let voting = self.get_voting_or_revert(voting_id);
+        let configuration = self.get_configuration_or_revert(voting_id);
+
+        if voting.creator() != &creator {
+            revert(Error::NotVotingCreator);
+        }
+        match voting.state() {
+            VotingState::Canceled => revert(Error::VotingAlreadyCanceled),
+            VotingState::Finished => revert(Error::VotingAlreadyFinished),
+            _ => {}
+        }
+        let state_in_time = voting.state_in_time(get_block_time(), &configuration);
+        if voting.voting_type() != VotingType::Informal
+            || !matches!(state_in_time, VotingState::Created | VotingState::Informal)
+        {
+            revert(Error::VotingWithGivenTypeNotInProgress);
+        }
+        if self
+            .voters(voting_id, VotingType::Informal)
+            .iter()
+            .any(|voter| voter != creator)
+        {
+            revert(Error::VotingHasOtherBallots);
         }
+
+        self.cancel_voting(voting, None);
+        emit_event(VotingWithdrawn::new(voting_id, creator));
     }
 
     /// Iterates over all the ballots and unstakes reputation. Returns a map of address to it's stake.
@@ -507,13 +1562,68 @@ impl VotingEngine {
             if ballot.unbound || ballot.canceled {
                 continue;
             }
-            transfers.insert(ballot.voter, ballot.stake);
-            stakes.push((ballot.voter, ballot.stake));
+            for (owner, stake) in ballot.stakes() {
+                transfers.insert(owner, stake);
+                stakes.push((owner, stake));
+            }
         }
         self.refs.reputation_token().bulk_unstake(stakes);
         transfers
     }
 
+    /// Unstakes the reputation of the secret ballots that have not been revealed and burns the penalty.
+    fn settle_unrevealed_ballots(
+        &mut self,
+        voting_id: VotingId,
+        configuration: &Configuration,
+    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
+        let mut unstakes = BTreeMap::new();
+        let mut burns = BTreeMap::new();
+        let mut stakes: Vec<(Address, Balance)> = Vec::new();
+        for voter in self.sealed_voters.get_instance(&voting_id).iter() {
+            let sealed_ballot = match self.sealed_ballots.get_or_default(&(voting_id, voter)) {
+                Some(sealed_ballot) if sealed_ballot.is_pending() => sealed_ballot,
+                _ => continue,
+            };
+            let penalty = configuration.apply_unrevealed_ballot_penalty_to(sealed_ballot.stake);
+            stakes.push((voter, sealed_ballot.stake));
+            unstakes.insert(voter, sealed_ballot.stake);
+            if !penalty.is_zero() {
+                burns.insert(voter, penalty);
+            }
+        }
+        self.refs.reputation_token().bulk_unstake(stakes);
+        self.refs
+            .reputation_token()
+            .bulk_mint_burn(BTreeMap::new(), burns.clone());
+        (unstakes, burns)
+    }
+
+    /// Cancels all the secret ballots that have not been revealed and unstakes the reputation.
+    fn unstake_sealed_ballots(&mut self, voting_id: VotingId) -> BTreeMap<Address, Balance> {
+        let mut unstakes = BTreeMap::new();
+        for voter in self.sealed_voters.get_instance(&voting_id).iter() {
+            if let Some(stake) = self.cancel_sealed_ballot(voting_id, voter) {
+                unstakes.insert(voter, stake);
+            }
+        }
+        unstakes
+    }
+
+    /// Cancels the voter's secret ballot if it has not been revealed. Returns the unstaked reputation.
+    fn cancel_sealed_ballot(&mut self, voting_id: VotingId, voter: Address) -> Option<Balance> {
+        let key = (voting_id, voter);
+        let mut sealed_ballot = self
+            .sealed_ballots
+            .get_or_default(&key)
+            .filter(|sealed_ballot| sealed_ballot.is_pending())?;
+        let stake = sealed_ballot.stake;
+        self.refs.reputation_token().unstake(voter, stake);
+        sealed_ballot.canceled = true;
+        self.sealed_ballots.set(&key, Some(sealed_ballot));
+        Some(stake)
+    }
+
     fn recast_creators_ballot_from_informal_to_formal(
         &mut self,
         voting: &mut VotingStateMachine,
@@ -529,6 +1639,7 @@ impl VotingEngine {
             Choice::InFavor,
             creator_ballot.stake,
             creator_ballot.unbound,
+            creator_ballot.rationale,
             voting,
             configuration,
         );
@@ -544,8 +1655,10 @@ impl VotingEngine {
         for i in 0..self.voters_count(voting_id, voting_type) {
             let ballot = self.get_ballot_at(voting_id, voting_type, i);
             if ballot.choice.is_in_favor() && !ballot.unbound && !ballot.canceled {
-                stakes.push((ballot.voter, ballot.stake));
-                summary.insert(ballot.voter, ballot.stake);
+                for (owner, stake) in ballot.stakes() {
+                    stakes.push((owner, stake));
+                    summary.insert(owner, stake);
+                }
             }
         }
         self.refs.reputation_token().bulk_unstake(stakes);
@@ -562,74 +1675,70 @@ impl VotingEngine {
         for i in 0..self.voters_count(voting_id, voting_type) {
             let ballot = self.get_ballot_at(voting_id, voting_type, i);
             if ballot.choice.is_against() && !ballot.unbound && !ballot.canceled {
-                stakes.push((ballot.voter, ballot.stake));
-                summary.insert(ballot.voter, ballot.stake);
+                for (owner, stake) in ballot.stakes() {
+                    stakes.push((owner, stake));
+                    summary.insert(owner, stake);
+                }
             }
         }
         self.refs.reputation_token().bulk_unstake(stakes);
         summary
     }
 
-    fn redistribute_reputation_of_no_voters(
+    fn return_abstain_voters_rep(
         &self,
-        voting: &VotingStateMachine,
+        voting_id: VotingId,
         voting_type: VotingType,
-    ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
-        let total_stake_in_favor = voting.stake_in_favor();
-        let voting_id = voting.voting_id();
-        let total_stake_against = voting.stake_against();
-        let mut burns: BTreeMap<Address, Balance> = BTreeMap::new();
-        let mut mints: BTreeMap<Address, Balance> = BTreeMap::new();
+    ) -> BTreeMap<Address, Balance> {
+        let mut summary = BTreeMap::new();
         let mut stakes: Vec<(Address, Balance)> = Vec::new();
-
         for i in 0..self.voters_count(voting_id, voting_type) {
             let ballot = self.get_ballot_at(voting_id, voting_type, i);
-            if ballot.unbound || ballot.canceled {
-                continue;
-            }
-            if ballot.choice.is_against() {
-                stakes.push((ballot.voter, ballot.stake));
-                burns.insert(ballot.voter, ballot.stake);
-            } else {
-                let amount_to_mint = total_stake_against * ballot.stake / total_stake_in_favor;
-                mints.insert(ballot.voter, amount_to_mint);
+            if ballot.choice.is_abstain() && !ballot.unbound && !ballot.canceled {
+                for (owner, stake) in ballot.stakes() {
+                    stakes.push((owner, stake));
+                    summary.insert(owner, stake);
+                }
             }
         }
         self.refs.reputation_token().bulk_unstake(stakes);
-        self.refs
-            .reputation_token()
-            .bulk_mint_burn(mints.clone(), burns.clone());
-        (mints, burns)
+        summary
     }
 
-    fn redistribute_reputation_of_yes_voters(
-        &self,
+    /// Unstakes the reputation staked on the `losing` side and redistributes it according to the `policy`.
+    ///
+    /// Delegated stakes are redistributed back to the delegators.
+    fn redistribute_reputation_of_losers(
+        &mut self,
         voting: &VotingStateMachine,
         voting_type: VotingType,
+        losing: Choice,
+        policy: &dyn RedistributionPolicy,
     ) -> (BTreeMap<Address, Balance>, BTreeMap<Address, Balance>) {
         let voting_id = voting.voting_id();
-        let total_stake_in_favor = voting.stake_in_favor();
-        let total_stake_against = voting.stake_against();
-        let mut burns: BTreeMap<Address, Balance> = BTreeMap::new();
-        let mut mints: BTreeMap<Address, Balance> = BTreeMap::new();
-        let mut stakes: Vec<(Address, Balance)> = Vec::new();
+        let mut winning_stakes: Vec<(Address, Balance)> = Vec::new();
+        let mut losing_stakes: Vec<(Address, Balance)> = Vec::new();
+
         for i in 0..self.voters_count(voting_id, voting_type) {
             let ballot = self.get_ballot_at(voting_id, voting_type, i);
-            if ballot.unbound || ballot.canceled {
+            if ballot.unbound || ballot.canceled || ballot.choice.is_abstain() {
                 continue;
             }
-            if ballot.choice.is_in_favor() {
-                stakes.push((ballot.voter, ballot.stake));
-                burns.insert(ballot.voter, ballot.stake);
+            if ballot.choice == losing {
+                losing_stakes.extend(ballot.stakes());
             } else {
-                let amount_to_mint = total_stake_in_favor * ballot.stake / total_stake_against;
-                mints.insert(ballot.voter, amount_to_mint);
+                winning_stakes.extend(ballot.stakes());
             }
         }
-        self.refs.reputation_token().bulk_unstake(stakes);
-        self.refs
-            .reputation_token()
-            .bulk_mint_burn(mints.clone(), burns.clone());
+        let (mints, burns) = policy.redistribute(&winning_stakes, &losing_stakes);
+        self.dust_ledger
+            .record_reputation_dust(redistribution_dust(&mints, &burns));
+        self.refs.reputation_token().bulk_unstake(losing_stakes);
+        if !mints.is_empty() || !burns.is_empty() {
+            self.refs
+                .reputation_token()
+                .bulk_mint_burn(mints.clone(), burns.clone());
+        }
         (mints, burns)
     }
 
@@ -647,6 +1756,14 @@ impl VotingEngine {
         self.voters(voting_id, voting_type).len()
     }
 
+    /// Gets the number of voters that count toward the quorum - withdrawn ballots are excluded.
+    fn quorum_voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32 {
+        self.voters_count(voting_id, voting_type)
+            - self
+                .withdrawn_ballots
+                .get_or_default(&(voting_id, voting_type))
+    }
+
     fn bound_ballot(
         &mut self,
         voting: &mut VotingStateMachine,
@@ -657,7 +1774,7 @@ impl VotingEngine {
             .get_ballot(voting.voting_id(), voting_type, address)
             .unwrap_or_revert_with(Error::BallotDoesNotExist);
 
-        voting.bind_stake(ballot.stake, ballot.choice);
+        voting.bind_stake(ballot.stake, ballot.weight, ballot.choice);
 
         self.refs.reputation_token().mint(address, ballot.stake);
         self.refs.reputation_token().stake(address, ballot.stake);
@@ -681,33 +1798,71 @@ impl VotingEngine {
     /// If the voter is also the creator, voting is canceled.
     /// Otherwise, only his vote is invalidated.
     ///
-    /// Returns a tuple of vectors listing canceled and affected votings.j
+    /// Only the votings created by the voter and the votings he has staked reputation in are visited -
+    /// his ballots, sealed ballots and the stakes delegated to other voters.
+    ///
+    /// Returns a tuple of vectors listing canceled and affected votings.
     pub fn slash_voter(&mut self, voter: Address) -> (Vec<VotingId>, Vec<VotingId>) {
-        let active_voting_ids = self.active_votings.get_or_default();
-        let mut affected_votings = vec![];
+        let created_votings: Vec<VotingId> =
+            self.creator_votings.get_instance(&voter).iter().collect();
         let mut canceled_votings = vec![];
-        for voting_id in active_voting_ids.into_iter() {
+        for voting_id in created_votings {
             let voting = self.get_voting_or_revert(voting_id);
-            if voting.creator() == &voter {
-                self.cancel_voting(voting);
+            if !voting.completed() {
+                self.cancel_voting(voting, None);
                 canceled_votings.push(voting_id);
-            } else if self.cancel_ballot(voting, voter) {
+            }
+        }
+
+        let staked_votings: Vec<(VotingId, VotingType)> = self
+            .voter_ballots
+            .get_instance(&voter)
+            .iter()
+            .chain(self.voter_stakes.get_instance(&voter).iter())
+            .collect();
+        let mut affected_votings = vec![];
+        for (voting_id, voting_type) in staked_votings {
+            if affected_votings.contains(&voting_id) {
+                continue;
+            }
+            let voting = self.get_voting_or_revert(voting_id);
+            if voting.completed() || voting.voting_type() != voting_type {
+                continue;
+            }
+            if self.cancel_ballot(voting, voter) {
                 affected_votings.push(voting_id);
             }
         }
         (canceled_votings, affected_votings)
     }
 
-    fn cancel_voting(&mut self, mut voting: VotingStateMachine) {
+    /// Builds a [Configuration] from the variables currently set in the Variable Repository.
+    fn current_configuration(&self) -> Configuration {
+        ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build()
+    }
+
+    fn cancel_voting(&mut self, mut voting: VotingStateMachine, reason: Option<DocumentHash>) {
         let voting_id = voting.voting_id();
         let voting_type = voting.voting_type();
-        let unstakes = self.unstake_all_reputation(voting_id, voting_type);
+        let mut unstakes = self.unstake_all_reputation(voting_id, voting_type);
+        if voting_type == VotingType::Formal {
+            unstakes.extend(self.unstake_sealed_ballots(voting_id));
+        }
         voting.cancel();
         self.set_voting(voting);
         self.remove_from_active_list(voting_id);
 
         // Emit event.
-        emit_event(VotingCanceled::new(voting_id, voting_type, unstakes));
+        emit_event(VotingCanceled::new(
+            voting_id,
+            voting_type,
+            unstakes,
+            reason,
+        ));
     }
 
     // Note: it doesn't remove a voter from self.votings to keep the quorum num right.
@@ -717,21 +1872,35 @@ impl VotingEngine {
         let voting_id = voting.voting_id();
         let ballots_key = (voting_id, voting.voting_type(), voter);
         let mut ballot = match self.ballots.get(&ballots_key) {
+            Some(ballot) if ballot.canceled => return false,
             Some(ballot) => ballot,
-            None => return false, // End method if voter never voted in this voting.
+            None => {
+                // The voter may have committed a secret ballot in this voting.
+                if voting.voting_type() == VotingType::Formal
+                    && self.cancel_sealed_ballot(voting_id, voter).is_some()
+                {
+                    return true;
+                }
+                // The voter may have his stake delegated in this voting.
+                let configuration = self.get_configuration_or_revert(voting_id);
+                let withdrawn = self.withdraw_delegated_stake(&mut voting, voter, &configuration);
+                self.set_voting(voting);
+                return withdrawn;
+            }
         };
 
-        // Unstake reputation.
-        self.refs.reputation_token().unstake(voter, ballot.stake);
+        // Unstake reputation, including the delegated one.
+        self.refs.reputation_token().bulk_unstake(ballot.stakes());
 
         // Update voting.
-        let stake = ballot.stake;
+        let weight = ballot.weight;
         let choice = ballot.choice;
         if ballot.unbound {
-            voting.remove_unbound_stake(stake, choice)
+            voting.remove_unbound_stake(weight, choice)
         } else {
-            voting.remove_stake(stake, choice);
+            voting.remove_stake(ballot.stake, weight, choice);
         }
+        voting.remove_vote(choice);
         self.set_voting(voting);
 
         // Emit event.
@@ -744,16 +1913,95 @@ impl VotingEngine {
         true
     }
 
+    /// Returns the reputation a delegate has staked on behalf of the `delegator` in the voting.
+    fn delegated_stake(&self, voting: &VotingStateMachine, delegator: Address) -> Balance {
+        let voting_id = voting.voting_id();
+        let voting_type = voting.voting_type();
+        self.delegated_ballots
+            .get_or_default(&(voting_id, voting_type, delegator))
+            .and_then(|delegate| self.ballots.get(&(voting_id, voting_type, delegate)))
+            .filter(|ballot| !ballot.canceled)
+            .and_then(|ballot| {
+                ballot
+                    .delegated
+                    .iter()
+                    .find(|(address, _)| address == &delegator)
+                    .map(|(_, stake)| *stake)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Removes the delegator's stake from the delegate's ballot and unstakes it.
+    /// A ballot left with no stake is withdrawn, like in [withdraw_vote()](Self::withdraw_vote).
+    /// Returns true if the delegator's stake was delegated in the voting.
+    fn withdraw_delegated_stake(
+        &mut self,
+        voting: &mut VotingStateMachine,
+        delegator: Address,
+        configuration: &Configuration,
+    ) -> bool {
+        let voting_id = voting.voting_id();
+        let voting_type = voting.voting_type();
+        let delegation_key = (voting_id, voting_type, delegator);
+        let delegate = match self.delegated_ballots.get_or_default(&delegation_key) {
+            Some(delegate) => delegate,
+            None => return false,
+        };
+
+        let ballots_key = (voting_id, voting_type, delegate);
+        let mut ballot = self
+            .ballots
+            .get(&ballots_key)
+            .unwrap_or_revert_with(Error::BallotDoesNotExist);
+        let position = ballot
+            .delegated
+            .iter()
+            .position(|(address, _)| address == &delegator)
+            .unwrap_or_revert_with(Error::BallotDoesNotExist);
+        let (_, stake) = ballot.delegated.remove(position);
+
+        // A canceled ballot has been unstaked already.
+        if !ballot.canceled {
+            if !voting.is_informal_without_stake(configuration) {
+                self.refs.reputation_token().unstake(delegator, stake);
+            }
+            voting.remove_stake(ballot.stake, ballot.weight, ballot.choice);
+            ballot.stake -= stake;
+            ballot.weight = configuration.ballot_weight(ballot.stake);
+            if ballot.stake.is_zero() {
+                voting.remove_vote(ballot.choice);
+                ballot.canceled = true;
+                self.count_withdrawn_ballot(voting_id, voting_type);
+            } else {
+                voting.add_stake(ballot.stake, ballot.weight, ballot.choice);
+            }
+        }
+        self.ballots.set(&ballots_key, ballot);
+        self.delegated_ballots.set(&delegation_key, None);
+
+        emit_event(DelegatedStakeWithdrawn::new(
+            delegator,
+            delegate,
+            voting_id,
+            voting_type,
+            stake,
+        ));
+
+        true
+    }
+
+    fn count_withdrawn_ballot(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        let key = (voting_id, voting_type);
+        let withdrawn = self.withdrawn_ballots.get_or_default(&key);
+        self.withdrawn_ballots.set(&key, withdrawn + 1);
+    }
+
     fn add_to_active_list(&mut self, voting_id: VotingId) {
-        let mut active_list = self.active_votings.get_or_default();
-        active_list.push(voting_id);
-        self.active_votings.set(active_list);
+        self.active_votings.push(voting_id);
     }
 
     fn remove_from_active_list(&mut self, voting_id: VotingId) {
-        let mut active_list = self.active_votings.get_or_default();
-        active_list.retain(|&id| id != voting_id);
-        self.active_votings.set(active_list);
+        self.active_votings.remove(voting_id);
     }
 }
 
@@ -766,3 +2014,16 @@ fn add_to_map(
         target.insert((addr, reason), amount);
     }
 }
+
+/// Turns the [vote_batch()](VotingEngine::vote_batch) items into the
+/// [vote_batch_with_rationale()](VotingEngine::vote_batch_with_rationale) items with no rationale.
+pub fn without_rationale(
+    ballots: Vec<(VotingId, VotingType, Choice, Balance)>,
+) -> Vec<(VotingId, VotingType, Choice, Balance, Option<DocumentHash>)> {
+    ballots
+        .into_iter()
+        .map(|(voting_id, voting_type, choice, stake)| {
+            (voting_id, voting_type, choice, stake, None)
+        })
+        .collect()
+}
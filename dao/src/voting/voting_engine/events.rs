@@ -1,11 +1,13 @@
 //! Set of voting events.
 use crate::configuration::Configuration;
-use crate::voting::ballot::{Ballot, Choice};
+use crate::utils::types::DocumentHash;
+use crate::voting::ballot::{Ballot, Choice, SealedBallot};
+use crate::voting::reputation_redistribution::RedistributionPolicyKind;
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::voting_state_machine::{
     Stats, VotingResult, VotingStateMachine, VotingType,
 };
-use odra::types::{Address, Balance, BlockTime};
+use odra::types::{Address, Balance, BlockTime, Bytes};
 use odra::{Event, OdraType};
 use std::collections::BTreeMap;
 
@@ -20,6 +22,8 @@ pub enum Reason {
     FormalWon = 3,
     /// Voting process finished, voters voted against.
     FormalLost = 4,
+    /// Voting process finished, a secret ballot has not been revealed.
+    BallotNotRevealed = 5,
 }
 
 /// Event thrown after ballot is cast.
@@ -31,10 +35,16 @@ pub struct BallotCast {
     pub voting_id: VotingId,
     /// Voting type (Formal/Informal).
     pub voting_type: VotingType,
-    /// Selected option.
+    /// Selected option - `in favor`, `against` or `abstain`.
     pub choice: Choice,
     /// Vote power.
     pub stake: Balance,
+    /// Effective vote power recorded in the voting stats.
+    pub weight: Balance,
+    /// Reputation staked on behalf of the delegators.
+    pub delegated: Vec<(Address, Balance)>,
+    /// An optional document explaining the choice.
+    pub rationale: Option<DocumentHash>,
 }
 
 impl BallotCast {
@@ -45,6 +55,106 @@ impl BallotCast {
             voting_type: ballot.voting_type,
             choice: ballot.choice,
             stake: ballot.stake,
+            weight: ballot.weight,
+            delegated: ballot.delegated.clone(),
+            rationale: ballot.rationale.clone(),
+        }
+    }
+}
+
+/// Event thrown after a ballot is changed or withdrawn.
+///
+/// A withdrawn ballot has a zero stake.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct BallotChanged {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type (Formal/Informal).
+    pub voting_type: VotingType,
+    /// Previously selected option.
+    pub previous_choice: Choice,
+    /// Previous vote power.
+    pub previous_stake: Balance,
+    /// Selected option.
+    pub choice: Choice,
+    /// Vote power.
+    pub stake: Balance,
+    /// An optional document explaining the choice.
+    pub rationale: Option<DocumentHash>,
+}
+
+impl BallotChanged {
+    pub fn new(ballot: &Ballot, previous_choice: Choice, previous_stake: Balance) -> Self {
+        Self {
+            voter: ballot.voter,
+            voting_id: ballot.voting_id,
+            voting_type: ballot.voting_type,
+            previous_choice,
+            previous_stake,
+            choice: ballot.choice,
+            stake: ballot.stake,
+            rationale: ballot.rationale.clone(),
+        }
+    }
+}
+
+/// Event thrown after a delegator's stake is withdrawn from the delegate's ballot.
+///
+/// It happens when the delegator overrides the delegate's vote or is slashed.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct DelegatedStakeWithdrawn {
+    /// The delegator's address.
+    pub delegator: Address,
+    /// The delegate's address.
+    pub delegate: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type (Formal/Informal).
+    pub voting_type: VotingType,
+    /// Withdrawn stake.
+    pub stake: Balance,
+}
+
+impl DelegatedStakeWithdrawn {
+    pub fn new(
+        delegator: Address,
+        delegate: Address,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        stake: Balance,
+    ) -> Self {
+        Self {
+            delegator,
+            delegate,
+            voting_id,
+            voting_type,
+            stake,
+        }
+    }
+}
+
+/// Event thrown after a secret ballot is committed.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct BallotCommitted {
+    /// The voter's address.
+    pub voter: Address,
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// A hash of the choice, the stake and a salt.
+    pub commitment: Bytes,
+    /// Staked reputation.
+    pub stake: Balance,
+}
+
+impl BallotCommitted {
+    pub fn new(ballot: &SealedBallot) -> Self {
+        Self {
+            voter: ballot.voter,
+            voting_id: ballot.voting_id,
+            commitment: ballot.commitment.clone(),
+            stake: ballot.stake,
         }
     }
 }
@@ -66,6 +176,14 @@ pub struct VotingCreatedInfo {
     pub config_formal_quorum: u32,
     /// Configuration value - [formal voting time](Configuration::formal_voting_time()).
     pub config_formal_voting_time: u64,
+    /// Configuration value - [is the quorum stake weighted](Configuration::is_stake_weighted_quorum()).
+    pub config_stake_weighted_quorum: bool,
+    /// Configuration value - [informal voting stake quorum](Configuration::informal_voting_stake_quorum()).
+    pub config_informal_stake_quorum: Balance,
+    /// Configuration value - [formal voting stake quorum](Configuration::formal_voting_stake_quorum()).
+    pub config_formal_stake_quorum: Balance,
+    /// Configuration value - [pass threshold](Configuration::pass_threshold()).
+    pub config_pass_threshold: Balance,
     /// Configuration value - [total number of onboarded users](Configuration::total_onboarded()).
     pub config_total_onboarded: Balance,
     /// Configuration value - [is the time between votes doubled](Configuration::should_double_time_between_votings()).
@@ -91,6 +209,10 @@ impl VotingCreatedInfo {
             config_informal_voting_time: config.informal_voting_time(),
             config_formal_quorum: config.formal_voting_quorum(),
             config_formal_voting_time: config.formal_voting_time(),
+            config_stake_weighted_quorum: config.is_stake_weighted_quorum(),
+            config_informal_stake_quorum: config.informal_voting_stake_quorum(),
+            config_formal_stake_quorum: config.formal_voting_stake_quorum(),
+            config_pass_threshold: config.pass_threshold(),
             config_total_onboarded: config.total_onboarded(),
             config_voting_clearness_delta: config.voting_clearness_delta(),
             config_double_time_between_votings: config.should_double_time_between_votings(),
@@ -110,23 +232,34 @@ pub struct VotingEnded {
     pub stake_against: Balance,
     pub unbound_stake_in_favor: Balance,
     pub unbound_stake_against: Balance,
+    pub stake_abstain: Balance,
+    pub unbound_stake_abstain: Balance,
     pub votes_in_favor: u32,
     pub votes_against: u32,
+    pub votes_abstain: u32,
+    pub pass_threshold: Balance,
+    /// Indicates if the voting ended before the end of the formal phase, as the result could no longer change.
+    pub ended_early: bool,
     pub unstakes: BTreeMap<(Address, Reason), Balance>,
     pub stakes: BTreeMap<(Address, Reason), Balance>,
     pub burns: BTreeMap<(Address, Reason), Balance>,
     pub mints: BTreeMap<(Address, Reason), Balance>,
+    /// The policy applied to the losing stake, if the reputation has been redistributed.
+    pub redistribution_policy: Option<RedistributionPolicyKind>,
 }
 
 impl VotingEnded {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         voting: &VotingStateMachine,
         voting_result: VotingResult,
         stats: &Stats,
+        configuration: &Configuration,
         unstakes: BTreeMap<(Address, Reason), Balance>,
         stakes: BTreeMap<(Address, Reason), Balance>,
         burns: BTreeMap<(Address, Reason), Balance>,
         mints: BTreeMap<(Address, Reason), Balance>,
+        redistribution_policy: Option<RedistributionPolicyKind>,
     ) -> Self {
         Self {
             voting_id: voting.voting_id(),
@@ -136,12 +269,18 @@ impl VotingEnded {
             stake_against: stats.stake_against,
             unbound_stake_in_favor: stats.unbound_stake_in_favor,
             unbound_stake_against: stats.unbound_stake_against,
+            stake_abstain: stats.stake_abstain,
+            unbound_stake_abstain: stats.unbound_stake_abstain,
             votes_in_favor: stats.votes_in_favor,
             votes_against: stats.votes_against,
+            votes_abstain: stats.votes_abstain,
+            pass_threshold: configuration.pass_threshold(),
+            ended_early: voting.ended_early(),
             unstakes,
             stakes,
             burns,
             mints,
+            redistribution_policy,
         }
     }
 }
@@ -173,7 +312,7 @@ impl BallotCanceled {
     }
 }
 
-/// Event thrown after voting is canceled during full slashing.
+/// Event thrown after voting is canceled during full slashing or by the guardian.
 #[derive(Debug, PartialEq, Eq, Event)]
 pub struct VotingCanceled {
     /// A unique voting id.
@@ -182,6 +321,8 @@ pub struct VotingCanceled {
     pub voting_type: VotingType,
     /// Map of voters' addresses to their canceled stakes.
     pub unstakes: BTreeMap<Address, Balance>,
+    /// A hash of the document explaining why the voting was canceled. `None` if canceled during slashing.
+    pub reason: Option<DocumentHash>,
 }
 
 impl VotingCanceled {
@@ -189,11 +330,56 @@ impl VotingCanceled {
         voting_id: VotingId,
         voting_type: VotingType,
         unstakes: BTreeMap<Address, Balance>,
+        reason: Option<DocumentHash>,
     ) -> Self {
         Self {
             voting_id,
             voting_type,
             unstakes,
+            reason,
+        }
+    }
+}
+
+/// Event thrown after a voting is withdrawn by its creator.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct VotingWithdrawn {
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// The creator of the voting.
+    pub creator: Address,
+}
+
+impl VotingWithdrawn {
+    pub fn new(voting_id: VotingId, creator: Address) -> Self {
+        Self { voting_id, creator }
+    }
+}
+
+/// Event thrown after a voting phase is extended due to a late change of the result.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct VotingExtended {
+    /// A unique voting id.
+    pub voting_id: VotingId,
+    /// Voting type (Formal/Informal).
+    pub voting_type: VotingType,
+    /// The new end time of the phase.
+    pub end_time: BlockTime,
+    /// The total extension of the phase.
+    pub extension: BlockTime,
+}
+
+impl VotingExtended {
+    pub fn new(voting: &VotingStateMachine, configuration: &Configuration) -> Self {
+        let extension = match voting.voting_type() {
+            VotingType::Informal => voting.informal_extension(),
+            VotingType::Formal => voting.formal_extension(),
+        };
+        Self {
+            voting_id: voting.voting_id(),
+            voting_type: voting.voting_type(),
+            end_time: voting.current_phase_end_time(configuration),
+            extension,
         }
     }
 }
@@ -1,11 +1,14 @@
 //! Voting State Machine.
 use crate::configuration::Configuration;
-use crate::rules::validation::voting::{AfterFormalVoting, VoteInTime, VotingNotCompleted};
+use crate::rules::validation::voting::{
+    AfterFormalVoting, RevealInTime, VoteInTime, VotingNotCompleted,
+};
 use crate::rules::RulesBuilder;
+use crate::utils::{is_per_mil_reached, Error};
 use crate::voting::ballot::Choice;
 use crate::voting::types::VotingId;
 use odra::types::{Address, Balance, BlockTime};
-use odra::OdraType;
+use odra::{OdraType, UnwrapOrRevert};
 
 /// Serializable voting state with a state machine capabilities.
 ///
@@ -19,6 +22,9 @@ pub struct VotingStateMachine {
     formal_stats: Stats,
     created_at: u64,
     creator: Address,
+    informal_extension: BlockTime,
+    formal_extension: BlockTime,
+    ended_early: bool,
 }
 
 impl VotingStateMachine {
@@ -32,6 +38,9 @@ impl VotingStateMachine {
             formal_stats: Default::default(),
             created_at,
             creator,
+            informal_extension: 0,
+            formal_extension: 0,
+            ended_early: false,
         }
     }
 
@@ -42,17 +51,33 @@ impl VotingStateMachine {
             result_close = true;
         }
 
-        self.state = VotingState::Formal;
-        self.voting_type = VotingType::Formal;
+        self.start_formal_voting();
 
         result_close
     }
 
+    /// Updates voting type to [Formal](VotingType::Formal).
+    pub fn start_formal_voting(&mut self) {
+        self.state = VotingState::Formal;
+        self.voting_type = VotingType::Formal;
+    }
+
     /// Ends the voting process gracefully.
     pub fn finish(&mut self) {
         self.state = VotingState::Finished;
     }
 
+    /// Ends the voting process before the end of the formal phase, as the result can no longer change.
+    pub fn finish_early(&mut self) {
+        self.state = VotingState::Finished;
+        self.ended_early = true;
+    }
+
+    /// Indicates if the voting process has ended before the end of the formal phase.
+    pub fn ended_early(&self) -> bool {
+        self.ended_early
+    }
+
     /// Ends the voting process forcefully and cancels the result.
     pub fn cancel(&mut self) {
         self.state = VotingState::Canceled;
@@ -63,29 +88,37 @@ impl VotingStateMachine {
         self.voting_type
     }
 
-    /// Checks if Voting is of type [Informal](VotingType::Informal) and stakes the reputation.
+    /// Checks if Voting is of type [Informal](VotingType::Informal) and does not stake the reputation.
+    ///
+    /// A [Signal Voting](Configuration::is_signal()) never stakes the reputation.
     pub fn is_informal_without_stake(&self, configuration: &Configuration) -> bool {
-        !configuration.informal_stake_reputation() && self.voting_type() == VotingType::Informal
+        (!configuration.informal_stake_reputation() || configuration.is_signal())
+            && self.voting_type() == VotingType::Informal
     }
 
-    /// Checks if Voting is still in the voting phase.
+    /// Checks if the current voting phase has ended.
     pub fn is_in_time(&self, block_time: u64, configuration: &Configuration) -> bool {
+        self.current_phase_end_time(configuration) <= block_time
+    }
+
+    /// Checks if the current voting phase has ended at the given time and the voting waits to be finished.
+    pub fn is_overdue(&self, block_time: BlockTime, configuration: &Configuration) -> bool {
+        if self.completed() {
+            return false;
+        }
         match self.voting_type() {
-            VotingType::Informal => {
-                let start_time = self.informal_voting_start_time(configuration);
-                let voting_time = configuration.informal_voting_time();
-                start_time + voting_time <= block_time
-            }
+            VotingType::Informal => self.is_in_time(block_time, configuration),
             VotingType::Formal => {
-                self.informal_voting_start_time(configuration) + configuration.formal_voting_time()
-                    <= block_time
+                self.state_in_time(block_time, configuration) == VotingState::Finished
             }
         }
     }
 
-    /// Gets the informal phase end time.
+    /// Gets the informal phase end time, including the extension.
     pub fn informal_voting_end_time(&self, configuration: &Configuration) -> BlockTime {
-        self.informal_voting_start_time(configuration) + configuration.informal_voting_time()
+        self.informal_voting_start_time(configuration)
+            + configuration.informal_voting_time()
+            + self.informal_extension
     }
 
     /// Gets the informal-formal break end time.
@@ -94,26 +127,103 @@ impl VotingStateMachine {
             + configuration.time_between_informal_and_formal_voting()
     }
 
-    /// Gets the informal phase end time.
+    /// Gets the formal phase end time, including the extension.
     pub fn formal_voting_end_time(&self, configuration: &Configuration) -> BlockTime {
-        self.time_between_votings_end_time(configuration) + configuration.formal_voting_time()
+        self.time_between_votings_end_time(configuration)
+            + configuration.formal_voting_time()
+            + self.formal_extension
     }
 
-    /// Checks is the `in_favor` stake surpasses the `against` stake.
-    pub fn is_in_favor(&self) -> bool {
+    /// Gets the end time of the current phase.
+    pub fn current_phase_end_time(&self, configuration: &Configuration) -> BlockTime {
         match self.voting_type() {
-            VotingType::Informal => {
-                self.informal_stats.stake_in_favor >= self.informal_stats.stake_against
-            }
-            VotingType::Formal => {
-                self.formal_stats.stake_in_favor >= self.formal_stats.stake_against
-            }
+            VotingType::Informal => self.informal_voting_end_time(configuration),
+            VotingType::Formal => self.formal_voting_end_time(configuration),
+        }
+    }
+
+    /// Gets the time the informal phase has been extended by.
+    pub fn informal_extension(&self) -> BlockTime {
+        self.informal_extension
+    }
+
+    /// Gets the time the formal phase has been extended by.
+    pub fn formal_extension(&self) -> BlockTime {
+        self.formal_extension
+    }
+
+    /// Gets the leading side of the current phase and tells if the result is close.
+    pub fn outcome(&self, configuration: &Configuration) -> VotingOutcome {
+        VotingOutcome {
+            in_favor: self.is_in_favor(configuration),
+            close: self.is_result_close(configuration),
         }
     }
 
+    /// Extends the current phase if a ballot cast in the last moments of the phase has changed
+    /// the leading side or moved the result within the [voting clearness delta](Configuration::voting_clearness_delta()).
+    ///
+    /// `previous_outcome` is the [outcome](Self::outcome()) before the ballot was cast.
+    /// The end time is pushed back by the [extension time](Configuration::voting_extension_time()),
+    /// but the total extension of a phase cannot exceed the [extension cap](Configuration::voting_extension_cap()).
+    ///
+    /// Returns true if the phase has been extended.
+    pub fn extend_on_late_change(
+        &mut self,
+        previous_outcome: VotingOutcome,
+        block_time: BlockTime,
+        configuration: &Configuration,
+    ) -> bool {
+        let window = configuration.voting_extension_window();
+        if window == 0 || block_time + window < self.current_phase_end_time(configuration) {
+            return false;
+        }
+
+        let outcome = self.outcome(configuration);
+        let side_changed = outcome.in_favor != previous_outcome.in_favor;
+        let became_close = outcome.close && !previous_outcome.close;
+        if !side_changed && !became_close {
+            return false;
+        }
+
+        let extension = match self.voting_type() {
+            VotingType::Informal => &mut self.informal_extension,
+            VotingType::Formal => &mut self.formal_extension,
+        };
+        let extended = (*extension + configuration.voting_extension_time())
+            .min(configuration.voting_extension_cap());
+        if extended <= *extension {
+            return false;
+        }
+        *extension = extended;
+        true
+    }
+
+    /// Gets the reveal phase end time. Equals to the formal phase end time if the formal voting is not secret.
+    pub fn reveal_end_time(&self, configuration: &Configuration) -> BlockTime {
+        self.formal_voting_end_time(configuration) + configuration.formal_voting_reveal_time()
+    }
+
+    /// Checks if the `in_favor` stake reaches the [pass threshold](Configuration::pass_threshold())
+    /// of the sum of the `in_favor` and `against` stakes.
+    pub fn is_in_favor(&self, configuration: &Configuration) -> bool {
+        let stats = match self.voting_type() {
+            VotingType::Informal => &self.informal_stats,
+            VotingType::Formal => &self.formal_stats,
+        };
+        // overflow is not possible due to reputation token having Balance as max
+        let decisive_stake = stats.stake_in_favor + stats.stake_against;
+        is_per_mil_reached(
+            stats.stake_in_favor,
+            decisive_stake,
+            configuration.pass_threshold(),
+        )
+        .unwrap_or_revert_with(Error::ArithmeticOverflow)
+    }
+
     /// Depending on the result of the voting, returns the amount of reputation staked on the winning side.
-    pub fn get_winning_stake(&self) -> Balance {
-        match (self.voting_type(), self.is_in_favor()) {
+    pub fn get_winning_stake(&self, configuration: &Configuration) -> Balance {
+        match (self.voting_type(), self.is_in_favor(configuration)) {
             (VotingType::Informal, true) => self.informal_stats.stake_in_favor,
             (VotingType::Informal, false) => self.informal_stats.stake_against,
             (VotingType::Formal, true) => self.formal_stats.stake_in_favor,
@@ -121,25 +231,68 @@ impl VotingStateMachine {
         }
     }
 
+    /// Checks if the result of the current phase can no longer change.
+    ///
+    /// `joinable` is the maximal vote power that can still be added to the losing side,
+    /// `leavable` is the maximal vote power that can still be withdrawn from the winning side.
+    pub fn is_result_locked(
+        &self,
+        joinable: Balance,
+        leavable: Balance,
+        configuration: &Configuration,
+    ) -> bool {
+        let in_favor = self.is_in_favor(configuration);
+        // overflow is not possible due to reputation token having Balance as max
+        let (stake_in_favor, stake_against) = if in_favor {
+            (
+                self.stake_in_favor().saturating_sub(leavable),
+                self.stake_against() + joinable,
+            )
+        } else {
+            (
+                self.stake_in_favor() + joinable,
+                self.stake_against().saturating_sub(leavable),
+            )
+        };
+        let worst_case = is_per_mil_reached(
+            stake_in_favor,
+            stake_in_favor + stake_against,
+            configuration.pass_threshold(),
+        )
+        .unwrap_or_revert_with(Error::ArithmeticOverflow);
+        worst_case == in_favor
+    }
+
     /// Gets the current voting result.
+    ///
+    /// `voters_number` includes the voters who abstained, so they count toward the quorum,
+    /// but do not affect the outcome.
+    ///
+    /// If the [stake weighted quorum](Configuration::is_stake_weighted_quorum()) is enabled,
+    /// the total bound stake is compared to the quorum instead of `voters_number`.
     pub fn get_result(&self, voters_number: u32, configuration: &Configuration) -> VotingResult {
-        if self.get_quorum(configuration) > voters_number {
+        if !self.is_quorum_reached(voters_number, configuration) {
             VotingResult::QuorumNotReached
-        } else if self.is_in_favor() {
+        } else if self.is_in_favor(configuration) {
             VotingResult::InFavor
         } else {
             VotingResult::Against
         }
     }
 
-    /// Adds the `stake` to the total bound stake.
-    pub fn add_stake(&mut self, stake: Balance, choice: Choice) {
+    /// Adds the `weight` to the total bound stake and the `stake` to the total bound reputation.
+    ///
+    /// The weight differs from the stake in the [quadratic voting](Configuration::is_quadratic_voting()).
+    pub fn add_stake(&mut self, stake: Balance, weight: Balance, choice: Choice) {
         // overflow is not possible due to reputation token having Balance as max
+        self.stats_mut().bound_reputation += stake;
         match (self.voting_type(), choice) {
-            (VotingType::Informal, Choice::InFavor) => self.informal_stats.stake_in_favor += stake,
-            (VotingType::Informal, Choice::Against) => self.informal_stats.stake_against += stake,
-            (VotingType::Formal, Choice::InFavor) => self.formal_stats.stake_in_favor += stake,
-            (VotingType::Formal, Choice::Against) => self.formal_stats.stake_against += stake,
+            (VotingType::Informal, Choice::InFavor) => self.informal_stats.stake_in_favor += weight,
+            (VotingType::Informal, Choice::Against) => self.informal_stats.stake_against += weight,
+            (VotingType::Informal, Choice::Abstain) => self.informal_stats.stake_abstain += weight,
+            (VotingType::Formal, Choice::InFavor) => self.formal_stats.stake_in_favor += weight,
+            (VotingType::Formal, Choice::Against) => self.formal_stats.stake_against += weight,
+            (VotingType::Formal, Choice::Abstain) => self.formal_stats.stake_abstain += weight,
         }
     }
 
@@ -153,23 +306,32 @@ impl VotingStateMachine {
             (VotingType::Informal, Choice::Against) => {
                 self.informal_stats.unbound_stake_against += stake
             }
+            (VotingType::Informal, Choice::Abstain) => {
+                self.informal_stats.unbound_stake_abstain += stake
+            }
             (VotingType::Formal, Choice::InFavor) => {
                 self.formal_stats.unbound_stake_in_favor += stake
             }
             (VotingType::Formal, Choice::Against) => {
                 self.formal_stats.unbound_stake_against += stake
             }
+            (VotingType::Formal, Choice::Abstain) => {
+                self.formal_stats.unbound_stake_abstain += stake
+            }
         }
     }
 
-    /// Removes the `stake` from the total bound stake.
-    pub fn remove_stake(&mut self, stake: Balance, choice: Choice) {
+    /// Removes the `weight` from the total bound stake and the `stake` from the total bound reputation.
+    pub fn remove_stake(&mut self, stake: Balance, weight: Balance, choice: Choice) {
         // overflow is not possible due to reputation token having Balance as max
+        self.stats_mut().bound_reputation -= stake;
         match (self.voting_type(), choice) {
-            (VotingType::Informal, Choice::InFavor) => self.informal_stats.stake_in_favor -= stake,
-            (VotingType::Informal, Choice::Against) => self.informal_stats.stake_against -= stake,
-            (VotingType::Formal, Choice::InFavor) => self.formal_stats.stake_in_favor -= stake,
-            (VotingType::Formal, Choice::Against) => self.formal_stats.stake_against -= stake,
+            (VotingType::Informal, Choice::InFavor) => self.informal_stats.stake_in_favor -= weight,
+            (VotingType::Informal, Choice::Against) => self.informal_stats.stake_against -= weight,
+            (VotingType::Informal, Choice::Abstain) => self.informal_stats.stake_abstain -= weight,
+            (VotingType::Formal, Choice::InFavor) => self.formal_stats.stake_in_favor -= weight,
+            (VotingType::Formal, Choice::Against) => self.formal_stats.stake_against -= weight,
+            (VotingType::Formal, Choice::Abstain) => self.formal_stats.stake_abstain -= weight,
         }
     }
 
@@ -183,19 +345,45 @@ impl VotingStateMachine {
             (VotingType::Informal, Choice::Against) => {
                 self.informal_stats.unbound_stake_against -= stake
             }
+            (VotingType::Informal, Choice::Abstain) => {
+                self.informal_stats.unbound_stake_abstain -= stake
+            }
             (VotingType::Formal, Choice::InFavor) => {
                 self.formal_stats.unbound_stake_in_favor -= stake
             }
             (VotingType::Formal, Choice::Against) => {
                 self.formal_stats.unbound_stake_against -= stake
             }
+            (VotingType::Formal, Choice::Abstain) => {
+                self.formal_stats.unbound_stake_abstain -= stake
+            }
+        }
+    }
+
+    /// Increments the number of votes cast for the given `choice`.
+    pub fn add_vote(&mut self, choice: Choice) {
+        let stats = self.stats_mut();
+        match choice {
+            Choice::InFavor => stats.votes_in_favor += 1,
+            Choice::Against => stats.votes_against += 1,
+            Choice::Abstain => stats.votes_abstain += 1,
         }
     }
 
-    /// Removes the unbound stake and adds it to the bound stake.
-    pub fn bind_stake(&mut self, stake: Balance, choice: Choice) {
-        self.remove_unbound_stake(stake, choice);
-        self.add_stake(stake, choice);
+    /// Decrements the number of votes cast for the given `choice`.
+    pub fn remove_vote(&mut self, choice: Choice) {
+        let stats = self.stats_mut();
+        match choice {
+            Choice::InFavor => stats.votes_in_favor -= 1,
+            Choice::Against => stats.votes_against -= 1,
+            Choice::Abstain => stats.votes_abstain -= 1,
+        }
+    }
+
+    /// Removes the unbound `weight` and adds it to the bound stake. The `stake` is added to the total bound reputation.
+    pub fn bind_stake(&mut self, stake: Balance, weight: Balance, choice: Choice) {
+        self.remove_unbound_stake(weight, choice);
+        self.add_stake(stake, weight, choice);
     }
 
     /// Gets the sum of bound and unbound stake.
@@ -204,29 +392,47 @@ impl VotingStateMachine {
         self.total_bound_stake() + self.total_unbound_stake()
     }
 
-    /// Gets the total bound stake.
+    /// Gets the total bound stake, including the `abstain` stake.
     pub fn total_bound_stake(&self) -> Balance {
         // overflow is not possible due to reputation token having Balance as max
         match self.voting_type() {
             VotingType::Informal => {
-                self.informal_stats.stake_in_favor + self.informal_stats.stake_against
+                self.informal_stats.stake_in_favor
+                    + self.informal_stats.stake_against
+                    + self.informal_stats.stake_abstain
             }
             VotingType::Formal => {
-                self.formal_stats.stake_in_favor + self.formal_stats.stake_against
+                self.formal_stats.stake_in_favor
+                    + self.formal_stats.stake_against
+                    + self.formal_stats.stake_abstain
             }
         }
     }
 
-    /// Gets the total unbound stake.
+    /// Gets the total reputation staked on the bound ballots, including the `abstain` ones.
+    ///
+    /// Unlike [total_bound_stake()](Self::total_bound_stake), it is not affected by
+    /// the [quadratic voting](Configuration::is_quadratic_voting()).
+    pub fn total_bound_reputation(&self) -> Balance {
+        match self.voting_type() {
+            VotingType::Informal => self.informal_stats.bound_reputation,
+            VotingType::Formal => self.formal_stats.bound_reputation,
+        }
+    }
+
+    /// Gets the total unbound stake, including the `abstain` stake.
     pub fn total_unbound_stake(&self) -> Balance {
         // overflow is not possible due to reputation token having Balance as max
         match self.voting_type() {
             VotingType::Informal => {
                 self.informal_stats.unbound_stake_in_favor
                     + self.informal_stats.unbound_stake_against
+                    + self.informal_stats.unbound_stake_abstain
             }
             VotingType::Formal => {
-                self.formal_stats.unbound_stake_in_favor + self.formal_stats.unbound_stake_against
+                self.formal_stats.unbound_stake_in_favor
+                    + self.formal_stats.unbound_stake_against
+                    + self.formal_stats.unbound_stake_abstain
             }
         }
     }
@@ -252,6 +458,14 @@ impl VotingStateMachine {
         }
     }
 
+    /// Get the voting's abstain stake.
+    pub fn stake_abstain(&self) -> Balance {
+        match self.voting_type() {
+            VotingType::Informal => self.informal_stats.stake_abstain,
+            VotingType::Formal => self.formal_stats.stake_abstain,
+        }
+    }
+
     /// Gets the voting creator.
     pub fn creator(&self) -> &Address {
         &self.creator
@@ -277,6 +491,7 @@ impl VotingStateMachine {
         let informal_voting_end = self.informal_voting_end_time(configuration);
         let between_voting_end = self.time_between_votings_end_time(configuration);
         let voting_end = self.formal_voting_end_time(configuration);
+        let reveal_end = self.reveal_end_time(configuration);
 
         if block_time < informal_voting_start {
             VotingState::Created
@@ -286,6 +501,8 @@ impl VotingStateMachine {
             VotingState::BetweenVotings
         } else if block_time > between_voting_end && block_time <= voting_end {
             VotingState::Formal
+        } else if block_time > voting_end && block_time <= reveal_end {
+            VotingState::Reveal
         } else {
             VotingState::Finished
         }
@@ -312,11 +529,36 @@ impl VotingStateMachine {
     fn is_result_close(&self, configuration: &Configuration) -> bool {
         let stake_in_favor = self.stake_in_favor() + self.unbound_stake_in_favor();
         let stake_against = self.stake_against() + self.unbound_stake_against();
+        let total_stake = self.total_stake();
+        if total_stake.is_zero() {
+            return false;
+        }
         let stake_diff = stake_in_favor.abs_diff(stake_against);
-        let stake_diff_percent = stake_diff.saturating_mul(Balance::from(100)) / self.total_stake();
+        let stake_diff_percent = stake_diff.saturating_mul(Balance::from(100)) / total_stake;
         stake_diff_percent <= configuration.voting_clearness_delta()
     }
 
+    fn is_quorum_reached(&self, voters_number: u32, configuration: &Configuration) -> bool {
+        self.is_quorum_reached_by(voters_number, self.total_bound_reputation(), configuration)
+    }
+
+    /// Checks if the given number of voters or the `stake` (if the [stake weighted quorum](Configuration::is_stake_weighted_quorum())
+    /// is enabled) reaches the quorum of the current voting phase.
+    ///
+    /// The `stake` is compared to a part of the total reputation, so it must not be weighted.
+    pub fn is_quorum_reached_by(
+        &self,
+        voters_number: u32,
+        stake: Balance,
+        configuration: &Configuration,
+    ) -> bool {
+        if configuration.is_stake_weighted_quorum() {
+            self.get_stake_quorum(configuration) <= stake
+        } else {
+            self.get_quorum(configuration) <= voters_number
+        }
+    }
+
     fn get_quorum(&self, configuration: &Configuration) -> u32 {
         match self.voting_type() {
             VotingType::Informal => configuration.informal_voting_quorum(),
@@ -324,6 +566,20 @@ impl VotingStateMachine {
         }
     }
 
+    fn get_stake_quorum(&self, configuration: &Configuration) -> Balance {
+        match self.voting_type() {
+            VotingType::Informal => configuration.informal_voting_stake_quorum(),
+            VotingType::Formal => configuration.formal_voting_stake_quorum(),
+        }
+    }
+
+    fn stats_mut(&mut self) -> &mut Stats {
+        match self.voting_type() {
+            VotingType::Informal => &mut self.informal_stats,
+            VotingType::Formal => &mut self.formal_stats,
+        }
+    }
+
     fn unbound_stake_in_favor(&self) -> Balance {
         match self.voting_type() {
             VotingType::Informal => self.informal_stats.unbound_stake_in_favor,
@@ -348,6 +604,16 @@ impl VotingStateMachine {
             .validate(self, configuration);
     }
 
+    /// Verifies if a secret ballot can be revealed.
+    ///
+    /// Stops contract execution if validation fails. See [`RevealInTime`].
+    pub fn guard_reveal(&self, block_time: BlockTime, configuration: &Configuration) {
+        RulesBuilder::new()
+            .add_voting_validation(RevealInTime::create(block_time))
+            .build()
+            .validate(self, configuration);
+    }
+
     /// Verifies if the formal voting can be finished.
     ///
     /// Stops contract execution if validation fails. See [`AfterFormalVoting`] and [`VotingNotCompleted`].
@@ -371,10 +637,28 @@ pub struct Stats {
     pub unbound_stake_in_favor: Balance,
     /// The total unbounded `against` stake.
     pub unbound_stake_against: Balance,
+    /// The total `abstain` stake.
+    pub stake_abstain: Balance,
+    /// The total unbounded `abstain` stake.
+    pub unbound_stake_abstain: Balance,
     /// The number of VA's voted `in favor`.
     pub votes_in_favor: u32,
     /// The number of VA's voted `against`.
     pub votes_against: u32,
+    /// The number of VA's who abstained.
+    pub votes_abstain: u32,
+    /// The total reputation staked on the bound ballots. Unlike the stakes above, it is not weighted
+    /// in the quadratic voting, so it can be compared to the stake quorum.
+    pub bound_reputation: Balance,
+}
+
+/// The leading side of a voting phase.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VotingOutcome {
+    /// Tells if the `in favor` side leads.
+    pub in_favor: bool,
+    /// Tells if the difference between the sides is within the voting clearness delta.
+    pub close: bool,
 }
 
 /// State of Voting.
@@ -388,6 +672,8 @@ pub enum VotingState {
     BetweenVotings,
     /// Formal voting started.
     Formal,
+    /// Formal voting ended, secret ballots are being revealed.
+    Reveal,
     /// Formal voting ended.
     Finished,
     /// The voting interrupted.
@@ -1,15 +1,17 @@
-use crate::configuration::ConfigurationBuilder;
+use crate::configuration::{ConfigurationBuilder, VoterKind};
 use crate::modules::refs::ContractRefs;
+use crate::modules::timelock::QueuedAction;
 use crate::modules::AccessControl;
+use crate::utils::types::DocumentHash;
 use crate::utils::ContractCall;
-use crate::voting::ballot::{Ballot, Choice};
+use crate::voting::ballot::{Ballot, Choice, SealedBallot, SignedBallot};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
 use crate::voting::voting_engine::voting_state_machine::VotingType;
 use crate::voting::voting_engine::voting_state_machine::{VotingStateMachine, VotingSummary};
 use crate::voting::voting_engine::{VotingEngine, VotingEngineComposer};
 use odra::contract_env::{caller, emit_event};
-use odra::types::{Address, Balance, BlockTime, CallArgs};
+use odra::types::{Address, Balance, BlockTime, Bytes, CallArgs};
 use odra::{Composer, Event, Instance, OdraType};
 
 /// Admin contract uses [VotingEngine](VotingEngine) to vote on changes of ownership and managing whitelists of other contracts.
@@ -46,13 +48,31 @@ impl AdminContract {
                 &self,
                 voting_id: VotingId,
             ) -> Option<VotingStateMachine>;
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
             pub fn get_ballot(
                 &self,
                 voting_id: VotingId,
                 voting_type: VotingType,
                 address: Address,
             ) -> Option<Ballot>;
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
+            pub fn vote_with_signature(&mut self, signed_ballot: SignedBallot);
+            pub fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>);
+            pub fn get_nonce(&self, voter: Address) -> u64;
+            pub fn get_reputation_dust(&self) -> Balance;
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn execute_action(&mut self, voting_id: VotingId);
+            pub fn cancel_action(&mut self, voting_id: VotingId);
+            pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction>;
             pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
         }
 
@@ -92,6 +112,7 @@ impl AdminContract {
             self.refs.va_token().total_supply(),
             &self.refs.variable_repository().all_variables(),
         )
+        .voter_kind(VoterKind::Admin)
         .contract_call(ContractCall {
             address: contract_to_update,
             entry_point: action.get_entry_point(),
@@ -118,15 +139,80 @@ impl AdminContract {
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     ) {
         self.voting_engine
-            .vote(caller(), voting_id, voting_type, choice, stake);
+            .vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Casts many ballots in one call. See [VotingEngine::vote_batch()].
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        self.voting_engine.vote_batch(caller(), ballots);
+    }
+
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        self.voting_engine.vote_as_delegate(
+            caller(),
+            voting_id,
+            voting_type,
+            choice,
+            stake,
+            delegators,
+        );
+    }
+
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .change_vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
+        self.voting_engine
+            .commit_vote(caller(), voting_id, commitment, stake);
+    }
+
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
     }
 
     pub fn slash_voter(&mut self, voter: Address) {
         self.access_control.ensure_whitelisted();
         self.voting_engine.slash_voter(voter);
     }
+
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// See [VotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+    }
 }
 
 /// Event emitted once voting is created.
@@ -142,6 +228,10 @@ pub struct AdminVotingCreated {
     config_informal_voting_time: u64,
     config_formal_quorum: u32,
     config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
     config_total_onboarded: Balance,
     config_double_time_between_votings: bool,
     config_voting_clearness_delta: Balance,
@@ -166,6 +256,10 @@ impl AdminVotingCreated {
             config_informal_voting_time: info.config_informal_voting_time,
             config_formal_quorum: info.config_formal_quorum,
             config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
             config_total_onboarded: info.config_total_onboarded,
             config_double_time_between_votings: info.config_double_time_between_votings,
             config_voting_clearness_delta: info.config_voting_clearness_delta,
@@ -0,0 +1,463 @@
+//! Contains Governance Executor Contract definition and related abstractions.
+//!
+//! # General
+//! A type of Governance Voting used to perform arbitrary contract calls.
+//!
+//! A VA proposes a list of [`ContractCall`]s. Each call may attach a CSPR `amount`, which is paid from
+//! the contract's purse - the contract can be funded using the `deposit` entry point.
+//!
+//! # Allowlist
+//! Only the entry points on the allowlist can be proposed. The allowlist is managed by Governance Executor
+//! votings only - the contract itself is the only account allowed to call `allow_call`/`disallow_call`,
+//! and both entry points are on the allowlist from the start.
+//!
+//! The allowlist is verified at the moment of the voting creation and again before the calls are performed.
+//! If any of the calls has been removed from the allowlist in the meantime, none of them is performed.
+//!
+//! # Voting
+//! The Voting process is managed by [`VotingEngine`]. The calls are performed in the given order
+//! once the voting passes. Governance Executor votings use the AdminPassThreshold.
+//!
+//! [`VotingEngine`]: VotingEngine
+use crate::configuration::{ConfigurationBuilder, VoterKind};
+use crate::modules::refs::ContractRefs;
+use crate::modules::timelock::QueuedAction;
+use crate::modules::AccessControl;
+use crate::utils::types::DocumentHash;
+use crate::utils::{consts, ContractCall, Error};
+use crate::voting::ballot::{Ballot, Choice, SealedBallot, SignedBallot};
+use crate::voting::types::VotingId;
+use crate::voting::voting_engine::events::VotingCreatedInfo;
+use crate::voting::voting_engine::voting_state_machine::VotingType;
+use crate::voting::voting_engine::voting_state_machine::{VotingStateMachine, VotingSummary};
+use crate::voting::voting_engine::{VotingEngine, VotingEngineComposer};
+use odra::contract_env::{caller, revert, self_address, self_balance};
+use odra::types::event::OdraEvent;
+use odra::types::{Address, Balance, BlockTime, Bytes};
+use odra::{Composer, Event, Instance, Mapping};
+
+/// GovernanceExecutorContract
+///
+/// It is responsible for votings on arbitrary contract calls. The calls must be on the allowlist.
+///
+/// For details see [the module docs](self).
+#[odra::module(
+    skip_instance,
+    events = [GovernanceVotingCreated, CallAllowed, CallDisallowed, ActionBlocked]
+)]
+pub struct GovernanceExecutorContract {
+    refs: ContractRefs,
+    voting_engine: VotingEngine,
+    access_control: AccessControl,
+    allowlist: Mapping<(Address, String), bool>,
+}
+
+impl Instance for GovernanceExecutorContract {
+    fn instance(namespace: &str) -> Self {
+        let refs = Composer::new(namespace, "refs").compose();
+        let voting_engine = VotingEngineComposer::new(namespace, "voting_engine")
+            .with_refs(&refs)
+            .compose();
+
+        Self {
+            refs,
+            voting_engine,
+            access_control: Composer::new(namespace, "access_control").compose(),
+            allowlist: Composer::new(namespace, "allowlist").compose(),
+        }
+    }
+}
+
+#[odra::module]
+impl GovernanceExecutorContract {
+    delegate! {
+        to self.voting_engine {
+            pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool;
+            pub fn get_voting(
+                &self,
+                voting_id: VotingId,
+            ) -> Option<VotingStateMachine>;
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
+            pub fn get_ballot(
+                &self,
+                voting_id: VotingId,
+                voting_type: VotingType,
+                address: Address,
+            ) -> Option<Ballot>;
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
+            pub fn vote_with_signature(&mut self, signed_ballot: SignedBallot);
+            pub fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>);
+            pub fn get_nonce(&self, voter: Address) -> u64;
+            pub fn get_reputation_dust(&self) -> Balance;
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
+            pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn cancel_action(&mut self, voting_id: VotingId);
+            pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction>;
+        }
+
+        to self.access_control {
+            pub fn change_ownership(&mut self, owner: Address);
+            pub fn add_to_whitelist(&mut self, address: Address);
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            pub fn get_owner(&self) -> Option<Address>;
+        }
+
+        to self.refs {
+            pub fn variable_repository_address(&self) -> Address;
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    /// Initializes a contract. Sets the deployer as the owner and puts the contract's own `allow_call`
+    /// and `disallow_call` entry points on the allowlist, so the allowlist can be managed by the contract's votings.
+    ///
+    /// # Events
+    /// * [`CallAllowed`]
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.access_control.init(caller());
+        self.set_call_allowed(self_address(), consts::EP_ALLOW_CALL.to_string(), true);
+        self.set_call_allowed(self_address(), consts::EP_DISALLOW_CALL.to_string(), true);
+    }
+
+    /// Creates a new voting on the `contract_calls`. The calls are performed in the given order
+    /// if the voting passes.
+    ///
+    /// # Events
+    /// * [`GovernanceVotingCreated`]
+    ///
+    /// # Errors
+    /// * [`ContractToCallNotSet`](Error::ContractToCallNotSet) if the list of calls is empty.
+    /// * [`CallNotAllowed`](Error::CallNotAllowed) if any of the calls is not on the allowlist.
+    pub fn create_voting(&mut self, contract_calls: Vec<ContractCall>, stake: Balance) {
+        if contract_calls.is_empty() {
+            revert(Error::ContractToCallNotSet);
+        }
+        if !contract_calls
+            .iter()
+            .all(|contract_call| is_allowed(&self.allowlist, contract_call))
+        {
+            revert(Error::CallNotAllowed);
+        }
+
+        let voting_configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .voter_kind(VoterKind::Governance)
+        .contract_calls(contract_calls.clone())
+        .build();
+
+        let (info, _) = self
+            .voting_engine
+            .create_voting(caller(), stake, voting_configuration);
+
+        GovernanceVotingCreated::new(contract_calls, info).emit();
+    }
+
+    /// Returns the contract calls the voting with the given id performs once passed.
+    ///
+    /// # Errors
+    /// * [`ConfigurationNotFound`](Error::ConfigurationNotFound) if the voting does not exist.
+    pub fn get_contract_calls(&self, voting_id: VotingId) -> Vec<ContractCall> {
+        self.voting_engine
+            .get_configuration_or_revert(voting_id)
+            .contract_calls()
+            .clone()
+    }
+
+    /// Adds the `entry_point` of the contract at `address` to the allowlist.
+    /// Only a passed Governance Executor voting can call this method.
+    ///
+    /// # Events
+    /// * [`CallAllowed`]
+    ///
+    /// # Errors
+    /// * [`NotGovernanceVoting`](Error::NotGovernanceVoting) if the caller is not the contract itself.
+    pub fn allow_call(&mut self, address: Address, entry_point: String) {
+        self.ensure_self_call();
+        self.set_call_allowed(address, entry_point, true);
+    }
+
+    /// Removes the `entry_point` of the contract at `address` from the allowlist.
+    /// Only a passed Governance Executor voting can call this method.
+    ///
+    /// # Events
+    /// * [`CallDisallowed`]
+    ///
+    /// # Errors
+    /// * [`NotGovernanceVoting`](Error::NotGovernanceVoting) if the caller is not the contract itself.
+    pub fn disallow_call(&mut self, address: Address, entry_point: String) {
+        self.ensure_self_call();
+        self.set_call_allowed(address, entry_point, false);
+    }
+
+    /// Checks whether the `entry_point` of the contract at `address` is on the allowlist.
+    pub fn is_call_allowed(&self, address: Address, entry_point: String) -> bool {
+        self.allowlist
+            .get(&(address, entry_point))
+            .unwrap_or_default()
+    }
+
+    /// Finishes the voting. See [VotingEngine::finish_voting()].
+    ///
+    /// If any of the calls has been removed from the allowlist since the voting was created,
+    /// the formal voting finishes as usual, but none of the calls is performed.
+    ///
+    /// # Events
+    /// * [`ActionBlocked`] if the calls are dropped.
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        if voting_type == VotingType::Formal {
+            let allowlist = &self.allowlist;
+            let contract_calls = self
+                .voting_engine
+                .drop_disallowed_calls(voting_id, |contract_call| {
+                    is_allowed(allowlist, contract_call)
+                });
+            if !contract_calls.is_empty() {
+                ActionBlocked {
+                    voting_id,
+                    contract_calls,
+                }
+                .emit();
+            }
+        }
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
+    /// Executes the action queued by a passed voting. See [VotingEngine::execute_action()].
+    ///
+    /// # Errors
+    /// * [`CallNotAllowed`](Error::CallNotAllowed) if any of the calls has been removed from the allowlist
+    /// since the voting was created.
+    pub fn execute_action(&mut self, voting_id: VotingId) {
+        if let Some(action) = self.voting_engine.get_queued_action(voting_id) {
+            if !action
+                .contract_calls
+                .iter()
+                .all(|contract_call| is_allowed(&self.allowlist, contract_call))
+            {
+                revert(Error::CallNotAllowed);
+            }
+        }
+        self.voting_engine.execute_action(voting_id);
+    }
+
+    /// Deposits CSPR to the contract's purse. The funds are used to pay the `amount` of the contract calls.
+    #[odra(payable)]
+    pub fn deposit(&mut self) {}
+
+    /// Gets the CSPR balance of the contract.
+    pub fn get_cspr_balance(&self) -> Balance {
+        self_balance()
+    }
+
+    /// Casts a ballot. See [VotingEngine::vote()].
+    pub fn vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Casts many ballots in one call. See [VotingEngine::vote_batch()].
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        self.voting_engine.vote_batch(caller(), ballots);
+    }
+
+    /// Casts a ballot on behalf of the caller and his delegators. See [VotingEngine::vote_as_delegate()].
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        self.voting_engine.vote_as_delegate(
+            caller(),
+            voting_id,
+            voting_type,
+            choice,
+            stake,
+            delegators,
+        );
+    }
+
+    /// Changes the choice and/or the stake of the caller's ballot. See [VotingEngine::change_vote()].
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .change_vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Withdraws the caller's ballot and unstakes the reputation. See [VotingEngine::withdraw_vote()].
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    /// Commits a secret ballot in the formal voting. See [VotingEngine::commit_vote()].
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
+        self.voting_engine
+            .commit_vote(caller(), voting_id, commitment, stake);
+    }
+
+    /// Reveals the caller's secret ballot. See [VotingEngine::reveal_vote()].
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
+    }
+
+    /// Cancels the ballots and the votings of the `voter`. See [VotingEngine::slash_voter()].
+    ///
+    /// # Errors
+    /// * [`NotWhitelisted`](Error::NotWhitelisted) if the caller is not whitelisted.
+    pub fn slash_voter(&mut self, voter: Address) {
+        self.access_control.ensure_whitelisted();
+        self.voting_engine.slash_voter(voter);
+    }
+
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// See [VotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+    }
+}
+
+impl GovernanceExecutorContract {
+    fn ensure_self_call(&self) {
+        if caller() != self_address() {
+            revert(Error::NotGovernanceVoting);
+        }
+    }
+
+    fn set_call_allowed(&mut self, address: Address, entry_point: String, allowed: bool) {
+        self.allowlist.set(&(address, entry_point.clone()), allowed);
+        if allowed {
+            CallAllowed {
+                address,
+                entry_point,
+            }
+            .emit();
+        } else {
+            CallDisallowed {
+                address,
+                entry_point,
+            }
+            .emit();
+        }
+    }
+}
+
+fn is_allowed(allowlist: &Mapping<(Address, String), bool>, contract_call: &ContractCall) -> bool {
+    allowlist
+        .get(&(
+            *contract_call.address(),
+            contract_call.entry_point().to_string(),
+        ))
+        .unwrap_or_default()
+}
+
+/// Event emitted when governance voting has been created.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct GovernanceVotingCreated {
+    contract_calls: Vec<ContractCall>,
+    creator: Address,
+    stake: Option<Balance>,
+    voting_id: VotingId,
+    config_informal_quorum: u32,
+    config_informal_voting_time: u64,
+    config_formal_quorum: u32,
+    config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
+    config_total_onboarded: Balance,
+    config_double_time_between_votings: bool,
+    config_voting_clearness_delta: Balance,
+    config_time_between_informal_and_formal_voting: BlockTime,
+}
+
+impl GovernanceVotingCreated {
+    pub fn new(contract_calls: Vec<ContractCall>, info: VotingCreatedInfo) -> Self {
+        Self {
+            contract_calls,
+            creator: info.creator,
+            stake: info.stake,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
+        }
+    }
+}
+
+/// Event emitted when an entry point has been added to the allowlist.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct CallAllowed {
+    pub address: Address,
+    pub entry_point: String,
+}
+
+/// Event emitted when an entry point has been removed from the allowlist.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct CallDisallowed {
+    pub address: Address,
+    pub entry_point: String,
+}
+
+/// Event emitted when the calls of a voting have been dropped, because some of them
+/// had been removed from the allowlist.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct ActionBlocked {
+    pub voting_id: VotingId,
+    pub contract_calls: Vec<ContractCall>,
+}
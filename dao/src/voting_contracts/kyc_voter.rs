@@ -16,13 +16,14 @@
 //!
 //! [`Reputation Token Contract`]: crate::core_contracts::ReputationContract
 //! [`VotingEngine`]: VotingEngine
-use crate::configuration::ConfigurationBuilder;
+use crate::configuration::{ConfigurationBuilder, VoterKind};
 use crate::modules::kyc_info::{KycInfo, KycInfoComposer};
 use crate::modules::refs::ContractRefs;
+use crate::modules::timelock::QueuedAction;
 use crate::modules::AccessControl;
 use crate::utils::types::DocumentHash;
 use crate::utils::{consts, ContractCall, Error};
-use crate::voting::ballot::{Ballot, Choice};
+use crate::voting::ballot::{Ballot, Choice, SealedBallot, SignedBallot};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
 use crate::voting::voting_engine::voting_state_machine::VotingType;
@@ -30,7 +31,7 @@ use crate::voting::voting_engine::voting_state_machine::{VotingStateMachine, Vot
 use crate::voting::voting_engine::{VotingEngine, VotingEngineComposer};
 use odra::contract_env::{self, caller};
 use odra::types::event::OdraEvent;
-use odra::types::{Address, Balance, BlockTime, CallArgs};
+use odra::types::{Address, Balance, BlockTime, Bytes, CallArgs};
 use odra::{Composer, Event, Instance, UnwrapOrRevert};
 
 /// KycVoterContract
@@ -74,13 +75,31 @@ impl KycVoterContract {
                 &self,
                 voting_id: VotingId,
             ) -> Option<VotingStateMachine>;
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
             pub fn get_ballot(
                 &self,
                 voting_id: VotingId,
                 voting_type: VotingType,
                 address: Address,
             ) -> Option<Ballot>;
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
+            pub fn vote_with_signature(&mut self, signed_ballot: SignedBallot);
+            pub fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>);
+            pub fn get_nonce(&self, voter: Address) -> u64;
+            pub fn get_reputation_dust(&self) -> Balance;
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn execute_action(&mut self, voting_id: VotingId);
+            pub fn cancel_action(&mut self, voting_id: VotingId);
+            pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction>;
         }
 
         to self.access_control {
@@ -122,6 +141,7 @@ impl KycVoterContract {
             self.refs.va_token().total_supply(),
             &self.refs.variable_repository().all_variables(),
         )
+        .voter_kind(VoterKind::Kyc)
         .contract_call(ContractCall {
             address: self.refs.kyc_token_address(),
             entry_point: consts::EP_MINT.to_string(),
@@ -149,9 +169,66 @@ impl KycVoterContract {
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     ) {
         self.voting_engine
-            .vote(caller(), voting_id, voting_type, choice, stake);
+            .vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Casts many ballots in one call. See [VotingEngine::vote_batch()].
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        self.voting_engine.vote_batch(caller(), ballots);
+    }
+
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        self.voting_engine.vote_as_delegate(
+            caller(),
+            voting_id,
+            voting_type,
+            choice,
+            stake,
+            delegators,
+        );
+    }
+
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .change_vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
+        self.voting_engine
+            .commit_vote(caller(), voting_id, commitment, stake);
+    }
+
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
     }
 
     pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
@@ -175,6 +252,29 @@ impl KycVoterContract {
         self.voting_engine.slash_voter(voter);
     }
 
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// The subject of the canceled voting can be a subject of a new KYC voting.
+    ///
+    /// See [VotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+        let address = self.kyc.get_voting_subject(voting_id);
+        self.kyc.clear_voting(&address);
+    }
+
+    /// Cancels the voting created by the caller. Allowed in the informal phase if no one else has voted.
+    ///
+    /// The subject of the withdrawn voting can be a subject of a new KYC voting.
+    ///
+    /// See [VotingEngine::withdraw_voting()].
+    pub fn withdraw_voting(&mut self, voting_id: VotingId) {
+        self.voting_engine.withdraw_voting(caller(), voting_id);
+        let address = self.kyc.get_voting_subject(voting_id);
+        self.kyc.clear_voting(&address);
+    }
+
     fn assert_not_kyced(&self, address: &Address) {
         if self.kyc.is_kycd(address) {
             contract_env::revert(Error::UserKycedAlready);
@@ -200,6 +300,10 @@ pub struct KycVotingCreated {
     config_informal_voting_time: u64,
     config_formal_quorum: u32,
     config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
     config_total_onboarded: Balance,
     config_double_time_between_votings: bool,
     config_voting_clearness_delta: Balance,
@@ -222,6 +326,10 @@ impl KycVotingCreated {
             config_informal_voting_time: info.config_informal_voting_time,
             config_formal_quorum: info.config_formal_quorum,
             config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
             config_total_onboarded: info.config_total_onboarded,
             config_double_time_between_votings: info.config_double_time_between_votings,
             config_voting_clearness_delta: info.config_voting_clearness_delta,
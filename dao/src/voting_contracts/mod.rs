@@ -1,14 +1,25 @@
 //! Module containg voting contracts.
 mod admin;
+mod governance_executor;
 mod kyc_voter;
+mod multi_option_voter;
 mod onboarding_request;
 mod repo_voter;
 mod reputation_voter;
+mod signal_voter;
 mod simple_voter;
 mod slashing_voter;
 
 pub use admin::{Action as AdminAction, AdminContract, AdminContractDeployer, AdminContractRef};
+pub use governance_executor::{
+    CallAllowed, CallDisallowed, GovernanceExecutorContract, GovernanceExecutorContractDeployer,
+    GovernanceExecutorContractRef, GovernanceVotingCreated,
+};
 pub use kyc_voter::{KycVoterContract, KycVoterContractDeployer, KycVoterContractRef};
+pub use multi_option_voter::{
+    MultiOptionVoterContract, MultiOptionVoterContractDeployer, MultiOptionVoterContractRef,
+    MultiOptionVotingCreated,
+};
 pub use onboarding_request::{
     OnboardingRequestContract, OnboardingRequestContractDeployer, OnboardingRequestContractRef,
     OnboardingVotingCreated,
@@ -18,6 +29,9 @@ pub use reputation_voter::{
     Action as ReputationAction, ReputationVoterContract, ReputationVoterContractDeployer,
     ReputationVoterContractRef,
 };
+pub use signal_voter::{
+    SignalVoterContract, SignalVoterContractDeployer, SignalVoterContractRef, SignalVotingCreated,
+};
 pub use simple_voter::{SimpleVoterContract, SimpleVoterContractDeployer, SimpleVoterContractRef};
 pub use slashing_voter::{
     SlashingVoterContract, SlashingVoterContractDeployer, SlashingVoterContractRef,
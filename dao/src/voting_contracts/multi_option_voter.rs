@@ -0,0 +1,281 @@
+use odra::{
+    call_contract,
+    contract_env::{caller, revert},
+    types::{event::OdraEvent, Address, Balance, BlockTime, CallArgs},
+    Composer, Event, Instance,
+};
+
+use crate::{
+    configuration::{Configuration, ConfigurationBuilder},
+    modules::{refs::ContractRefs, timelock::QueuedAction, AccessControl},
+    utils::{consts, types::DocumentHash, ContractCall, Error},
+    voting::{
+        ballot::RankedBallot,
+        multi_option_engine::{
+            multi_option_voting::{MultiOptionVoting, TallyMethod, VotingOption},
+            MultiOptionVotingEngine, MultiOptionVotingEngineComposer,
+        },
+        types::VotingId,
+        voting_engine::{
+            events::VotingCreatedInfo,
+            voting_state_machine::{VotingStateMachine, VotingSummary, VotingType},
+        },
+    },
+};
+
+/// MultiOptionVoterContract
+///
+/// It is responsible for votings with more than two options, eg. picking one of several budgets or documents.
+///
+/// Each option is described by a document hash and may define contract calls performed if the option wins.
+/// Only the calls on the allowlist of the Governance Executor set as `GovernanceExecutorAddress`
+/// in the Variable Repository can be proposed.
+/// Voters rank the options and the winner is picked by plurality or instant-runoff.
+#[odra::module(skip_instance, events = [MultiOptionVotingCreated])]
+pub struct MultiOptionVoterContract {
+    refs: ContractRefs,
+    voting_engine: MultiOptionVotingEngine,
+    access_control: AccessControl,
+}
+
+impl Instance for MultiOptionVoterContract {
+    fn instance(namespace: &str) -> Self {
+        let refs = Composer::new(namespace, "refs").compose();
+        let voting_engine = MultiOptionVotingEngineComposer::new(namespace, "voting_engine")
+            .with_refs(&refs)
+            .compose();
+
+        Self {
+            refs,
+            voting_engine,
+            access_control: Composer::new(namespace, "access_control").compose(),
+        }
+    }
+}
+
+#[odra::module]
+impl MultiOptionVoterContract {
+    delegate! {
+        to self.voting_engine {
+            pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool;
+            pub fn get_voting(
+                &self,
+                voting_id: VotingId,
+            ) -> Option<VotingStateMachine>;
+            pub fn get_multi_option_voting(&self, voting_id: VotingId) -> Option<MultiOptionVoting>;
+            pub fn get_ballot(
+                &self,
+                voting_id: VotingId,
+                voting_type: VotingType,
+                address: Address,
+            ) -> Option<RankedBallot>;
+            pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<RankedBallot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<RankedBallot>;
+            pub fn execute_action(&mut self, voting_id: VotingId);
+            pub fn cancel_action(&mut self, voting_id: VotingId);
+            pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
+        }
+
+        to self.access_control {
+            pub fn change_ownership(&mut self, owner: Address);
+            pub fn add_to_whitelist(&mut self, address: Address);
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            pub fn get_owner(&self) -> Option<Address>;
+        }
+
+        to self.refs {
+            pub fn variable_repository_address(&self) -> Address;
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.access_control.init(caller())
+    }
+
+    /// Creates a new multi-option voting.
+    ///
+    /// The creator's `ranking` of the options is cast as the first vote with the given `stake`.
+    ///
+    /// # Events
+    /// * [`MultiOptionVotingCreated`]
+    ///
+    /// # Errors
+    /// * [`TooFewVotingOptions`](crate::utils::Error::TooFewVotingOptions) if less than two options are given.
+    /// * [`InvalidRanking`](crate::utils::Error::InvalidRanking) if the ranking is invalid.
+    /// * [`CallNotAllowed`](crate::utils::Error::CallNotAllowed) if any of the options' calls is not
+    /// on the Governance Executor allowlist.
+    pub fn create_voting(
+        &mut self,
+        options: Vec<VotingOption>,
+        tally_method: TallyMethod,
+        ranking: Vec<u32>,
+        stake: Balance,
+    ) {
+        let voting_configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .build();
+
+        if !options
+            .iter()
+            .flat_map(|option| option.contract_calls.iter())
+            .all(|contract_call| is_allowed(&voting_configuration, contract_call))
+        {
+            revert(Error::CallNotAllowed);
+        }
+
+        let document_hashes = options
+            .iter()
+            .map(|option| option.document_hash.clone())
+            .collect();
+        let (info, _) = self.voting_engine.create_voting(
+            caller(),
+            options,
+            tally_method,
+            ranking,
+            stake,
+            voting_configuration,
+        );
+
+        MultiOptionVotingCreated::new(document_hashes, tally_method, info).emit();
+    }
+
+    /// Finishes the voting. See [MultiOptionVotingEngine::finish_voting()].
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
+    /// Casts a ballot ranking the options. See [MultiOptionVotingEngine::vote()].
+    pub fn vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .vote(caller(), voting_id, voting_type, ranking, stake, rationale);
+    }
+
+    /// Changes the ranking and/or the stake of the caller's ballot. See [MultiOptionVotingEngine::change_vote()].
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .change_vote(caller(), voting_id, voting_type, ranking, stake, rationale);
+    }
+
+    /// Withdraws the caller's ballot and unstakes the reputation. See [MultiOptionVotingEngine::withdraw_vote()].
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    /// Cancels the ballots and the votings of the `voter`. See [MultiOptionVotingEngine::slash_voter()].
+    ///
+    /// # Errors
+    /// * [`NotWhitelisted`](crate::utils::Error::NotWhitelisted) if the caller is not whitelisted.
+    pub fn slash_voter(&mut self, voter: Address) {
+        self.access_control.ensure_whitelisted();
+        self.voting_engine.slash_voter(voter);
+    }
+
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// See [MultiOptionVotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+    }
+}
+
+/// Checks if the call is on the allowlist of the Governance Executor. If the `GovernanceExecutorAddress`
+/// is not set, no call is allowed.
+fn is_allowed(configuration: &Configuration, contract_call: &ContractCall) -> bool {
+    match configuration.governance_executor_address() {
+        Some(governance_executor) => {
+            let mut args = CallArgs::new();
+            args.insert(consts::ARG_ADDRESS, *contract_call.address());
+            args.insert(
+                consts::ARG_ENTRY_POINT,
+                contract_call.entry_point().to_string(),
+            );
+            call_contract(governance_executor, "is_call_allowed", &args, None)
+        }
+        None => false,
+    }
+}
+
+/// Event emitted when multi-option voting has been created.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct MultiOptionVotingCreated {
+    options: Vec<DocumentHash>,
+    tally_method: TallyMethod,
+    creator: Address,
+    stake: Option<Balance>,
+    voting_id: VotingId,
+    config_informal_quorum: u32,
+    config_informal_voting_time: u64,
+    config_formal_quorum: u32,
+    config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_total_onboarded: Balance,
+    config_double_time_between_votings: bool,
+    config_voting_clearness_delta: Balance,
+    config_time_between_informal_and_formal_voting: BlockTime,
+}
+
+impl MultiOptionVotingCreated {
+    pub fn new(
+        options: Vec<DocumentHash>,
+        tally_method: TallyMethod,
+        info: VotingCreatedInfo,
+    ) -> Self {
+        Self {
+            options,
+            tally_method,
+            creator: info.creator,
+            stake: info.stake,
+            voting_id: info.voting_id,
+            config_informal_quorum: info.config_informal_quorum,
+            config_informal_voting_time: info.config_informal_voting_time,
+            config_formal_quorum: info.config_formal_quorum,
+            config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_total_onboarded: info.config_total_onboarded,
+            config_double_time_between_votings: info.config_double_time_between_votings,
+            config_voting_clearness_delta: info.config_voting_clearness_delta,
+            config_time_between_informal_and_formal_voting: info
+                .config_time_between_informal_and_formal_voting,
+        }
+    }
+}
@@ -29,7 +29,7 @@ use crate::modules::refs::ContractRefs;
 use crate::modules::AccessControl;
 use crate::onboarding::{Onboarding, OnboardingComposer};
 use crate::utils::types::DocumentHash;
-use crate::voting::ballot::{Ballot, Choice};
+use crate::voting::ballot::{Ballot, Choice, SealedBallot};
 use crate::voting::types::VotingId;
 use crate::voting::voting_engine::events::VotingCreatedInfo;
 use crate::voting::voting_engine::voting_state_machine::{
@@ -38,7 +38,7 @@ use crate::voting::voting_engine::voting_state_machine::{
 use crate::voting::voting_engine::{VotingEngine, VotingEngineComposer};
 use odra::contract_env::{attached_value, caller, self_balance};
 use odra::types::event::OdraEvent;
-use odra::types::{Address, Balance, BlockTime};
+use odra::types::{Address, Balance, BlockTime, Bytes};
 use odra::{Composer, Event, Instance};
 
 /// Onboarding Request Contract.
@@ -82,10 +82,39 @@ impl OnboardingRequestContract {
                 voting_type: VotingType,
                 address: Address,
             ) -> Option<Ballot>;
+            /// Returns the Voter's [`SealedBallot`].
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
             /// Gets the address of nth voter who voted on Voting with `voting_id`.
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            /// Gets the total number of users participated in voting.
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            /// Returns a page of the ids of the votings that have not been finished or canceled yet.
+            ///
+            /// The pages are not stable - when a voting is removed from the list, the last one is moved to its position,
+            /// so a voting may be skipped if the list changes between the calls.
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            /// Returns a page of the ids of the votings created by the `creator`.
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            /// Returns a page of the [`Ballot`]s cast by the `voter`.
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            /// Returns a page of the [`Ballot`]s cast in the voting with `voting_id`.
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
             /// Returns [Voting](VotingStateMachine) for given id.
             pub fn get_voting(&self, voting_id: VotingId) -> Option<VotingStateMachine>;
+            /// Returns the votings whose current phase has ended, so they can be finished by a keeper.
+            /// Only a page of the active votings is checked, and the pages are not stable across removals.
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            /// Adds the attached CSPR to the pool the keepers are rewarded from.
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            /// Returns the CSPR left in the pool the keepers are rewarded from.
+            pub fn get_keeper_reward_pool(&self) -> Balance;
+            /// Returns the total reputation that couldn't be redistributed.
+            pub fn get_reputation_dust(&self) -> Balance;
+            /// Returns the total CSPR that couldn't be redistributed.
+            pub fn get_cspr_dust(&self) -> Balance;
+            /// Returns how many Formal Votings finished within `[from, to)` each voter has voted in.
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
         }
 
         to self.access_control {
@@ -112,7 +141,25 @@ impl OnboardingRequestContract {
             /// with a refund or convert the requester to a VA.
             pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
             /// Casts a vote. [Read more](VotingEngine::vote())
-            pub fn vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance);
+            pub fn vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance, rationale: Option<DocumentHash>);
+            /// Casts many ballots in one call. [Read more](VotingEngine::vote_batch())
+            pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>);
+            pub fn vote_as_delegate(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance, delegators: Vec<(Address, Balance)>);
+            pub fn change_vote(&mut self, voting_id: VotingId, voting_type: VotingType, choice: Choice, stake: Balance, rationale: Option<DocumentHash>);
+            pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType);
+            /// Commits a secret ballot. [Read more](VotingEngine::commit_vote())
+            pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance);
+            /// Reveals a secret ballot. [Read more](VotingEngine::reveal_vote())
+            pub fn reveal_vote(
+                &mut self,
+                voting_id: VotingId,
+                choice: Choice,
+                salt: Bytes,
+                rationale: Option<DocumentHash>,
+            );
+            /// Cancels an active voting and returns the CSPR deposit. Only the guardian can call this method.
+            /// [Read more](Onboarding::guardian_cancel_voting())
+            pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash);
         }
 
         to self.refs {
@@ -185,6 +232,10 @@ pub struct OnboardingVotingCreated {
     config_informal_voting_time: u64,
     config_formal_quorum: u32,
     config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
     config_total_onboarded: Balance,
     config_double_time_between_votings: bool,
     config_voting_clearness_delta: Balance,
@@ -203,6 +254,10 @@ impl OnboardingVotingCreated {
             config_informal_voting_time: info.config_informal_voting_time,
             config_formal_quorum: info.config_formal_quorum,
             config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
             config_total_onboarded: info.config_total_onboarded,
             config_double_time_between_votings: info.config_double_time_between_votings,
             config_voting_clearness_delta: info.config_voting_clearness_delta,
@@ -5,11 +5,11 @@ use odra::{
 };
 
 use crate::{
-    configuration::ConfigurationBuilder,
-    modules::{refs::ContractRefs, AccessControl},
-    utils::{consts, ContractCall},
+    configuration::{ConfigurationBuilder, VoterKind},
+    modules::{refs::ContractRefs, timelock::QueuedAction, AccessControl},
+    utils::{consts, types::DocumentHash, ContractCall},
     voting::{
-        ballot::{Ballot, Choice},
+        ballot::{Ballot, Choice, SealedBallot, SignedBallot},
         types::VotingId,
         voting_engine::{
             events::VotingCreatedInfo,
@@ -55,13 +55,31 @@ impl RepoVoterContract {
             &self,
                 voting_id: VotingId,
             ) -> Option<VotingStateMachine>;
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
             pub fn get_ballot(
                 &self,
                 voting_id: VotingId,
                 voting_type: VotingType,
                 address: Address,
             ) -> Option<Ballot>;
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
+            pub fn vote_with_signature(&mut self, signed_ballot: SignedBallot);
+            pub fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>);
+            pub fn get_nonce(&self, voter: Address) -> u64;
+            pub fn get_reputation_dust(&self) -> Balance;
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn execute_action(&mut self, voting_id: VotingId);
+            pub fn cancel_action(&mut self, voting_id: VotingId);
+            pub fn get_queued_action(&self, voting_id: VotingId) -> Option<QueuedAction>;
             pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary;
         }
 
@@ -104,6 +122,7 @@ impl RepoVoterContract {
             self.refs.va_token().total_supply(),
             &self.refs.variable_repository().all_variables(),
         )
+        .voter_kind(VoterKind::Repo)
         .contract_call(ContractCall {
             address: variable_repo_to_edit,
             entry_point: consts::EP_UPDATE_AT.to_string(),
@@ -131,15 +150,87 @@ impl RepoVoterContract {
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     ) {
         self.voting_engine
-            .vote(caller(), voting_id, voting_type, choice, stake);
+            .vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    /// Casts many ballots in one call. See [VotingEngine::vote_batch()].
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        self.voting_engine.vote_batch(caller(), ballots);
+    }
+
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        self.voting_engine.vote_as_delegate(
+            caller(),
+            voting_id,
+            voting_type,
+            choice,
+            stake,
+            delegators,
+        );
+    }
+
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .change_vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
+        self.voting_engine
+            .commit_vote(caller(), voting_id, commitment, stake);
+    }
+
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
     }
 
     pub fn slash_voter(&mut self, voter: Address) {
         self.access_control.ensure_whitelisted();
         self.voting_engine.slash_voter(voter);
     }
+
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// See [VotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+    }
+
+    /// Cancels the voting created by the caller. Allowed in the informal phase if no one else has voted.
+    ///
+    /// See [VotingEngine::withdraw_voting()].
+    pub fn withdraw_voting(&mut self, voting_id: VotingId) {
+        self.voting_engine.withdraw_voting(caller(), voting_id);
+    }
 }
 
 /// Event emitted when repo voting has been created.
@@ -156,6 +247,10 @@ pub struct RepoVotingCreated {
     config_informal_voting_time: u64,
     config_formal_quorum: u32,
     config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
     config_total_onboarded: Balance,
     config_double_time_between_votings: bool,
     config_voting_clearness_delta: Balance,
@@ -182,6 +277,10 @@ impl RepoVotingCreated {
             config_informal_voting_time: info.config_informal_voting_time,
             config_formal_quorum: info.config_formal_quorum,
             config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
             config_total_onboarded: info.config_total_onboarded,
             config_double_time_between_votings: info.config_double_time_between_votings,
             config_voting_clearness_delta: info.config_voting_clearness_delta,
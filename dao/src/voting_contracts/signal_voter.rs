@@ -0,0 +1,257 @@
+use odra::{
+    contract_env::caller,
+    types::{event::OdraEvent, Address, Balance},
+    Composer, Event, Instance, Mapping,
+};
+
+use crate::{
+    configuration::{ConfigurationBuilder, VoterKind},
+    modules::{refs::ContractRefs, AccessControl},
+    utils::types::DocumentHash,
+    voting::{
+        ballot::{Ballot, Choice},
+        types::VotingId,
+        voting_engine::{
+            events::VotingCreatedInfo,
+            voting_state_machine::{VotingStateMachine, VotingSummary, VotingType},
+            VotingEngine, VotingEngineComposer,
+        },
+    },
+};
+
+/// SignalVoterContract
+///
+/// It is responsible for temperature checks - quick, non-binding polls on a document.
+///
+/// A Signal Voting has a single phase of `SignalVotingTime`, there is no formal voting and no action is performed.
+/// No reputation is at risk - the ballots are not staked. The vote power of a voter is the voter's reputation
+/// balance at the moment of the voting creation.
+#[odra::module(skip_instance, events = [SignalVotingCreated])]
+pub struct SignalVoterContract {
+    refs: ContractRefs,
+    voting_engine: VotingEngine,
+    signal_votings: Mapping<VotingId, DocumentHash>,
+    access_control: AccessControl,
+}
+
+impl Instance for SignalVoterContract {
+    fn instance(namespace: &str) -> Self {
+        let refs = Composer::new(namespace, "refs").compose();
+        let voting_engine = VotingEngineComposer::new(namespace, "voting_engine")
+            .with_refs(&refs)
+            .compose();
+
+        Self {
+            refs,
+            voting_engine,
+            access_control: Composer::new(namespace, "access_control").compose(),
+            signal_votings: Composer::new(namespace, "signal_votings").compose(),
+        }
+    }
+}
+
+#[odra::module]
+impl SignalVoterContract {
+    delegate! {
+        to self.voting_engine {
+            pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool;
+            pub fn get_voting(
+                &self,
+                voting_id: VotingId,
+            ) -> Option<VotingStateMachine>;
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
+            pub fn get_ballot(
+                &self,
+                voting_id: VotingId,
+                voting_type: VotingType,
+                address: Address,
+            ) -> Option<Ballot>;
+            pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
+        }
+
+        to self.access_control {
+            pub fn change_ownership(&mut self, owner: Address);
+            pub fn add_to_whitelist(&mut self, address: Address);
+            pub fn remove_from_whitelist(&mut self, address: Address);
+            pub fn is_whitelisted(&self, address: Address) -> bool;
+            pub fn get_owner(&self) -> Option<Address>;
+        }
+
+        to self.refs {
+            pub fn variable_repository_address(&self) -> Address;
+            pub fn reputation_token_address(&self) -> Address;
+        }
+    }
+
+    #[odra(init)]
+    pub fn init(
+        &mut self,
+        variable_repository: Address,
+        reputation_token: Address,
+        va_token: Address,
+    ) {
+        self.refs.set_variable_repository(variable_repository);
+        self.refs.set_reputation_token(reputation_token);
+        self.refs.set_va_token(va_token);
+        self.access_control.init(caller())
+    }
+
+    /// Creates a new Signal Voting on the document. The creator votes in favor.
+    ///
+    /// # Events
+    /// * [`SignalVotingCreated`]
+    pub fn create_voting(&mut self, document_hash: DocumentHash) {
+        let voting_configuration = ConfigurationBuilder::new(
+            self.refs.va_token().total_supply(),
+            &self.refs.variable_repository().all_variables(),
+        )
+        .voter_kind(VoterKind::Signal)
+        .build();
+
+        let creator = caller();
+        let vote_power = self.refs.reputation_token().balance_of(creator);
+        let (info, _) = self
+            .voting_engine
+            .create_voting(creator, vote_power, voting_configuration);
+
+        self.signal_votings
+            .set(&info.voting_id, document_hash.clone());
+
+        SignalVotingCreated::new(document_hash, info).emit();
+    }
+
+    /// Finishes the voting. The result is only recorded, no action is performed and no formal voting starts.
+    ///
+    /// See [VotingEngine::finish_voting()].
+    pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
+        self.voting_engine.finish_voting(voting_id, voting_type)
+    }
+
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// See [VotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+    }
+
+    /// Returns the document the voting is about.
+    pub fn get_document_hash(&self, voting_id: VotingId) -> Option<DocumentHash> {
+        self.signal_votings.get(&voting_id)
+    }
+
+    /// Casts a ballot. The vote power is the voter's reputation balance at the voting creation.
+    ///
+    /// See [VotingEngine::vote()].
+    pub fn vote(&mut self, voting_id: VotingId, choice: Choice, rationale: Option<DocumentHash>) {
+        let voter = caller();
+        let voting = self.voting_engine.get_voting_or_revert(voting_id);
+        let vote_power = self
+            .refs
+            .reputation_token()
+            .balance_of_at(voter, voting.created_at());
+        self.voting_engine.vote(
+            voter,
+            voting_id,
+            VotingType::Informal,
+            choice,
+            vote_power,
+            rationale,
+        );
+    }
+
+    /// Changes the choice of the caller's ballot. The creator's ballot can't be changed.
+    ///
+    /// See [VotingEngine::change_vote()].
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        rationale: Option<DocumentHash>,
+    ) {
+        let voter = caller();
+        let voting = self.voting_engine.get_voting_or_revert(voting_id);
+        let vote_power = self
+            .refs
+            .reputation_token()
+            .balance_of_at(voter, voting.created_at());
+        self.voting_engine.change_vote(
+            voter,
+            voting_id,
+            VotingType::Informal,
+            choice,
+            vote_power,
+            rationale,
+        );
+    }
+
+    /// Withdraws the caller's ballot. The creator's ballot can't be withdrawn.
+    ///
+    /// See [VotingEngine::withdraw_vote()].
+    pub fn withdraw_vote(&mut self, voting_id: VotingId) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, VotingType::Informal);
+    }
+
+    /// Casts many ballots in one call. The stakes are ignored - the vote power of each ballot is
+    /// the voter's reputation balance at the voting creation.
+    ///
+    /// See [VotingEngine::vote_batch()].
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        let voter = caller();
+        let ballots = ballots
+            .into_iter()
+            .map(|(voting_id, voting_type, choice, _)| {
+                let vote_power = self
+                    .voting_engine
+                    .get_voting(voting_id)
+                    .map(|voting| {
+                        self.refs
+                            .reputation_token()
+                            .balance_of_at(voter, voting.created_at())
+                    })
+                    .unwrap_or_default();
+                (voting_id, voting_type, choice, vote_power)
+            })
+            .collect();
+        self.voting_engine.vote_batch(voter, ballots);
+    }
+}
+
+/// Event emitted when signal voting has been created.
+#[derive(Debug, PartialEq, Eq, Event)]
+pub struct SignalVotingCreated {
+    document_hash: DocumentHash,
+    creator: Address,
+    voting_id: VotingId,
+    config_quorum: u32,
+    config_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_stake_quorum: Balance,
+    config_pass_threshold: Balance,
+    config_total_onboarded: Balance,
+}
+
+impl SignalVotingCreated {
+    pub fn new(document_hash: DocumentHash, info: VotingCreatedInfo) -> Self {
+        Self {
+            document_hash,
+            creator: info.creator,
+            voting_id: info.voting_id,
+            config_quorum: info.config_informal_quorum,
+            config_voting_time: info.config_informal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_stake_quorum: info.config_informal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
+            config_total_onboarded: info.config_total_onboarded,
+        }
+    }
+}
@@ -1,22 +1,22 @@
 use odra::{
     contract_env::{caller, revert},
-    types::{event::OdraEvent, Address, Balance, BlockTime},
+    types::{event::OdraEvent, Address, Balance, BlockTime, Bytes},
     Composer, Event, Instance, Mapping, OdraType, UnwrapOrRevert, Variable,
 };
 
 use crate::rules::validation::IsVa;
 use crate::rules::RulesBuilder;
 use crate::{
-    configuration::ConfigurationBuilder,
+    configuration::{ConfigurationBuilder, VoterKind},
     modules::{refs::ContractRefs, AccessControl},
-    utils::Error,
+    utils::{types::DocumentHash, Error},
     voting::{
-        ballot::{Ballot, Choice},
+        ballot::{Ballot, Choice, SealedBallot, SignedBallot},
         types::VotingId,
         voting_engine::{
             events::VotingCreatedInfo,
             voting_state_machine::{VotingResult, VotingStateMachine, VotingSummary, VotingType},
-            VotingEngine, VotingEngineComposer,
+            without_rationale, VotingEngine, VotingEngineComposer,
         },
     },
 };
@@ -56,16 +56,29 @@ impl SlashingVoterContract {
         to self.voting_engine {
             pub fn voting_exists(&self, voting_id: VotingId, voting_type: VotingType) -> bool;
             pub fn get_voter(&self, voting_id: VotingId, voting_type: VotingType, at: u32) -> Option<Address>;
+            pub fn voters_count(&self, voting_id: VotingId, voting_type: VotingType) -> u32;
+            pub fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+            pub fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<Ballot>;
+            pub fn ballots_page(&self, voting_id: VotingId, voting_type: VotingType, offset: u32, limit: u32) -> Vec<Ballot>;
             pub fn get_voting(
                 &self,
                 voting_id: VotingId,
             ) -> Option<VotingStateMachine>;
+            pub fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, VotingType)>;
+            #[odra(payable)]
+            pub fn fund_keeper_reward_pool(&mut self);
+            pub fn get_keeper_reward_pool(&self) -> Balance;
             pub fn get_ballot(
                 &self,
                 voting_id: VotingId,
                 voting_type: VotingType,
                 voter: Address,
             ) -> Option<Ballot>;
+            pub fn get_sealed_ballot(&self, voting_id: VotingId, address: Address) -> Option<SealedBallot>;
+            pub fn get_nonce(&self, voter: Address) -> u64;
+            pub fn get_reputation_dust(&self) -> Balance;
+            pub fn get_formal_participation(&self, offset: u32, limit: u32) -> Vec<(BlockTime, Vec<Address>)>;
         }
 
         to self.access_control {
@@ -120,6 +133,7 @@ impl SlashingVoterContract {
             self.refs.va_token().total_supply(),
             &self.refs.variable_repository().all_variables(),
         )
+        .voter_kind(VoterKind::Slashing)
         .build();
 
         let (info, _) = self
@@ -142,6 +156,7 @@ impl SlashingVoterContract {
         voting_type: VotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     ) {
         // Check if the caller is not a subject for the voting.
         let task = self.tasks.get(&voting_id).unwrap_or_revert();
@@ -149,7 +164,107 @@ impl SlashingVoterContract {
             revert(Error::SubjectOfSlashing);
         }
         self.voting_engine
-            .vote(caller(), voting_id, voting_type, choice, stake);
+            .vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn vote_with_signature(&mut self, signed_ballot: SignedBallot) {
+        let voter = self.voting_engine.verify_signed_ballot(&signed_ballot);
+        // Check if the voter is not a subject for the voting.
+        let task = self.tasks.get(&signed_ballot.voting_id).unwrap_or_revert();
+        if voter == task.subject {
+            revert(Error::SubjectOfSlashing);
+        }
+        self.voting_engine.vote(
+            voter,
+            signed_ballot.voting_id,
+            signed_ballot.voting_type,
+            signed_ballot.choice,
+            signed_ballot.stake,
+            signed_ballot.rationale,
+        );
+    }
+
+    /// Casts many ballots in one call. The caller cannot vote on their own slashing.
+    ///
+    /// See [VotingEngine::vote_batch()].
+    pub fn vote_batch(&mut self, ballots: Vec<(VotingId, VotingType, Choice, Balance)>) {
+        let voter = caller();
+        self.voting_engine.vote_batch_with(
+            voter,
+            without_rationale(ballots),
+            |voting_id, _, _, _| match self.tasks.get(&voting_id) {
+                Some(task) if task.subject == voter => Err(Error::SubjectOfSlashing),
+                Some(_) => Ok(()),
+                None => Err(Error::VotingDoesNotExist),
+            },
+        );
+    }
+
+    pub fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>) {
+        for signed_ballot in signed_ballots {
+            self.vote_with_signature(signed_ballot);
+        }
+    }
+
+    pub fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    ) {
+        // Check if neither the caller nor the delegators are a subject for the voting.
+        let task = self.tasks.get(&voting_id).unwrap_or_revert();
+        if caller() == task.subject || delegators.iter().any(|(d, _)| d == &task.subject) {
+            revert(Error::SubjectOfSlashing);
+        }
+        self.voting_engine.vote_as_delegate(
+            caller(),
+            voting_id,
+            voting_type,
+            choice,
+            stake,
+            delegators,
+        );
+    }
+
+    pub fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .change_vote(caller(), voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: VotingType) {
+        self.voting_engine
+            .withdraw_vote(caller(), voting_id, voting_type);
+    }
+
+    pub fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance) {
+        // Check if the caller is not a subject for the voting.
+        let task = self.tasks.get(&voting_id).unwrap_or_revert();
+        if caller() == task.subject {
+            revert(Error::SubjectOfSlashing);
+        }
+        self.voting_engine
+            .commit_vote(caller(), voting_id, commitment, stake);
+    }
+
+    pub fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    ) {
+        self.voting_engine
+            .reveal_vote(caller(), voting_id, choice, salt, rationale);
     }
 
     pub fn finish_voting(&mut self, voting_id: VotingId, voting_type: VotingType) -> VotingSummary {
@@ -164,6 +279,14 @@ impl SlashingVoterContract {
         self.access_control.ensure_whitelisted();
         self.voting_engine.slash_voter(voter);
     }
+
+    /// Cancels an active voting. Only the guardian can call this method.
+    ///
+    /// See [VotingEngine::guardian_cancel_voting()].
+    pub fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash) {
+        self.voting_engine
+            .guardian_cancel_voting(caller(), voting_id, reason);
+    }
 }
 
 impl SlashingVoterContract {
@@ -217,6 +340,10 @@ pub struct SlashingVotingCreated {
     config_informal_voting_time: u64,
     config_formal_quorum: u32,
     config_formal_voting_time: u64,
+    config_stake_weighted_quorum: bool,
+    config_informal_stake_quorum: Balance,
+    config_formal_stake_quorum: Balance,
+    config_pass_threshold: Balance,
     config_total_onboarded: Balance,
     config_double_time_between_votings: bool,
     config_voting_clearness_delta: Balance,
@@ -235,6 +362,10 @@ impl SlashingVotingCreated {
             config_informal_voting_time: info.config_informal_voting_time,
             config_formal_quorum: info.config_formal_quorum,
             config_formal_voting_time: info.config_formal_voting_time,
+            config_stake_weighted_quorum: info.config_stake_weighted_quorum,
+            config_informal_stake_quorum: info.config_informal_stake_quorum,
+            config_formal_stake_quorum: info.config_formal_stake_quorum,
+            config_pass_threshold: info.config_pass_threshold,
             config_total_onboarded: info.config_total_onboarded,
             config_double_time_between_votings: info.config_double_time_between_votings,
             config_voting_clearness_delta: info.config_voting_clearness_delta,
@@ -35,10 +35,14 @@ impl DaoWorld {
             Contract::RepoVoter => self.repo_voter.address(),
             Contract::SlashingVoter => self.slashing_voter.address(),
             Contract::SimpleVoter => self.simple_voter.address(),
+            Contract::SignalVoter => self.signal_voter.address(),
             Contract::ReputationVoter => self.reputation_voter.address(),
+            Contract::MultiOptionVoter => self.multi_option_voter.address(),
             Contract::BidEscrow => self.bid_escrow.address(),
             Contract::Onboarding => self.onboarding.address(),
             Contract::CSPRRateProvider => self.rate_provider.address(),
+            Contract::Participation => self.participation.address(),
+            Contract::GovernanceExecutor => self.governance_executor.address(),
         }
     }
 }
@@ -0,0 +1,36 @@
+use odra::{test_env, types::Balance};
+
+use crate::common::{params::Account, DaoWorld};
+
+#[allow(dead_code)]
+impl DaoWorld {
+    /// Runs the `f` as the `caller` and records the gas the call used.
+    pub fn measure_gas<F: FnOnce(&mut Self)>(&mut self, caller: &Account, f: F) {
+        let address = self.get_address(caller);
+        let before = test_env::total_gas_used(address);
+        self.set_caller(caller);
+        f(self);
+        let used = test_env::total_gas_used(address) - before;
+        self.gas_used.push(used);
+    }
+
+    /// Returns the total gas used by the first and the last `count` measured calls and clears the measurements.
+    pub fn take_gas_used(&mut self, count: usize) -> (Balance, Balance) {
+        let gas_used = std::mem::take(&mut self.gas_used);
+        assert!(
+            gas_used.len() >= count,
+            "Only {} calls measured",
+            gas_used.len()
+        );
+        let first = gas_used
+            .iter()
+            .take(count)
+            .fold(Balance::zero(), |acc, gas| acc + *gas);
+        let last = gas_used
+            .iter()
+            .rev()
+            .take(count)
+            .fold(Balance::zero(), |acc, gas| acc + *gas);
+        (first, last)
+    }
+}
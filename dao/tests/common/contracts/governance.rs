@@ -0,0 +1,68 @@
+use dao::{
+    utils::{consts, ContractCall, Error},
+    voting::types::VotingId,
+};
+use odra::{test_env, types::CallArgs};
+
+use crate::common::{
+    params::{Account, Contract},
+    DaoWorld,
+};
+
+#[allow(dead_code)]
+impl DaoWorld {
+    pub fn failing_allow_governance_call(
+        &mut self,
+        caller: &Account,
+        contract: &Contract,
+        entry_point: &str,
+        expected_error: Error,
+    ) {
+        let address = self.get_contract_address(contract);
+        self.set_caller(caller);
+        test_env::assert_exception(expected_error, || {
+            self.governance_executor
+                .allow_call(address, entry_point.to_string())
+        });
+    }
+
+    pub fn governance_contract_calls(&self, voting_id: VotingId) -> Vec<ContractCall> {
+        self.governance_executor.get_contract_calls(voting_id)
+    }
+
+    /// Builds a call that adds an entry point of the `contract` to the Governance Executor allowlist
+    /// or removes it, depending on the `allowlist_entry_point`.
+    pub fn allowlist_call(
+        &self,
+        allowlist_entry_point: &str,
+        contract: &Contract,
+        entry_point: &str,
+    ) -> ContractCall {
+        let mut call_args = CallArgs::new();
+        call_args.insert(
+            consts::ARG_ADDRESS.to_string(),
+            self.get_contract_address(contract),
+        );
+        call_args.insert(consts::ARG_ENTRY_POINT.to_string(), entry_point.to_string());
+
+        ContractCall {
+            address: *self.governance_executor.address(),
+            entry_point: allowlist_entry_point.to_string(),
+            call_args,
+            amount: None,
+        }
+    }
+
+    /// Builds a call that cancels the action queued by a voting in the `contract`.
+    pub fn cancel_action_call(&self, contract: &Contract, voting_id: VotingId) -> ContractCall {
+        let mut call_args = CallArgs::new();
+        call_args.insert("voting_id".to_string(), voting_id);
+
+        ContractCall {
+            address: self.get_contract_address(contract),
+            entry_point: consts::EP_CANCEL_ACTION.to_string(),
+            call_args,
+            amount: None,
+        }
+    }
+}
@@ -1,9 +1,14 @@
 mod account;
+mod benchmark;
 mod bid_escrow;
 pub mod cspr;
+mod governance;
+mod multi_option;
 mod ownership;
+mod participation;
 mod rate_provider;
 mod reputation;
+mod signal;
 pub mod tokens;
 mod variable;
 pub mod voting;
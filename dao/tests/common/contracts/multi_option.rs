@@ -0,0 +1,132 @@
+use dao::{
+    utils::Error,
+    voting::{
+        multi_option_engine::multi_option_voting::{TallyMethod, VotingOption},
+        types::VotingId,
+    },
+};
+use odra::test_env;
+
+use crate::common::{
+    params::{voting::VotingType, Account, ReputationBalance},
+    DaoWorld,
+};
+
+#[allow(dead_code)]
+impl DaoWorld {
+    pub fn create_multi_option_voting(
+        &mut self,
+        creator: &Account,
+        options: Vec<VotingOption>,
+        tally_method: TallyMethod,
+        ranking: Vec<u32>,
+        stake: ReputationBalance,
+    ) {
+        self.set_caller(creator);
+        self.multi_option_voter
+            .create_voting(options, tally_method, ranking, *stake);
+    }
+
+    pub fn failing_create_multi_option_voting(
+        &mut self,
+        creator: &Account,
+        options: Vec<VotingOption>,
+        tally_method: TallyMethod,
+        ranking: Vec<u32>,
+        stake: ReputationBalance,
+        expected_error: Error,
+    ) {
+        self.set_caller(creator);
+        test_env::assert_exception(expected_error, || {
+            self.multi_option_voter
+                .create_voting(options, tally_method, ranking, *stake)
+        });
+    }
+
+    pub fn rank_options(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: ReputationBalance,
+    ) {
+        self.set_caller(voter);
+        self.multi_option_voter
+            .vote(voting_id, voting_type.into(), ranking, *stake, None);
+    }
+
+    pub fn failing_rank_options(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: ReputationBalance,
+        expected_error: Error,
+    ) {
+        self.set_caller(voter);
+        test_env::assert_exception(expected_error, || {
+            self.multi_option_voter
+                .vote(voting_id, voting_type.into(), ranking, *stake, None)
+        });
+    }
+
+    pub fn change_ranking(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        ranking: Vec<u32>,
+        stake: ReputationBalance,
+    ) {
+        self.set_caller(voter);
+        self.multi_option_voter
+            .change_vote(voting_id, voting_type.into(), ranking, *stake, None);
+    }
+
+    pub fn withdraw_ranking(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+    ) {
+        self.set_caller(voter);
+        self.multi_option_voter
+            .withdraw_vote(voting_id, voting_type.into());
+    }
+
+    pub fn failing_withdraw_ranking(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        expected_error: Error,
+    ) {
+        self.set_caller(voter);
+        test_env::assert_exception(expected_error, || {
+            self.multi_option_voter
+                .withdraw_vote(voting_id, voting_type.into())
+        });
+    }
+
+    pub fn multi_option_voting_winner(&self, voting_id: VotingId) -> Option<u32> {
+        self.multi_option_voter
+            .get_multi_option_voting(voting_id)
+            .expect("Voting does not exists")
+            .winner()
+    }
+
+    /// Builds an option that updates a variable in the Variable Repository if it wins.
+    pub fn variable_update_option(
+        &self,
+        document_hash: &str,
+        key: &str,
+        value: &str,
+    ) -> VotingOption {
+        VotingOption {
+            document_hash: document_hash.to_string(),
+            contract_calls: vec![self.variable_update_call(key, value)],
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use dao::utils::Error;
+use odra::test_env;
+
+use crate::common::{params::Account, DaoWorld};
+
+#[allow(dead_code)]
+impl DaoWorld {
+    pub fn settle_participation_epoch(&mut self, caller: &Account, limit: u32) {
+        self.set_caller(caller);
+        self.participation.settle_epoch(limit);
+    }
+
+    pub fn failing_settle_participation_epoch(&mut self, caller: &Account, expected_error: Error) {
+        self.set_caller(caller);
+        test_env::assert_exception(expected_error, || self.participation.settle_epoch(100));
+    }
+
+    pub fn settled_participation_epochs(&self) -> u64 {
+        self.participation.get_settled_epochs()
+    }
+}
@@ -34,6 +34,28 @@ impl DaoWorld {
         self.reputation_token.mint(recipient, amount.0);
     }
 
+    pub fn delegate_voting_power(
+        &mut self,
+        delegator: &Account,
+        delegate: &Account,
+        voting_contract: Option<&Account>,
+        max_stake: ReputationBalance,
+    ) {
+        let delegate = self.get_address(delegate);
+        let voting_contract = voting_contract.map(|contract| self.get_address(contract));
+
+        self.set_caller(delegator);
+        self.reputation_token
+            .delegate(delegate, voting_contract, max_stake.0);
+    }
+
+    pub fn disable_delegation(&mut self, delegator: &Account, voting_contract: &Account) {
+        let voting_contract = self.get_address(voting_contract);
+
+        self.set_caller(delegator);
+        self.reputation_token.disable_delegation(voting_contract);
+    }
+
     pub fn assert_staked_reputation(&self, account: &Account, expected_balance: ReputationBalance) {
         let real_reputation_stake = self.staked_reputation(account);
 
@@ -58,6 +80,10 @@ impl DaoWorld {
         );
     }
 
+    pub fn assert_exact_total_supply(&self, expected_balance: ReputationBalance) {
+        assert_eq!(self.reputation_token.total_supply(), *expected_balance);
+    }
+
     pub fn assert_total_supply(&self, expected_balance: ReputationBalance) {
         let total_reputation = self.reputation_token.total_supply();
 
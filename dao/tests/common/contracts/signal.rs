@@ -0,0 +1,64 @@
+use dao::{
+    utils::{types::DocumentHash, Error},
+    voting::{
+        ballot::Choice,
+        types::VotingId,
+        voting_engine::voting_state_machine::{VotingSummary, VotingType},
+    },
+};
+use odra::test_env;
+
+use crate::common::{params::Account, DaoWorld};
+
+#[allow(dead_code)]
+impl DaoWorld {
+    pub fn create_signal_voting(&mut self, creator: &Account, document_hash: DocumentHash) {
+        self.set_caller(creator);
+        self.signal_voter.create_voting(document_hash);
+    }
+
+    pub fn signal_vote(&mut self, voter: &Account, voting_id: VotingId, choice: Choice) {
+        self.set_caller(voter);
+        self.signal_voter.vote(voting_id, choice, None);
+    }
+
+    pub fn failing_signal_vote(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        choice: Choice,
+        expected_error: Error,
+    ) {
+        self.set_caller(voter);
+        test_env::assert_exception(expected_error, || {
+            self.signal_voter.vote(voting_id, choice, None)
+        });
+    }
+
+    pub fn signal_change_vote(&mut self, voter: &Account, voting_id: VotingId, choice: Choice) {
+        self.set_caller(voter);
+        self.signal_voter.change_vote(voting_id, choice, None);
+    }
+
+    pub fn signal_withdraw_vote(&mut self, voter: &Account, voting_id: VotingId) {
+        self.set_caller(voter);
+        self.signal_voter.withdraw_vote(voting_id);
+    }
+
+    pub fn failing_signal_withdraw_vote(
+        &mut self,
+        voter: &Account,
+        voting_id: VotingId,
+        expected_error: Error,
+    ) {
+        self.set_caller(voter);
+        test_env::assert_exception(expected_error, || {
+            self.signal_voter.withdraw_vote(voting_id)
+        });
+    }
+
+    pub fn finish_signal_voting(&mut self, voting_id: VotingId) -> VotingSummary {
+        self.signal_voter
+            .finish_voting(voting_id, VotingType::Informal)
+    }
+}
@@ -1,6 +1,7 @@
-use odra::types::{Bytes, OdraType};
+use dao::utils::{consts, ContractCall};
+use odra::types::{Bytes, CallArgs, OdraType};
 
-use crate::common::DaoWorld;
+use crate::common::{helpers::value_to_bytes, params::Account, DaoWorld};
 
 impl DaoWorld {
     // sets variable value
@@ -8,9 +9,39 @@ impl DaoWorld {
         self.variable_repository.update_at(name, value, None);
     }
 
+    /// Serializes a variable value. Address variables are given as account names.
+    pub fn variable_to_bytes(&self, key: &str, value: &str) -> Bytes {
+        match key {
+            consts::GUARDIAN_ADDRESS
+            | consts::VETO_VOTER_ADDRESS
+            | consts::GOVERNANCE_EXECUTOR_ADDRESS
+            | consts::TREASURY_ADDRESS => {
+                let account = value.parse::<Account>().unwrap();
+                Some(self.get_address(&account)).serialize().unwrap().into()
+            }
+            _ => value_to_bytes(value, key),
+        }
+    }
+
     // gets variable value
     pub fn get_variable_or_none<T: OdraType>(&self, name: &str) -> Option<T> {
         let bytes = self.variable_repository.get(name.to_string()).unwrap();
         T::deserialize(bytes.as_slice())
     }
+
+    /// Builds a call that updates a variable in the Variable Repository.
+    pub fn variable_update_call(&self, key: &str, value: &str) -> ContractCall {
+        let value: Bytes = self.variable_to_bytes(key, value);
+        let mut call_args = CallArgs::new();
+        call_args.insert(consts::ARG_KEY.to_string(), key.to_string());
+        call_args.insert(consts::ARG_VALUE.to_string(), value);
+        call_args.insert(consts::ARG_ACTIVATION_TIME.to_string(), None::<u64>);
+
+        ContractCall {
+            address: *self.variable_repository.address(),
+            entry_point: consts::EP_UPDATE_AT.to_string(),
+            call_args,
+            amount: None,
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use dao::{
-    utils::{types::DocumentHash, Error},
+    utils::{types::DocumentHash, vote_batch_error, Error},
     voting::{
-        ballot::{Ballot as DaoBallot, Choice},
+        ballot::{ballot_commitment, Ballot as DaoBallot, Choice, SignedBallot},
         types::VotingId,
         voting_engine::voting_state_machine::{
             VotingStateMachine, VotingSummary, VotingType as DaoVotingType,
@@ -10,7 +10,7 @@ use dao::{
 };
 use odra::{
     test_env,
-    types::{Address, Balance, Bytes},
+    types::{Address, Balance, BlockTime, Bytes},
 };
 
 use crate::common::{
@@ -31,17 +31,76 @@ pub trait Voter {
         voting_type: DaoVotingType,
         choice: Choice,
         stake: Balance,
+        rationale: Option<DocumentHash>,
     );
+    fn vote_as_delegate(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: DaoVotingType,
+        choice: Choice,
+        stake: Balance,
+        delegators: Vec<(Address, Balance)>,
+    );
+    fn change_vote(
+        &mut self,
+        voting_id: VotingId,
+        voting_type: DaoVotingType,
+        choice: Choice,
+        stake: Balance,
+        rationale: Option<DocumentHash>,
+    );
+    fn withdraw_vote(&mut self, voting_id: VotingId, voting_type: DaoVotingType);
+    fn withdraw_voting(&mut self, voting_id: VotingId);
+    fn commit_vote(&mut self, voting_id: VotingId, commitment: Bytes, stake: Balance);
+    fn reveal_vote(
+        &mut self,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: Bytes,
+        rationale: Option<DocumentHash>,
+    );
+    fn vote_with_signature(&mut self, signed_ballot: SignedBallot);
+    fn vote_with_signatures(&mut self, signed_ballots: Vec<SignedBallot>);
+    fn vote_batch(&mut self, ballots: Vec<(VotingId, DaoVotingType, Choice, Balance)>);
+    fn vote_batch_with_rationale(
+        &mut self,
+        ballots: Vec<(
+            VotingId,
+            DaoVotingType,
+            Choice,
+            Balance,
+            Option<DocumentHash>,
+        )>,
+    );
+    fn get_nonce(&self, voter: Address) -> u64;
+    fn get_reputation_dust(&self) -> Balance;
+    fn fund_keeper_reward_pool(&mut self);
+    fn get_keeper_reward_pool(&self) -> Balance;
     fn finish_voting(&mut self, voting_id: VotingId, voting_type: DaoVotingType) -> VotingSummary;
     fn slash_voter(&mut self, voter: Address);
+    fn execute_action(&mut self, voting_id: VotingId);
+    fn cancel_action(&mut self, voting_id: VotingId);
+    fn guardian_cancel_voting(&mut self, voting_id: VotingId, reason: DocumentHash);
     fn voting_exists(&self, voting_id: VotingId, voting_type: DaoVotingType) -> bool;
     fn get_voting(&self, voting_id: VotingId) -> Option<VotingStateMachine>;
+    fn get_actionable_votings(&self, offset: u32, limit: u32) -> Vec<(VotingId, DaoVotingType)>;
     fn get_ballot(
         &self,
         voting_id: VotingId,
         voting_type: DaoVotingType,
         address: Address,
     ) -> Option<DaoBallot>;
+    fn voters_count(&self, voting_id: VotingId, voting_type: DaoVotingType) -> u32;
+    fn active_votings(&self, offset: u32, limit: u32) -> Vec<VotingId>;
+    fn votings_by_creator(&self, creator: Address, offset: u32, limit: u32) -> Vec<VotingId>;
+    fn ballots_of(&self, voter: Address, offset: u32, limit: u32) -> Vec<DaoBallot>;
+    fn ballots_page(
+        &self,
+        voting_id: VotingId,
+        voting_type: DaoVotingType,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<DaoBallot>;
 }
 
 #[allow(dead_code)]
@@ -85,6 +144,9 @@ impl DaoWorld {
                     *stake,
                 )
             }
+            builder::VotingSetup::Governance(contract_calls) => self
+                .governance_executor
+                .create_voting(contract_calls, *stake),
         }
     }
 
@@ -132,10 +194,250 @@ impl DaoWorld {
         let choice = ballot.choice.into();
         let stake = ballot.stake.0;
         let voting_type = ballot.voting_type.into();
+        let rationale = ballot.rationale.clone();
+
+        self.set_caller(&ballot.voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).vote(voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn vote_as_delegate(
+        &mut self,
+        contract: &Account,
+        ballot: &Ballot,
+        delegators: Vec<(Account, ReputationBalance)>,
+    ) {
+        let voting_id = ballot.voting_id;
+        let choice = ballot.choice.into();
+        let stake = ballot.stake.0;
+        let voting_type = ballot.voting_type.into();
+        let delegators = delegators
+            .iter()
+            .map(|(delegator, stake)| (self.get_address(delegator), stake.0))
+            .collect();
+
+        self.set_caller(&ballot.voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).vote_as_delegate(voting_id, voting_type, choice, stake, delegators);
+    }
+
+    pub fn failing_vote_as_delegate(
+        &mut self,
+        contract: &Account,
+        ballot: &Ballot,
+        delegators: Vec<(Account, ReputationBalance)>,
+        expected_error: Error,
+    ) {
+        let voting_id = ballot.voting_id;
+        let choice = ballot.choice.into();
+        let stake = ballot.stake.0;
+        let voting_type = ballot.voting_type.into();
+        let delegators: Vec<(Address, Balance)> = delegators
+            .iter()
+            .map(|(delegator, stake)| (self.get_address(delegator), stake.0))
+            .collect();
+
+        self.set_caller(&ballot.voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).vote_as_delegate(
+                voting_id,
+                voting_type,
+                choice,
+                stake,
+                delegators.clone(),
+            )
+        })
+    }
+
+    pub fn change_vote(&mut self, contract: &Account, ballot: &Ballot) {
+        let voting_id = ballot.voting_id;
+        let choice = ballot.choice.into();
+        let stake = ballot.stake.0;
+        let voting_type = ballot.voting_type.into();
+        let rationale = ballot.rationale.clone();
+
+        self.set_caller(&ballot.voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).change_vote(voting_id, voting_type, choice, stake, rationale);
+    }
+
+    pub fn failing_change_vote(
+        &mut self,
+        contract: &Account,
+        ballot: &Ballot,
+        expected_error: Error,
+    ) {
+        let voting_id = ballot.voting_id;
+        let choice = ballot.choice.into();
+        let stake = ballot.stake.0;
+        let voting_type = ballot.voting_type.into();
+        let rationale = ballot.rationale.clone();
+
+        self.set_caller(&ballot.voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).change_vote(voting_id, voting_type, choice, stake, rationale)
+        })
+    }
+
+    pub fn withdraw_vote(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+    ) {
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).withdraw_vote(voting_id, voting_type.into());
+    }
+
+    pub fn failing_withdraw_vote(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        expected_error: Error,
+    ) {
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).withdraw_vote(voting_id, voting_type.into())
+        })
+    }
+
+    pub fn withdraw_voting(&mut self, contract: &Account, creator: &Account, voting_id: VotingId) {
+        self.set_caller(creator);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).withdraw_voting(voting_id);
+    }
+
+    pub fn failing_withdraw_voting(
+        &mut self,
+        contract: &Account,
+        creator: &Account,
+        voting_id: VotingId,
+        expected_error: Error,
+    ) {
+        self.set_caller(creator);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).withdraw_voting(voting_id)
+        })
+    }
+
+    pub fn vote_batch(&mut self, contract: &Account, voter: &Account, ballots: &[Ballot]) {
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).vote_batch(batch_items(ballots));
+    }
+
+    pub fn failing_vote_batch(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        ballots: &[Ballot],
+        failing_index: usize,
+        error: Error,
+    ) {
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(vote_batch_error(failing_index, error), || {
+            VoterRef::at(&contract).vote_batch(batch_items(ballots))
+        })
+    }
+
+    pub fn vote_batch_with_rationale(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        ballots: &[Ballot],
+    ) {
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).vote_batch_with_rationale(batch_items_with_rationale(ballots));
+    }
+
+    pub fn failing_vote_batch_with_rationale(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        ballots: &[Ballot],
+        failing_index: usize,
+        error: Error,
+    ) {
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(vote_batch_error(failing_index, error), || {
+            VoterRef::at(&contract).vote_batch_with_rationale(batch_items_with_rationale(ballots))
+        })
+    }
+
+    pub fn commit_vote(&mut self, contract: &Account, ballot: &Ballot, salt: &str) {
+        let voting_id = ballot.voting_id;
+        let stake = ballot.stake.0;
+        let salt = Bytes::from(salt.as_bytes().to_vec());
+        let commitment = ballot_commitment(ballot.choice.into(), stake, &salt);
 
         self.set_caller(&ballot.voter);
         let contract = self.get_address(contract);
-        VoterRef::at(&contract).vote(voting_id, voting_type, choice, stake);
+        VoterRef::at(&contract).commit_vote(voting_id, commitment, stake);
+    }
+
+    pub fn failing_commit_vote(
+        &mut self,
+        contract: &Account,
+        ballot: &Ballot,
+        salt: &str,
+        expected_error: Error,
+    ) {
+        let voting_id = ballot.voting_id;
+        let stake = ballot.stake.0;
+        let salt = Bytes::from(salt.as_bytes().to_vec());
+        let commitment = ballot_commitment(ballot.choice.into(), stake, &salt);
+
+        self.set_caller(&ballot.voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).commit_vote(voting_id, commitment, stake)
+        })
+    }
+
+    pub fn reveal_vote(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: &str,
+        rationale: Option<DocumentHash>,
+    ) {
+        let salt = Bytes::from(salt.as_bytes().to_vec());
+
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).reveal_vote(voting_id, choice, salt, rationale);
+    }
+
+    pub fn failing_reveal_vote(
+        &mut self,
+        contract: &Account,
+        voter: &Account,
+        voting_id: VotingId,
+        choice: Choice,
+        salt: &str,
+        rationale: Option<DocumentHash>,
+        expected_error: Error,
+    ) {
+        let salt = Bytes::from(salt.as_bytes().to_vec());
+
+        self.set_caller(voter);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).reveal_vote(voting_id, choice, salt, rationale)
+        })
     }
 
     pub fn failing_vote(&mut self, contract: &Account, ballot: &Ballot, expected_error: Error) {
@@ -143,11 +445,12 @@ impl DaoWorld {
         let choice = ballot.choice.into();
         let stake = ballot.stake.0;
         let voting_type = ballot.voting_type.into();
+        let rationale = ballot.rationale.clone();
 
         self.set_caller(&ballot.voter);
         let contract = self.get_address(contract);
         test_env::assert_exception(expected_error, || {
-            VoterRef::at(&contract).vote(voting_id, voting_type, choice, stake)
+            VoterRef::at(&contract).vote(voting_id, voting_type, choice, stake, rationale)
         })
     }
 
@@ -162,6 +465,87 @@ impl DaoWorld {
         VoterRef::at(&contract).finish_voting(voting_id, voting_type);
     }
 
+    /// Builds a ballot with the voter's next nonce and no deadline. The ballot must be signed before relaying.
+    pub fn unsigned_ballot(
+        &self,
+        contract: &Account,
+        voter: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        choice: Choice,
+        stake: ReputationBalance,
+    ) -> SignedBallot {
+        let voter = self.get_address(voter);
+        SignedBallot {
+            public_key: test_env::public_key(&voter),
+            voting_id,
+            voting_type: voting_type.into(),
+            choice,
+            stake: *stake,
+            rationale: None,
+            nonce: self.get_nonce(contract, &voter),
+            deadline: BlockTime::MAX,
+            signature: Bytes::from(vec![]),
+        }
+    }
+
+    pub fn sign_ballot(
+        &self,
+        contract: &Account,
+        signer: &Account,
+        mut ballot: SignedBallot,
+    ) -> SignedBallot {
+        let message = ballot.message(self.get_address(contract));
+        ballot.signature = test_env::sign_message(&message, &self.get_address(signer));
+        ballot
+    }
+
+    pub fn relay_ballots(
+        &mut self,
+        relayer: &Account,
+        contract: &Account,
+        ballots: Vec<SignedBallot>,
+    ) {
+        self.set_caller(relayer);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).vote_with_signatures(ballots);
+    }
+
+    pub fn failing_relay_ballot(
+        &mut self,
+        relayer: &Account,
+        contract: &Account,
+        ballot: SignedBallot,
+        expected_error: Error,
+    ) {
+        self.set_caller(relayer);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).vote_with_signature(ballot);
+        });
+    }
+
+    pub fn get_nonce(&self, contract: &Account, voter: &Address) -> u64 {
+        VoterRef::at(&self.get_address(contract)).get_nonce(*voter)
+    }
+
+    pub fn get_reputation_dust(&self, contract: &Account) -> Balance {
+        VoterRef::at(&self.get_address(contract)).get_reputation_dust()
+    }
+
+    pub fn failing_finish_voting(
+        &mut self,
+        contract: &Account,
+        voting_id: u32,
+        voting_type: VotingType,
+        expected_error: Error,
+    ) {
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).finish_voting(voting_id, voting_type.into());
+        });
+    }
+
     pub fn voting_exists(
         &self,
         contract: &Account,
@@ -182,6 +566,96 @@ impl DaoWorld {
         VoterRef::at(&contract).slash_voter(voter);
     }
 
+    pub fn execute_action(&mut self, caller: &Account, contract: &Account, voting_id: VotingId) {
+        self.set_caller(caller);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).execute_action(voting_id);
+    }
+
+    pub fn failing_execute_action(
+        &mut self,
+        caller: &Account,
+        contract: &Account,
+        voting_id: VotingId,
+        expected_error: Error,
+    ) {
+        self.set_caller(caller);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).execute_action(voting_id)
+        })
+    }
+
+    pub fn cancel_action(&mut self, caller: &Account, contract: &Account, voting_id: VotingId) {
+        self.set_caller(caller);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).cancel_action(voting_id);
+    }
+
+    pub fn failing_cancel_action(
+        &mut self,
+        caller: &Account,
+        contract: &Account,
+        voting_id: VotingId,
+        expected_error: Error,
+    ) {
+        self.set_caller(caller);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).cancel_action(voting_id)
+        })
+    }
+
+    pub fn guardian_cancel_voting(
+        &mut self,
+        caller: &Account,
+        contract: &Account,
+        voting_id: VotingId,
+        reason: DocumentHash,
+    ) {
+        self.set_caller(caller);
+        let contract = self.get_address(contract);
+        VoterRef::at(&contract).guardian_cancel_voting(voting_id, reason);
+    }
+
+    pub fn failing_guardian_cancel_voting(
+        &mut self,
+        caller: &Account,
+        contract: &Account,
+        voting_id: VotingId,
+        expected_error: Error,
+    ) {
+        self.set_caller(caller);
+        let contract = self.get_address(contract);
+        test_env::assert_exception(expected_error, || {
+            VoterRef::at(&contract).guardian_cancel_voting(voting_id, DocumentHash::from("reason"))
+        })
+    }
+
+    pub fn is_voting_actionable(&self, contract: &Account, voting_id: VotingId) -> bool {
+        let voter = VoterRef::at(&self.get_address(contract));
+        voter
+            .get_actionable_votings(0, 100)
+            .iter()
+            .any(|(id, _)| *id == voting_id)
+    }
+
+    pub fn fund_keeper_reward_pool(
+        &mut self,
+        contract: &Account,
+        funder: &Account,
+        amount: Balance,
+    ) {
+        self.set_caller(funder);
+        VoterRef::at(&self.get_address(contract))
+            .with_tokens(amount)
+            .fund_keeper_reward_pool();
+    }
+
+    pub fn get_keeper_reward_pool(&self, contract: &Account) -> Balance {
+        VoterRef::at(&self.get_address(contract)).get_keeper_reward_pool()
+    }
+
     pub fn get_voting(&mut self, contract: &Account, voting_id: VotingId) -> VotingStateMachine {
         let voter = VoterRef::at(&self.get_address(contract));
         voter.get_voting(voting_id).expect("Voting does not exists")
@@ -197,4 +671,92 @@ impl DaoWorld {
         let account = self.get_address(account);
         VoterRef::at(&self.get_address(contract)).get_ballot(voting_id, voting_type, account)
     }
+
+    pub fn voters_count(
+        &self,
+        contract: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+    ) -> u32 {
+        VoterRef::at(&self.get_address(contract)).voters_count(voting_id, voting_type.into())
+    }
+
+    pub fn active_votings(&self, contract: &Account, offset: u32, limit: u32) -> Vec<VotingId> {
+        VoterRef::at(&self.get_address(contract)).active_votings(offset, limit)
+    }
+
+    pub fn votings_by_creator(
+        &self,
+        contract: &Account,
+        creator: &Account,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<VotingId> {
+        let creator = self.get_address(creator);
+        VoterRef::at(&self.get_address(contract)).votings_by_creator(creator, offset, limit)
+    }
+
+    pub fn ballots_of(
+        &self,
+        contract: &Account,
+        voter: &Account,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<DaoBallot> {
+        let voter = self.get_address(voter);
+        VoterRef::at(&self.get_address(contract)).ballots_of(voter, offset, limit)
+    }
+
+    pub fn ballots_page(
+        &self,
+        contract: &Account,
+        voting_id: VotingId,
+        voting_type: VotingType,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<DaoBallot> {
+        VoterRef::at(&self.get_address(contract)).ballots_page(
+            voting_id,
+            voting_type.into(),
+            offset,
+            limit,
+        )
+    }
+}
+
+fn batch_items(ballots: &[Ballot]) -> Vec<(VotingId, DaoVotingType, Choice, Balance)> {
+    ballots
+        .iter()
+        .map(|ballot| {
+            (
+                ballot.voting_id,
+                ballot.voting_type.into(),
+                ballot.choice.into(),
+                ballot.stake.0,
+            )
+        })
+        .collect()
+}
+
+fn batch_items_with_rationale(
+    ballots: &[Ballot],
+) -> Vec<(
+    VotingId,
+    DaoVotingType,
+    Choice,
+    Balance,
+    Option<DocumentHash>,
+)> {
+    ballots
+        .iter()
+        .map(|ballot| {
+            (
+                ballot.voting_id,
+                ballot.voting_type.into(),
+                ballot.choice.into(),
+                ballot.stake.0,
+                ballot.rationale.clone(),
+            )
+        })
+        .collect()
 }
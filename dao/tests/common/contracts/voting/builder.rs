@@ -1,10 +1,12 @@
+use dao::utils::{consts, ContractCall};
+use dao::voting::types::VotingId;
 use dao::voting_contracts::ReputationAction;
 use dao::{utils::types::DocumentHash, voting_contracts::AdminAction};
 use odra::types::{Address, BlockTime, Bytes};
 
 use crate::common::params::ReputationBalance;
 use crate::common::{
-    helpers::{to_milliseconds, value_to_bytes},
+    helpers::to_milliseconds,
     params::{voting::Voting, Account, Contract},
     DaoWorld,
 };
@@ -49,7 +51,7 @@ pub fn build(world: &DaoWorld, voting: Voting) -> VotingSetup {
             let key = voting.get_parsed_arg::<String>(1);
 
             let value = voting.get_parsed_arg::<String>(2);
-            let value = value_to_bytes(&value, &key);
+            let value = world.variable_to_bytes(&key, &value);
 
             let activation_time = voting.get_parsed_arg_or_none::<String>(3).map(|s| {
                 let values = s.split(' ').collect::<Vec<_>>();
@@ -76,6 +78,27 @@ pub fn build(world: &DaoWorld, voting: Voting) -> VotingSetup {
 
             VotingSetup::Reputation(recipient_address, action, amount, Default::default())
         }
+        Contract::GovernanceExecutor => {
+            let arg = voting.get_parsed_arg::<String>(0);
+            let contract_call = match arg.as_str() {
+                consts::EP_CANCEL_ACTION => {
+                    let contract = voting.get_parsed_arg::<Contract>(1);
+                    let voting_id = voting.get_parsed_arg::<VotingId>(2);
+                    world.cancel_action_call(&contract, voting_id)
+                }
+                consts::EP_ALLOW_CALL | consts::EP_DISALLOW_CALL => {
+                    let contract = voting.get_parsed_arg::<Contract>(1);
+                    let entry_point = voting.get_parsed_arg::<String>(2);
+                    world.allowlist_call(&arg, &contract, &entry_point)
+                }
+                key => {
+                    let value = voting.get_parsed_arg::<String>(1);
+                    world.variable_update_call(key, &value)
+                }
+            };
+
+            VotingSetup::Governance(vec![contract_call])
+        }
         contract => panic!("{:?} is not a voting contract", contract),
     }
 }
@@ -88,4 +111,5 @@ pub enum VotingSetup {
     Repository(Address, String, Bytes, Option<BlockTime>),
     Simple(DocumentHash),
     Reputation(Address, ReputationAction, ReputationBalance, DocumentHash),
+    Governance(Vec<ContractCall>),
 }
@@ -9,16 +9,19 @@ use dao::{
     },
     utils_contracts::{
         CSPRRateProviderContractDeployer, CSPRRateProviderContractRef, DaoIdsContractDeployer,
+        ParticipationContractDeployer, ParticipationContractRef,
     },
     voting_contracts::{
-        AdminContractDeployer, AdminContractRef, KycVoterContractDeployer, KycVoterContractRef,
-        RepoVoterContractDeployer, RepoVoterContractRef, ReputationVoterContractDeployer,
-        ReputationVoterContractRef, SimpleVoterContractDeployer, SimpleVoterContractRef,
-        SlashingVoterContractDeployer, SlashingVoterContractRef,
+        AdminContractDeployer, AdminContractRef, GovernanceExecutorContractDeployer,
+        GovernanceExecutorContractRef, KycVoterContractDeployer, KycVoterContractRef,
+        MultiOptionVoterContractDeployer, MultiOptionVoterContractRef, RepoVoterContractDeployer,
+        RepoVoterContractRef, ReputationVoterContractDeployer, ReputationVoterContractRef,
+        SignalVoterContractDeployer, SignalVoterContractRef, SimpleVoterContractDeployer,
+        SimpleVoterContractRef, SlashingVoterContractDeployer, SlashingVoterContractRef,
     },
 };
 use odra::test_env;
-use odra::types::Address;
+use odra::types::{Address, Balance};
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 use std::fs::OpenOptions;
@@ -51,11 +54,16 @@ pub struct DaoWorld {
     pub kyc_voter: KycVoterContractRef,
     pub repo_voter: RepoVoterContractRef,
     pub simple_voter: SimpleVoterContractRef,
+    pub signal_voter: SignalVoterContractRef,
     pub slashing_voter: SlashingVoterContractRef,
+    pub multi_option_voter: MultiOptionVoterContractRef,
     pub bid_escrow: BidEscrowContractRef,
     pub onboarding: OnboardingRequestContractRef,
+    pub participation: ParticipationContractRef,
+    pub governance_executor: GovernanceExecutorContractRef,
     pub bids: HashMap<(u32, Address), BidId>,
     pub offers: HashMap<Address, JobOfferId>,
+    pub gas_used: Vec<Balance>,
 }
 
 impl DaoWorld {
@@ -118,11 +126,21 @@ impl Default for DaoWorld {
             *reputation_token.address(),
             *va_token.address(),
         );
+        let signal_voter = SignalVoterContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+        );
         let mut slashing_voter = SlashingVoterContractDeployer::init(
             *variable_repository.address(),
             *reputation_token.address(),
             *va_token.address(),
         );
+        let mut multi_option_voter = MultiOptionVoterContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+        );
         let mut bid_escrow = BidEscrowContractDeployer::init(
             *variable_repository.address(),
             *reputation_token.address(),
@@ -135,11 +153,21 @@ impl Default for DaoWorld {
             *kyc_token.address(),
             *va_token.address(),
         );
+        let mut participation = ParticipationContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+        );
+        let mut governance_executor = GovernanceExecutorContractDeployer::init(
+            *variable_repository.address(),
+            *reputation_token.address(),
+            *va_token.address(),
+        );
 
         whitelist!(
-            ids => [admin, kyc_voter, slashing_voter, repo_voter, reputation_voter, simple_voter, bid_escrow, onboarding],
-            variable_repository => [repo_voter],
-            reputation_token => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, bid_escrow, onboarding],
+            ids => [admin, kyc_voter, slashing_voter, repo_voter, reputation_voter, simple_voter, signal_voter, multi_option_voter, bid_escrow, onboarding, governance_executor],
+            variable_repository => [repo_voter, multi_option_voter, governance_executor],
+            reputation_token => [admin, repo_voter, reputation_voter, kyc_voter, slashing_voter, simple_voter, signal_voter, multi_option_voter, bid_escrow, onboarding, participation, governance_executor],
             va_token => [slashing_voter, bid_escrow, onboarding],
             kyc_token => [kyc_voter],
             admin => [slashing_voter],
@@ -148,8 +176,10 @@ impl Default for DaoWorld {
             repo_voter => [slashing_voter, simple_voter],
             reputation_voter => [slashing_voter],
             simple_voter => [slashing_voter],
+            multi_option_voter => [slashing_voter],
             slashing_voter => [slashing_voter],
-            bid_escrow => [slashing_voter]
+            bid_escrow => [slashing_voter],
+            governance_executor => [slashing_voter]
         );
 
         let slashable_contracts: Vec<Address> = vec![
@@ -161,13 +191,18 @@ impl Default for DaoWorld {
             simple_voter.address(),
             slashing_voter.address(),
             bid_escrow.address(),
+            governance_executor.address(),
         ]
         .into_iter()
         .cloned()
         .collect();
 
         // WON'T DO: Maybe in variable repo?
-        slashing_voter.update_slashable_contracts(slashable_contracts);
+        // The multi-option votings don't take part in the participation rewards.
+        let mut slashable_multi_option_contracts = slashable_contracts.clone();
+        slashable_multi_option_contracts.push(*multi_option_voter.address());
+        slashing_voter.update_slashable_contracts(slashable_multi_option_contracts);
+        participation.update_voting_contracts(slashable_contracts);
 
         Self {
             virtual_balances: Default::default(),
@@ -181,11 +216,16 @@ impl Default for DaoWorld {
             kyc_voter,
             repo_voter,
             simple_voter,
+            signal_voter,
             slashing_voter,
+            multi_option_voter,
             bid_escrow,
             onboarding,
+            participation,
+            governance_executor,
             bids: Default::default(),
             offers: Default::default(),
+            gas_used: Default::default(),
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::common::params::ReputationBalance;
 use dao::utils::consts::*;
+use dao::voting::reputation_redistribution::RedistributionPolicyKind;
 use odra::types::{Balance, BlockTime, Bytes, OdraType};
 use std::{fmt::Debug, str::FromStr};
 
@@ -33,14 +34,38 @@ pub fn value_to_bytes(value: &str, key: &str) -> Bytes {
             | INFORMAL_QUORUM_RATIO
             | FORMAL_QUORUM_RATIO
             | DEFAULT_REPUTATION_SLASH
-            | VOTING_CLEARNESS_DELTA => {
+            | VOTING_CLEARNESS_DELTA
+            | ADMIN_PASS_THRESHOLD
+            | REPO_PASS_THRESHOLD
+            | REPUTATION_PASS_THRESHOLD
+            | SLASHING_PASS_THRESHOLD
+            | KYC_PASS_THRESHOLD
+            | SIMPLE_PASS_THRESHOLD
+            | BID_ESCROW_PASS_THRESHOLD
+            | UNREVEALED_BALLOT_PENALTY
+            | REDISTRIBUTION_PARTIAL_LOSS_RATIO
+            | NON_PARTICIPATION_DECAY
+            | SIGNAL_QUORUM_RATIO => {
                 let value = odra::types::Balance::from_dec_str(value).unwrap();
                 Bytes::from(value.serialize().unwrap())
             }
-            POST_JOB_DOS_FEE => {
+            POST_JOB_DOS_FEE
+            | KEEPER_CSPR_REWARD
+            | KEEPER_REPUTATION_REWARD
+            | PARTICIPATION_REWARD => {
                 let value = odra::types::Balance::from_dec_str(value).unwrap();
                 Bytes::from(value.serialize().unwrap())
             }
+            REDISTRIBUTION_POLICY => {
+                let value = match value {
+                    "ProRata" => RedistributionPolicyKind::ProRata,
+                    "PartialLoss" => RedistributionPolicyKind::PartialLoss,
+                    "Treasury" => RedistributionPolicyKind::Treasury,
+                    "NoRedistribution" => RedistributionPolicyKind::NoRedistribution,
+                    _ => panic!("Unknown redistribution policy"),
+                };
+                Bytes::from(value.serialize().unwrap())
+            }
             _ => {
                 let value: u64 = value.parse().unwrap();
                 Bytes::from(value.serialize().unwrap())
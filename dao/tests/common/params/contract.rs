@@ -14,10 +14,14 @@ pub enum Contract {
     RepoVoter,
     SlashingVoter,
     SimpleVoter,
+    SignalVoter,
     ReputationVoter,
+    MultiOptionVoter,
     BidEscrow,
     Onboarding,
     CSPRRateProvider,
+    Participation,
+    GovernanceExecutor,
 }
 
 impl FromStr for Contract {
@@ -34,10 +38,14 @@ impl FromStr for Contract {
             "RepoVoter" => Self::RepoVoter,
             "SlashingVoter" => Self::SlashingVoter,
             "SimpleVoter" => Self::SimpleVoter,
+            "SignalVoter" => Self::SignalVoter,
             "ReputationVoter" => Self::ReputationVoter,
+            "MultiOptionVoter" => Self::MultiOptionVoter,
             "BidEscrow" => Self::BidEscrow,
             "Onboarding" => Self::Onboarding,
             "CSPRRateProvider" => Self::CSPRRateProvider,
+            "Participation" => Self::Participation,
+            "GovernanceExecutor" => Self::GovernanceExecutor,
             invalid => return Err(format!("Unknown contract {}", invalid)),
         };
         Ok(contract)
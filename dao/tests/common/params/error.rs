@@ -78,6 +78,22 @@ impl FromStr for Error {
             "VoterDoesNotExist" => dao::utils::Error::VoterDoesNotExist,
             "VotingDoesNotExist" => dao::utils::Error::VotingDoesNotExist,
             "ZeroStake" => dao::utils::Error::ZeroStake,
+            "SecretBallotRequired" => dao::utils::Error::SecretBallotRequired,
+            "StakeExceedsSnapshotBalance" => dao::utils::Error::StakeExceedsSnapshotBalance,
+            "EpochNotEnded" => dao::utils::Error::EpochNotEnded,
+            "CallNotAllowed" => dao::utils::Error::CallNotAllowed,
+            "NotGuardian" => dao::utils::Error::NotGuardian,
+            "GuardianCancelTooEarly" => dao::utils::Error::GuardianCancelTooEarly,
+            "NotVotingCreator" => dao::utils::Error::NotVotingCreator,
+            "VotingHasOtherBallots" => dao::utils::Error::VotingHasOtherBallots,
+            "VoteBatchTooLarge" => dao::utils::Error::VoteBatchTooLarge,
+            "CannotCancelAction" => dao::utils::Error::CannotCancelAction,
+            "DelegatedStakeExceedsLimit" => dao::utils::Error::DelegatedStakeExceedsLimit,
+            "NotGovernanceVoting" => dao::utils::Error::NotGovernanceVoting,
+            "TreasuryAddressNotSet" => dao::utils::Error::TreasuryAddressNotSet,
+            "QueuedActionNotFound" => dao::utils::Error::QueuedActionNotFound,
+            "TimelockNotExpired" => dao::utils::Error::TimelockNotExpired,
+            "QueuedActionNotPending" => dao::utils::Error::QueuedActionNotPending,
             "VotingAlreadyCanceled" => dao::utils::Error::VotingAlreadyCanceled,
             "OnlyReputationTokenContractCanCancel" => {
                 dao::utils::Error::OnlyReputationTokenContractCanCancel
@@ -130,6 +146,7 @@ impl FromStr for Error {
             "JobCannotBeYetCanceled" => dao::utils::Error::JobCannotBeYetCanceled,
             "FiatRateNotSet" => dao::utils::Error::FiatRateNotSet,
             "OnlyJobPosterCanModifyJobOffer" => dao::utils::Error::OnlyJobPosterCanModifyJobOffer,
+            "RationaleRequired" => dao::utils::Error::RationaleRequired,
             "CannotStakeTwice" => dao::utils::Error::CannotStakeTwice,
             "VotingStakeDoesntExists" => dao::utils::Error::VotingStakeDoesntExists,
             "BidStakeDoesntExists" => dao::utils::Error::BidStakeDoesntExists,
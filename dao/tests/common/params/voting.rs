@@ -1,7 +1,7 @@
 use std::{fmt::Debug, str::FromStr};
 
 use cucumber::Parameter;
-use dao::voting;
+use dao::{utils::types::DocumentHash, voting};
 
 use super::{Account, Contract, ReputationBalance};
 use crate::common::helpers;
@@ -81,11 +81,12 @@ impl From<VotingType> for voting::voting_engine::voting_state_machine::VotingTyp
 }
 
 #[derive(Debug, Default, Clone, Copy, Parameter, PartialEq, Eq)]
-#[param(name = "choice", regex = "in favor|against|yes|no|Yes|No")]
+#[param(name = "choice", regex = "in favor|against|abstain|yes|no|Yes|No")]
 pub enum Choice {
     InFavor,
     #[default]
     Against,
+    Abstain,
 }
 
 impl FromStr for Choice {
@@ -97,6 +98,7 @@ impl FromStr for Choice {
             "in favor" => Self::InFavor,
             "against" => Self::Against,
             "no" => Self::Against,
+            "abstain" => Self::Abstain,
             invalid => return Err(format!("Invalid `Choice`: {invalid}")),
         })
     }
@@ -107,10 +109,51 @@ impl From<Choice> for voting::ballot::Choice {
         match value {
             Choice::InFavor => voting::ballot::Choice::InFavor,
             Choice::Against => voting::ballot::Choice::Against,
+            Choice::Abstain => voting::ballot::Choice::Abstain,
         }
     }
 }
 
+#[derive(Clone, Copy, Debug, Parameter)]
+#[param(name = "tally_method", regex = "plurality|instant-runoff")]
+pub enum TallyMethod {
+    Plurality,
+    InstantRunoff,
+}
+
+impl FromStr for TallyMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "plurality" => Self::Plurality,
+            "instant-runoff" => Self::InstantRunoff,
+            invalid => return Err(format!("Invalid `TallyMethod`: {invalid}")),
+        })
+    }
+}
+
+impl From<TallyMethod> for voting::multi_option_engine::multi_option_voting::TallyMethod {
+    fn from(value: TallyMethod) -> Self {
+        match value {
+            TallyMethod::Plurality => {
+                voting::multi_option_engine::multi_option_voting::TallyMethod::Plurality
+            }
+            TallyMethod::InstantRunoff => {
+                voting::multi_option_engine::multi_option_voting::TallyMethod::InstantRunoff
+            }
+        }
+    }
+}
+
+/// Parses a comma separated list of option indexes, eg. `1,0,2`.
+pub fn parse_ranking(value: &str) -> Vec<u32> {
+    value
+        .split(',')
+        .map(|option| option.trim().parse().expect("Couldn't parse ranking"))
+        .collect()
+}
+
 #[derive(Clone, Debug)]
 pub struct Ballot {
     pub voter: Account,
@@ -118,6 +161,7 @@ pub struct Ballot {
     pub choice: Choice,
     pub voting_id: u32,
     pub voting_type: VotingType,
+    pub rationale: Option<DocumentHash>,
 }
 
 #[derive(Default)]
@@ -158,6 +202,7 @@ impl From<&Vec<String>> for Ballot {
             choice,
             voting_id: Default::default(),
             voting_type: Default::default(),
+            rationale: None,
         }
     }
 }
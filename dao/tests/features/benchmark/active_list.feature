@@ -0,0 +1,20 @@
+Feature: Active list benchmark
+    Creating and finishing a voting uses the same amount of gas no matter how many votings are active.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | VA1     | true  | 10000       |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+      And following configuration
+        | key                 | value |
+        | InformalQuorumRatio | 1000  |
+
+    Scenario: Hundreds of votings are created and finished at a flat cost
+      When VA1 creates 500 test votings in SimpleVoter
+      Then the last 100 calls used at most 1 percent more gas than the first ones
+      And page of 3 from 498 of SimpleVoter active votings is 498,499
+      When 5 days passed
+      And VA2 finishes informal votings with ids from 0 to 499 in SimpleVoter contract
+      Then the last 100 calls used at most 1 percent more gas than the first ones
+      And page of 10 from 0 of SimpleVoter active votings is none
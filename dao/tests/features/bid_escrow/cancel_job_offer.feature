@@ -22,10 +22,10 @@ Feature: Cancelling the Job Offer
       | account          | CSPR balance | REP balance  | REP stake  |
       | JobPoster        | 1000         | 0            | 0          |
 
-  Scenario: Somebody tries to cancel the Job after the auction phase
-    When 18 days passed
+  Scenario: Somebody tries to cancel the Job during auction phase
+    When 5 days passed
     And VA1 cancels the JobOffer with id 0
     Then JobOffer with id 0 isn't cancelled
     And balances are
       | account          | CSPR balance | REP balance  | REP stake  |
-      | JobPoster        | 600          | 0            | 0          |
\ No newline at end of file
+      | JobPoster        | 600          | 0            | 0          |
@@ -0,0 +1,52 @@
+Feature: Voting power delegation in Bid Escrow
+  A VA can vote over a job on behalf of his delegators.
+  The reputation minted for the voters is shared by the delegators pro rata to their stakes.
+  Neither the Job Poster nor the Worker can vote over their job through a delegate.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value         |
+      | TimeBetweenInformalAndFormalVoting     | 0             |
+      | VotingStartAfterJobSubmission          | 0             |
+    And VA2 delegates voting power to VA1 in BidEscrow contract with at most 500 REP
+    And InternalWorker delegates voting power to VA1 in BidEscrow contract with at most 500 REP
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+
+  Scenario: A delegate votes over a job and the delegator gets his share of the minted reputation
+    When VA1 votes yes with 500 REP as a delegate in BidEscrow informal voting with id 0
+      | delegator | REP stake |
+      | VA2       | 500       |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And VA1 votes yes with 500 REP as a delegate in BidEscrow formal voting with id 0
+      | delegator | REP stake |
+      | VA2       | 500       |
+    Then balances are
+      | account          | REP balance  | REP stake  |
+      | InternalWorker   | 1000         | 100        |
+      | VA1              | 1000         | 500        |
+      | VA2              | 1000         | 500        |
+    When 6 days passed
+    And formal voting with id 0 ends in BidEscrow contract
+    Then balances are
+      | account          | REP balance  | REP stake  |
+      | InternalWorker   | 1036.36      | 0          |
+      | VA1              | 1006.82      | 0          |
+      | VA2              | 1006.82      | 0          |
+    And total reputation is 3050
+
+  Scenario: The Worker cannot vote over his job through a delegate
+    Then VA1 can't vote yes with 500 REP as a delegate in BidEscrow informal voting with id 0 with CannotVoteOnOwnJob
+      | delegator        | REP stake |
+      | InternalWorker   | 100       |
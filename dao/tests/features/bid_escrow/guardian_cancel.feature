@@ -0,0 +1,63 @@
+Feature: Guardian cancels Bid Escrow and Onboarding votings
+  The guardian can cancel a voting over a job or an onboarding request.
+  The job is canceled and all the CSPR and reputation stakes are returned.
+
+  Scenario: The guardian cancels a voting over a job
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value          |
+      | GuardianAddress                        | MultisigWallet |
+      | TimeBetweenInformalAndFormalVoting     | 0              |
+      | VotingStartAfterJobSubmission          | 0              |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+     #| InternalWorker   | 100       | Yes    | - automatically voted by the system
+      | VA1              | 500       | Yes    |
+    Then VA1 can't cancel voting with id 0 in BidEscrow contract as the guardian with NotGuardian
+    When MultisigWallet cancels voting with id 0 in BidEscrow contract as the guardian because of malicious-proposal
+    Then BidEscrow voting with id 0 is canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | InternalWorker   | 0            | 1000         | 0          |
+      | VA1              | 0            | 1000         | 0          |
+
+  Scenario: The guardian cancels a voting over an onboarding request
+    Given following balances
+      | account          | CSPR balance | REP balance  | is_kyced | is_va |
+      | Onboarding       | 0            | 0            | false    | false |
+      | MultisigWallet   | 0            | 0            | false    | false |
+      | Bob              | 1000         | 0            | true     | false |
+      | VA1              | 0            | 1000         | true     | true  |
+      | VA2              | 0            | 1000         | true     | true  |
+    And following configuration
+      | key                                    | value          |
+      | GuardianAddress                        | MultisigWallet |
+      | TimeBetweenInformalAndFormalVoting     | 0              |
+      | VotingStartAfterJobSubmission          | 0              |
+    When Bob submits an onboarding request with the stake of 1000 CSPR
+    And voters vote in Onboarding informal voting with id 0
+      | user    | REP stake  | choice |
+     #| Bob     | 100        | yes    | - automatically voted by the system - 1000CSPR converted to 100 Reputation
+      | VA1     | 500        | yes    |
+    And MultisigWallet cancels voting with id 0 in Onboarding contract as the guardian because of malicious-proposal
+    Then Onboarding voting with id 0 is canceled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | Onboarding       | 0            | 0            | 0          |
+      | Bob              | 1000         | 0            | 0          |
+      | VA1              | 0            | 1000         | 0          |
+    And total reputation is 2000
+    And Bob is not a VA
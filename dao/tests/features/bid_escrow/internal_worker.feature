@@ -65,3 +65,4 @@ Feature: Internal Flow
       | VA2              | 74.77        | 506.82       | 0          |
       | BidEscrow        | 0            | 0            | 0          |
     And total reputation is 3050
+    And reputation dust in BidEscrow contract is 0
@@ -0,0 +1,75 @@
+Feature: Keeper cancels expired Jobs and Job Offers
+  Anyone can cancel a Job Offer whose auction has ended or a Job that has not been submitted in time.
+  The keeper who cancels it is rewarded with KeeperReputationReward, if is a VA,
+  and with KeeperCSPRReward paid from the keeper reward pool, if the pool is sufficient.
+
+  Background:
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | Alice            | 100          | 0            | 0          | false    | false |
+    And following configuration
+      | key                     | value        |
+      | KeeperReputationReward  | 50000000000  |
+      | KeeperCSPRReward        | 100000000000 |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+
+  Scenario: The Job Offer waits to be cancelled after the auction phase
+    Then JobOffer with id 0 isn't actionable
+    When 18 days passed
+    Then JobOffer with id 0 is actionable
+    When JobPoster cancels the JobOffer with id 0
+    Then JobOffer with id 0 isn't actionable
+    And JobOffer with id 0 is cancelled
+
+  Scenario: A keeper cancels an expired Job Offer
+    When 18 days passed
+    And VA1 cancels the JobOffer with id 0
+    Then JobOffer with id 0 is cancelled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | VA1              | 0            | 1050         | 0          |
+
+  Scenario: A keeper cancels an expired Job
+    When InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And 10 days passed
+    And VA1 cancels the Job with id 0
+    Then Job with id 0 isn't cancelled
+    When 5 days passed
+    And VA1 cancels the Job with id 0
+    Then Job with id 0 is cancelled
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | InternalWorker   | 0            | 810          | 0          |
+      | VA1              | 0            | 1050         | 0          |
+
+  Scenario: A keeper is paid from the keeper reward pool, never from the escrowed funds
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And 18 days passed
+    And VA1 cancels the JobOffer with id 0
+    Then JobOffer with id 0 is cancelled
+    And the keeper reward pool of BidEscrow contract is 0 CSPR
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 400          | 0            | 0          |
+      | JobPoster        | 600          | 0            | 0          |
+      | VA1              | 0            | 1050         | 0          |
+    When Alice funds the keeper reward pool of BidEscrow contract with 100 CSPR
+    Then the keeper reward pool of BidEscrow contract is 100 CSPR
+    When VA1 cancels the JobOffer with id 1
+    Then JobOffer with id 1 is cancelled
+    And the keeper reward pool of BidEscrow contract is 0 CSPR
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | BidEscrow        | 0            | 0            | 0          |
+      | JobPoster        | 1000         | 0            | 0          |
+      | Alice            | 0            | 0            | 0          |
+      | VA1              | 100          | 1100         | 0          |
@@ -0,0 +1,51 @@
+Feature: Keeper reward
+    The caller who finishes an overdue voting is rewarded with KeeperReputationReward, if is a VA.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+      And following configuration
+        | key                     | value        |
+        | KeeperReputationReward  | 50000000000  |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+
+    Scenario: A VA who finishes an overdue voting is rewarded
+      Then voting with id 0 in SimpleVoter contract isn't actionable
+      When 5 days passed
+      Then voting with id 0 in SimpleVoter contract is actionable
+      When VA3 finishes informal voting with id 0 in SimpleVoter contract
+      Then voting with id 0 in SimpleVoter contract isn't actionable
+      When 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 500        | yes     |
+      And 5 days passed
+      Then voting with id 0 in SimpleVoter contract is actionable
+      When VA2 finishes formal voting with id 0 in SimpleVoter contract
+      Then voting with id 0 in SimpleVoter contract isn't actionable
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 0         |
+        | VA2     | 1050         | 0         |
+        | VA3     | 1050         | 0         |
+
+    Scenario: A non-VA who finishes an overdue voting gets no reputation
+      When 5 days passed
+      And Alice finishes informal voting with id 0 in SimpleVoter contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | Alice   | 0            | 0         |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 0         |
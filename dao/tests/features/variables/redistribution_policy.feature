@@ -0,0 +1,57 @@
+Feature: Redistribution policy
+    RedistributionPolicy determines what happens to the reputation staked on the losing side of a formal voting.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+
+    Scenario Outline: Losing stake redistribution
+      Given following configuration
+        | key                            | value          |
+        | RedistributionPolicy           | <policy>       |
+        | RedistributionPartialLossRatio | 500            |
+        | TreasuryAddress                | MultisigWallet |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 500        | yes    |
+        | VA3     | 200        | yes    |
+        | VA4     | 250        | no     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 500        | yes    |
+        | VA3     | 200        | yes    |
+        | VA4     | 250        | no     |
+      And 5 days passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then users balances are
+        | account         | REP balance  | REP stake  |
+        | VA1             | <VA1>        | 0          |
+        | VA2             | <VA2>        | 0          |
+        | VA3             | <VA3>        | 0          |
+        | VA4             | <VA4>        | 0          |
+        | MultisigWallet  | <treasury>   | 0          |
+      Examples:
+        | policy           | VA1      | VA2      | VA3     | VA4  | treasury |
+        | ProRata          | 1031.25  | 1156.25  | 1062.5  | 750  | 0        |
+        | PartialLoss      | 1015.625 | 1078.125 | 1031.25 | 875  | 0        |
+        | Treasury         | 1000     | 1000     | 1000    | 750  | 250      |
+        | NoRedistribution | 1000     | 1000     | 1000    | 1000 | 0        |
+
+    Scenario: Treasury policy requires the TreasuryAddress
+      Given following configuration
+        | key                  | value    |
+        | RedistributionPolicy | Treasury |
+      Then VA1 can't start voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 | error                 |
+        | SimpleVoter      | 100   |      |      |      | TreasuryAddressNotSet |
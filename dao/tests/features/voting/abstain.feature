@@ -0,0 +1,94 @@
+Feature: Abstain
+    Abstain ballots count toward the quorum, but do not affect the result.
+    The reputation staked by an abstaining voter is always returned.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+
+    Scenario Outline: Abstain ballots help to reach the quorum and are returned
+      When Admin is the owner of all contracts
+      And VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | abstain |
+        | VA3     | 200        | yes     |
+      Then <voting_contract> informal voting with id 0 has 500 REP abstained by 1 voter
+      When 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract starts
+      When 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | abstain |
+        | VA3     | 200        | yes     |
+        | VA4     | 150        | no      |
+      Then <voting_contract> formal voting with id 0 has 500 REP abstained by 1 voter
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 500       |
+        | VA3     | 1000         | 200       |
+        | VA4     | 1000         | 150       |
+      When 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1050         | 0         |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1100         | 0         |
+        | VA4     | 850          | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: Abstain ballots do not decide the result
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | abstain |
+        | VA3     | 200        | abstain |
+        | VA4     | 150        | no      |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | abstain |
+        | VA3     | 200        | abstain |
+        | VA4     | 150        | no      |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 900          | 0         |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 0         |
+        | VA4     | 1100         | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
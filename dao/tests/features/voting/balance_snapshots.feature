@@ -0,0 +1,38 @@
+Feature: Reputation balance snapshots
+    Voters can stake only the reputation they owned when the voting was created.
+    Background:
+      Given users
+        | user    | is_va        | REP balance |
+        | Alice   | false        | 0           |
+        | VA1     | true         | 1000        |
+        | VA2     | true         | 1000        |
+        | VA3     | true         | 1000        |
+        | VA5     | true         | 1000        |
+
+    Scenario Outline: Reputation minted during the voting can't be staked
+      When VA1 starts voting with the following config
+        | voting_contract   | stake | arg1   | arg2   | arg3   |
+        | <voting_contract> | 100   | <arg1> | <arg2> | <arg3> |
+      And 1 hour passed
+      And Owner mints 500 REP to VA2
+      Then votes in <voting_contract> informal voting with id 0 fail
+        | user    | REP stake  | choice  | result                      |
+        | VA2     | 1500       | yes     | StakeExceedsSnapshotBalance |
+      When voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA2     | 1000       | yes     |
+        | VA3     | 200        | yes     |
+      Then users balances are
+        | account | REP balance  | REP stake  |
+        | VA1     | 1000         | 100        |
+        | VA2     | 1500         | 1000       |
+        | VA3     | 1000         | 200        |
+
+    Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
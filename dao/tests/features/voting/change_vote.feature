@@ -0,0 +1,78 @@
+Feature: Change or withdraw a ballot
+    While a voting phase is open, a voter can change his choice and stake or withdraw his ballot.
+    The ballot cast automatically for the creator cannot be changed.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+
+    Scenario Outline: Voters change and withdraw their ballots
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 200        | yes    |
+        | VA3     | 200        | yes    |
+        | VA4     | 100        | no     |
+      And VA2 changes vote in <voting_contract> informal voting with id 0 to no with 300 REP
+      And VA4 changes vote in <voting_contract> informal voting with id 0 to no with 50 REP
+      And VA3 withdraws vote in <voting_contract> informal voting with id 0
+      Then VA1 can't withdraw vote in <voting_contract> informal voting with id 0
+      And users balances are
+        | account | REP balance | REP stake |
+        | VA1     | 1000        | 100       |
+        | VA2     | 1000        | 300       |
+        | VA3     | 1000        | 0         |
+        | VA4     | 1000        | 50        |
+      When 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract starts
+      And users balances are
+        | account | REP balance | REP stake |
+        | VA1     | 1000        | 100       |
+        | VA2     | 1000        | 0         |
+        | VA3     | 1000        | 0         |
+        | VA4     | 1000        | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: Withdrawn ballots do not count toward the quorum
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 200        | yes    |
+        | VA3     | 200        | yes    |
+      And VA3 withdraws vote in <voting_contract> informal voting with id 0
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract does not start
+      And users balances are
+        | account | REP balance | REP stake |
+        | VA1     | 1000        | 0         |
+        | VA2     | 1000        | 0         |
+        | VA3     | 1000        | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
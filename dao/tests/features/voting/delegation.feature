@@ -0,0 +1,141 @@
+Feature: Voting power delegation
+    A VA can delegate his voting power to another VA - globally or in a single voting contract.
+    The delegator caps the reputation the delegate can stake on his behalf, and can disable
+    the global delegation in a single voting contract.
+    The delegate stakes the delegators' reputation, which is redistributed back to them pro rata.
+    A delegator can override the delegate's vote by voting himself.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+
+    Scenario Outline: Delegated reputation is redistributed to the delegators
+      Given VA2 delegates voting power to VA4 globally with at most 200 REP
+      And VA3 delegates voting power to VA4 in <voting_contract> contract with at most 200 REP
+      When Admin is the owner of all contracts
+      And VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And VA4 votes yes with 100 REP as a delegate in <voting_contract> informal voting with id 0
+        | delegator | REP stake |
+        | VA2       | 200       |
+        | VA3       | 200       |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA5     | 150        | no     |
+      Then users balances are
+        | account | REP balance | REP stake |
+        | VA1     | 1000        | 100       |
+        | VA2     | 1000        | 200       |
+        | VA3     | 1000        | 200       |
+        | VA4     | 1000        | 100       |
+        | VA5     | 1000        | 150       |
+      When 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And VA4 votes yes with 100 REP as a delegate in <voting_contract> formal voting with id 0
+        | delegator | REP stake |
+        | VA2       | 200       |
+        | VA3       | 200       |
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA3     | 200        | no     |
+        | VA5     | 150        | no     |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance | REP stake |
+        | VA1     | 1087.5      | 0         |
+        | VA2     | 1175        | 0         |
+        | VA3     | 800         | 0         |
+        | VA4     | 1087.5      | 0         |
+        | VA5     | 850         | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA6                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: A delegate's ballot left with no stake does not count toward the quorum
+      Given VA2 delegates voting power to VA4 globally with at most 200 REP
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And VA4 votes yes with 0 REP as a delegate in <voting_contract> informal voting with id 0
+        | delegator | REP stake |
+        | VA2       | 200       |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 200        | yes    |
+      Then <voting_contract> informal voting with id 0 has 3 voters
+      When 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract does not start
+      And users balances are
+        | account | REP balance | REP stake |
+        | VA1     | 1000        | 0         |
+        | VA2     | 1000        | 0         |
+        | VA4     | 1000        | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA6                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario: Only VAs can vote through delegation, within the delegator's cap
+      Given VA2 delegates voting power to VA4 globally with at most 200 REP
+      And Alice delegates voting power to VA4 globally with at most 200 REP
+      And VA3 delegates voting power to Alice globally with at most 200 REP
+      When VA1 starts voting with the following config
+        | voting_contract | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter     | 100   |      |      |      |
+      Then VA4 can't vote yes with 100 REP as a delegate in SimpleVoter informal voting with id 0 with DelegatedStakeExceedsLimit
+        | delegator | REP stake |
+        | VA2       | 300       |
+      And VA4 can't vote yes with 100 REP as a delegate in SimpleVoter informal voting with id 0 with NotOnboarded
+        | delegator | REP stake |
+        | Alice     | 0         |
+      And Alice can't vote yes with 0 REP as a delegate in SimpleVoter informal voting with id 0 with NotOnboarded
+        | delegator | REP stake |
+        | VA3       | 200       |
+      When VA4 votes yes with 100 REP as a delegate in SimpleVoter informal voting with id 0
+        | delegator | REP stake |
+        | VA2       | 200       |
+      Then users balances are
+        | account | REP balance | REP stake |
+        | VA2     | 1000        | 200       |
+        | VA3     | 1000        | 0         |
+        | VA4     | 1000        | 100       |
+
+    Scenario: A delegator disables the global delegation in a single voting contract
+      Given VA2 delegates voting power to VA4 globally with at most 500 REP
+      And VA2 disables delegation in SimpleVoter contract
+      When VA1 starts voting with the following config
+        | voting_contract | stake | arg1            | arg2             | arg3  |
+        | SimpleVoter     | 100   |                 |                  |       |
+        | Admin           | 100   | ReputationToken | add_to_whitelist | Alice |
+      Then VA4 can't vote yes with 100 REP as a delegate in SimpleVoter informal voting with id 0 with NotADelegate
+        | delegator | REP stake |
+        | VA2       | 200       |
+      When VA4 votes yes with 100 REP as a delegate in Admin informal voting with id 1
+        | delegator | REP stake |
+        | VA2       | 200       |
+      Then users balances are
+        | account | REP balance | REP stake |
+        | VA2     | 1000        | 200       |
+        | VA4     | 1000        | 100       |
@@ -0,0 +1,148 @@
+Feature: Early finish
+    The formal voting can be finished before the end of the formal phase if the quorum is reached
+    and neither the reputation not staked yet nor the ballots that can still change can flip the result.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+
+    Scenario: The voting ends early if the result is locked in
+      Given following configuration
+        | key                  | value |
+        | InformalQuorumRatio  | 250   |
+        | FormalQuorumRatio    | 250   |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 1000  |      |      |      |
+      And VA2 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 1000  |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 1
+        | user    | REP stake  | choice  |
+       #| VA2     | 1000       | yes     | - automatically voted by the system
+        | VA3     | 1000       | yes     |
+        | VA4     | 1000       | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then voting with id 0 in SimpleVoter contract ended early
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 0         |
+        | VA2     | 1000         | 1000      |
+        | VA3     | 1000         | 1000      |
+        | VA4     | 1000         | 1000      |
+
+    Scenario: The voting cannot end early if a ballot on the winning side can still change
+      Given following configuration
+        | key                  | value |
+        | InformalQuorumRatio  | 250   |
+        | FormalQuorumRatio    | 250   |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 500   |      |      |      |
+      And VA2 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 400   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 1
+        | user    | REP stake  | choice  |
+       #| VA2     | 400        | yes     | - automatically voted by the system
+        | VA1     | 500        | yes     |
+        | VA3     | 1000       | yes     |
+        | VA4     | 1000       | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 500        | yes     | - automatically voted by the system
+        | VA2     | 600        | yes     |
+      Then formal voting with id 0 in SimpleVoter contract can't end yet
+      When VA2 changes vote in SimpleVoter formal voting with id 0 to no with 600 REP
+      And 5 days passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then voting with id 0 in SimpleVoter contract didn't end early
+
+    Scenario: The voting cannot end early if the remaining reputation can flip the result
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      When voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+        | VA3     | 400        | yes     |
+      Then formal voting with id 0 in SimpleVoter contract can't end yet
+      When voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA4     | 1000       | no      |
+      Then formal voting with id 0 in SimpleVoter contract can't end yet
+      When 5 days passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then voting with id 0 in SimpleVoter contract didn't end early
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 900          | 0         |
+        | VA2     | 600          | 0         |
+        | VA3     | 600          | 0         |
+        | VA4     | 1900         | 0         |
+
+    Scenario: The voting cannot end early without the quorum
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      Then formal voting with id 0 in SimpleVoter contract can't end yet
+
+    Scenario: The voting cannot end early if the quorum depends on ballots that can still be withdrawn
+      Given following configuration
+        | key                  | value |
+        | InformalQuorumRatio  | 250   |
+        | FormalQuorumRatio    | 500   |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 1000  |      |      |      |
+      And VA2 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 500   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 1
+        | user    | REP stake  | choice  |
+       #| VA2     | 500        | yes     | - automatically voted by the system
+        | VA3     | 1000       | yes     |
+        | VA4     | 1000       | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 1000       | yes     | - automatically voted by the system
+        | VA2     | 500        | abstain |
+      Then formal voting with id 0 in SimpleVoter contract can't end yet
+      When VA2 withdraws vote in SimpleVoter formal voting with id 0
+      And 5 days passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then voting with id 0 in SimpleVoter contract didn't end early
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 0         |
+        | VA2     | 1000         | 500       |
+        | VA3     | 1000         | 1000      |
+        | VA4     | 1000         | 1000      |
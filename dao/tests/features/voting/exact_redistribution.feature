@@ -0,0 +1,49 @@
+Feature: Exact redistribution
+    The losing stake is split among the winners using the largest remainder method,
+    so the reputation minted is equal to the reputation burned.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+
+    Scenario Outline: The losing stake can't be split evenly
+      When Admin is the owner of all contracts
+      And VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 100        | yes    |
+        | VA3     | 100        | yes    |
+        | VA4     | 100        | no     |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice |
+       #| VA1     | 100        | yes    | - automatically voted by the system
+        | VA2     | 100        | yes    |
+        | VA3     | 100        | yes    |
+        | VA4     | 100        | no     |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  |
+        | VA1     | 1033.333     |
+        | VA2     | 1033.333     |
+        | VA3     | 1033.333     |
+        | VA4     | 900          |
+      And total reputation is exactly <total>
+      And reputation dust in <voting_contract> contract is 0
+      Examples:
+        | voting_contract  | arg1               | arg2             | arg3  | total |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice | 5000  |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     | 5000  |
+        | SimpleVoter      |                    |                  |       | 5000  |
+        | ReputationVoter  | Alice              | mint             | 100   | 5100  |
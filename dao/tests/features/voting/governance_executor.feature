@@ -0,0 +1,91 @@
+Feature: Governance Executor performs arbitrary contract calls
+  If voting passes, the proposed calls are performed. Only the calls on the allowlist can be proposed and performed.
+  The allowlist is managed by Governance Executor votings.
+
+Background:
+  Given users
+    | user    | is_va | REP balance |
+    | Alice   | false | 0           |
+    | VA1     | true  | 1000        |
+    | VA2     | true  | 1000        |
+    | VA3     | true  | 1000        |
+  And following configuration
+    | key            | value |
+    | PostJobDOSFee  | 10    |
+
+Scenario: Allowed calls are performed once the voting passes
+  When GovernanceExecutor voting with id 0 created by VA1 passes
+    | voting_contract    | stake | arg1       | arg2               | arg3      |
+    | GovernanceExecutor | 100   | allow_call | VariableRepository | update_at |
+  And GovernanceExecutor voting with id 1 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2  |
+    | GovernanceExecutor | 100   | PostJobDOSFee | 12345 |
+  Then GovernanceExecutor voting with id 1 calls VariableRepository update_at
+  And value of PostJobDOSFee is 12345
+  When GovernanceExecutor voting with id 2 created by VA1 fails
+    | voting_contract    | stake | arg1          | arg2  |
+    | GovernanceExecutor | 100   | PostJobDOSFee | 10    |
+  Then value of PostJobDOSFee is 12345
+
+Scenario: Calls out of the allowlist can't be proposed
+  Then VA1 can't start voting with the following config
+    | voting_contract    | stake | arg1          | arg2  | error          |
+    | GovernanceExecutor | 100   | PostJobDOSFee | 12345 | CallNotAllowed |
+  When GovernanceExecutor voting with id 0 created by VA1 passes
+    | voting_contract    | stake | arg1       | arg2               | arg3      |
+    | GovernanceExecutor | 100   | allow_call | VariableRepository | update_at |
+  And GovernanceExecutor voting with id 1 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2               | arg3      |
+    | GovernanceExecutor | 100   | disallow_call | VariableRepository | update_at |
+  Then VA1 can't start voting with the following config
+    | voting_contract    | stake | arg1          | arg2  | error          |
+    | GovernanceExecutor | 100   | PostJobDOSFee | 12345 | CallNotAllowed |
+
+Scenario: Calls removed from the allowlist during the voting are not performed
+  When GovernanceExecutor voting with id 0 created by VA1 passes
+    | voting_contract    | stake | arg1       | arg2               | arg3      |
+    | GovernanceExecutor | 100   | allow_call | VariableRepository | update_at |
+  And VA1 starts voting with the following config
+    | voting_contract    | stake | arg1          | arg2  |
+    | GovernanceExecutor | 100   | PostJobDOSFee | 12345 |
+  And voters vote in GovernanceExecutor informal voting with id 1
+    | user    | REP stake  | choice  |
+   #| VA1     | 100        | yes     | - automatically voted by the system
+    | VA2     | 500        | yes     |
+    | VA3     | 500        | yes     |
+  And 5 days passed
+  And informal voting with id 1 ends in GovernanceExecutor contract
+  And 2 days passed
+  And voters vote in GovernanceExecutor formal voting with id 1
+    | user    | REP stake  | choice  |
+   #| VA1     | 100        | yes     | - automatically voted by the system
+    | VA2     | 500        | yes     |
+    | VA3     | 500        | yes     |
+  And GovernanceExecutor voting with id 2 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2               | arg3      |
+    | GovernanceExecutor | 100   | disallow_call | VariableRepository | update_at |
+  And formal voting with id 1 ends in GovernanceExecutor contract
+  Then value of PostJobDOSFee is 10
+
+Scenario: Queued calls removed from the allowlist can't be executed
+  When GovernanceExecutor voting with id 0 created by VA1 passes
+    | voting_contract    | stake | arg1       | arg2               | arg3      |
+    | GovernanceExecutor | 100   | allow_call | VariableRepository | update_at |
+  Given following configuration
+    | key            | value     |
+    | TimelockDelay  | 172800000 |
+  When GovernanceExecutor voting with id 1 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2  |
+    | GovernanceExecutor | 100   | PostJobDOSFee | 12345 |
+  Given following configuration
+    | key            | value |
+    | TimelockDelay  | 0     |
+  When GovernanceExecutor voting with id 2 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2               | arg3      |
+    | GovernanceExecutor | 100   | disallow_call | VariableRepository | update_at |
+  Then Alice can't execute the action of voting with id 1 in GovernanceExecutor contract with CallNotAllowed
+  And value of PostJobDOSFee is 10
+
+Scenario: Only a Governance Executor voting can manage the allowlist
+  Then Alice can't allow VariableRepository update_at calls in GovernanceExecutor
+  And Owner can't allow VariableRepository update_at calls in GovernanceExecutor
@@ -0,0 +1,69 @@
+Feature: Guardian
+  The guardian, a multisig wallet set as GuardianAddress in the Variable Repository, can cancel any active voting
+  in an emergency. The stakes are returned to the voters. The guardian can cancel one voting per GuardianCancelInterval
+  across all the voting contracts.
+
+Background:
+  Given users
+    | user           | is_va | REP balance |
+    | Alice          | false | 0           |
+    | Bob            | false | 0           |
+    | MultisigWallet | false | 0           |
+    | VA1            | true  | 1000        |
+    | VA2            | true  | 1000        |
+    | VA3            | true  | 1000        |
+  And following configuration
+    | key                    | value          |
+    | GuardianAddress        | MultisigWallet |
+    | GuardianCancelInterval | 604800000      |
+  When VA1 starts voting with the following config
+    | voting_contract | stake | arg1            | arg2             | arg3  |
+    | Admin           | 100   | ReputationToken | add_to_whitelist | Alice |
+  And voters vote in Admin informal voting with id 0
+    | user    | REP stake  | choice  |
+   #| VA1     | 100        | yes     | - automatically voted by the system
+    | VA2     | 200        | yes     |
+
+Scenario: The guardian cancels a voting
+  Then VA1 can't cancel voting with id 0 in Admin contract as the guardian with NotGuardian
+  When MultisigWallet cancels voting with id 0 in Admin contract as the guardian because of malicious-proposal
+  Then Admin voting with id 0 is canceled
+  And users balances are
+    | account | REP balance | REP stake |
+    | VA1     | 1000        | 0         |
+    | VA2     | 1000        | 0         |
+    | VA3     | 1000        | 0         |
+
+Scenario: The guardian acts at most once per GuardianCancelInterval
+  When VA2 starts voting with the following config
+    | voting_contract | stake | arg1            | arg2             | arg3  |
+    | Admin           | 100   | ReputationToken | add_to_whitelist | Bob   |
+  And MultisigWallet cancels voting with id 0 in Admin contract as the guardian because of malicious-proposal
+  Then MultisigWallet can't cancel voting with id 1 in Admin contract as the guardian with GuardianCancelTooEarly
+  When 7 days passed
+  Then MultisigWallet can't cancel voting with id 0 in Admin contract as the guardian with VotingAlreadyCanceled
+  When MultisigWallet cancels voting with id 1 in Admin contract as the guardian because of malicious-proposal
+  Then Admin voting with id 1 is canceled
+
+Scenario: Governance rotates the guardian
+  When RepoVoter voting with id 1 created by VA1 passes
+    | voting_contract | stake | arg1               | arg2            | arg3 |
+    | RepoVoter       | 100   | VariableRepository | GuardianAddress | Bob  |
+  Then MultisigWallet can't cancel voting with id 0 in Admin contract as the guardian with NotGuardian
+  When Bob cancels voting with id 0 in Admin contract as the guardian because of malicious-proposal
+  Then Admin voting with id 0 is canceled
+
+Scenario: The GuardianCancelInterval is shared by all the voting contracts
+  When VA2 starts plurality multi-option voting with 100 REP ranking 0
+    | document |
+    | Budget A |
+    | Budget B |
+  And MultisigWallet cancels voting with id 0 in Admin contract as the guardian because of malicious-proposal
+  Then MultisigWallet can't cancel voting with id 1 in MultiOptionVoter contract as the guardian with GuardianCancelTooEarly
+  When 7 days passed
+  And MultisigWallet cancels voting with id 1 in MultiOptionVoter contract as the guardian because of malicious-proposal
+  Then MultiOptionVoter voting with id 1 is canceled
+  And users balances are
+    | account | REP balance | REP stake |
+    | VA1     | 1000        | 0         |
+    | VA2     | 1000        | 0         |
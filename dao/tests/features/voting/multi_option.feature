@@ -0,0 +1,174 @@
+Feature: Multi-option voting
+    Voters rank the options of a multi-option voting. The winning option's contract calls are performed
+    and the reputation of voters whose final choice lost is redistributed to the winning voters.
+    Only the calls on the Governance Executor allowlist can be proposed.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+      And following configuration
+        | key                       | value              |
+        | GovernanceExecutorAddress | GovernanceExecutor |
+      When GovernanceExecutor voting with id 0 created by VA1 passes
+        | voting_contract    | stake | arg1       | arg2               | arg3      |
+        | GovernanceExecutor | 100   | allow_call | VariableRepository | update_at |
+
+    Scenario: The option with the most first preferences wins a plurality voting
+      When VA1 starts plurality multi-option voting with 100 REP ranking 0
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+        | Budget C | PostJobDOSFee | 30    |
+      And voters rank options in informal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+       #| VA1     | 100        | 0       | - automatically voted by the system
+        | VA2     | 400        | 1       |
+        | VA3     | 300        | 2       |
+        | VA4     | 200        | 2       |
+      And 5 days passed
+      And informal voting with id 1 ends in MultiOptionVoter contract
+      And 2 days passed
+      And voters rank options in formal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+       #| VA1     | 100        | 0       | - automatically voted by the system
+        | VA2     | 400        | 1       |
+        | VA3     | 300        | 2       |
+        | VA4     | 200        | 2       |
+      And 5 days passed
+      And formal voting with id 1 ends in MultiOptionVoter contract
+      Then option 2 wins multi-option voting with id 1
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 900          | 0         |
+        | VA2     | 600          | 0         |
+        | VA3     | 1300         | 0         |
+        | VA4     | 1200         | 0         |
+      And value of PostJobDOSFee is 30
+
+    Scenario: Eliminated options transfer their ballots to the next preference in an instant-runoff voting
+      When VA1 starts instant-runoff multi-option voting with 100 REP ranking 0,1
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+        | Budget C | PostJobDOSFee | 30    |
+      And voters rank options in informal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+       #| VA1     | 100        | 0,1     | - automatically voted by the system
+        | VA2     | 400        | 1       |
+        | VA3     | 500        | 2       |
+        | VA4     | 200        | 0,1     |
+      And 5 days passed
+      And informal voting with id 1 ends in MultiOptionVoter contract
+      And 2 days passed
+      And voters rank options in formal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+       #| VA1     | 100        | 0,1     | - automatically voted by the system
+        | VA2     | 400        | 1       |
+        | VA3     | 500        | 2       |
+        | VA4     | 200        | 0,1     |
+      And 5 days passed
+      And formal voting with id 1 ends in MultiOptionVoter contract
+      Then option 1 wins multi-option voting with id 1
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1071         | 0         |
+        | VA2     | 1285         | 0         |
+        | VA3     | 500          | 0         |
+        | VA4     | 1142         | 0         |
+      And value of PostJobDOSFee is 20
+
+    Scenario: Invalid rankings are rejected
+      When VA1 starts plurality multi-option voting with 100 REP ranking 0
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+        | Budget C | PostJobDOSFee | 30    |
+      Then VA2 can't rank options 0,0 with 100 REP in informal multi-option voting with id 1
+      And VA2 can't rank options 3 with 100 REP in informal multi-option voting with id 1
+
+    Scenario: The winning option's contract calls are queued in the timelock
+      Given following configuration
+        | key            | value     |
+        | TimelockDelay  | 172800000 |
+      When VA1 starts plurality multi-option voting with 100 REP ranking 0
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+      And voters rank options in informal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+       #| VA1     | 100        | 0       | - automatically voted by the system
+        | VA2     | 400        | 1       |
+        | VA3     | 300        | 1       |
+        | VA4     | 200        | 0       |
+      And 5 days passed
+      And informal voting with id 1 ends in MultiOptionVoter contract
+      And 2 days passed
+      And voters rank options in formal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+       #| VA1     | 100        | 0       | - automatically voted by the system
+        | VA2     | 400        | 1       |
+        | VA3     | 300        | 1       |
+        | VA4     | 200        | 0       |
+      And 5 days passed
+      And formal voting with id 1 ends in MultiOptionVoter contract
+      Then option 1 wins multi-option voting with id 1
+      And Alice can't execute the action of voting with id 1 in MultiOptionVoter contract with TimelockNotExpired
+      When 2 days passed
+      And Alice executes the action of voting with id 1 in MultiOptionVoter contract
+      Then value of PostJobDOSFee is 20
+
+    Scenario: Options with calls out of the allowlist can't be proposed
+      When GovernanceExecutor voting with id 1 created by VA1 passes
+        | voting_contract    | stake | arg1          | arg2               | arg3      |
+        | GovernanceExecutor | 100   | disallow_call | VariableRepository | update_at |
+      Then VA1 can't start plurality multi-option voting with 100 REP ranking 0 with CallNotAllowed
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+
+    Scenario: The votings and the ballots of a slashed voter are canceled
+      When Owner adds Alice to whitelist in MultiOptionVoter contract
+      And VA1 starts plurality multi-option voting with 100 REP ranking 0
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+      And VA2 starts plurality multi-option voting with 100 REP ranking 0
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+      And voters rank options in informal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+        | VA2     | 400        | 1       |
+        | VA3     | 300        | 1       |
+      And Alice calls MultiOptionVoter to slash VA2
+      Then MultiOptionVoter voting with id 2 is canceled
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 300       |
+
+    Scenario: Voters change and withdraw their rankings, the creator's ranking is immutable
+      When VA1 starts plurality multi-option voting with 100 REP ranking 0
+        | document | key           | value |
+        | Budget A | PostJobDOSFee | 10    |
+        | Budget B | PostJobDOSFee | 20    |
+        | Budget C | PostJobDOSFee | 30    |
+      And voters rank options in informal multi-option voting with id 1
+        | user    | REP stake  | ranking |
+        | VA2     | 400        | 1       |
+        | VA3     | 300        | 2       |
+      And VA2 changes ranking to 2,1 with 200 REP in informal multi-option voting with id 1
+      And VA3 withdraws ranking in informal multi-option voting with id 1
+      Then VA1 can't withdraw ranking in informal multi-option voting with id 1
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 200       |
+        | VA3     | 1000         | 0         |
@@ -0,0 +1,127 @@
+Feature: Voter participation
+    VAs who voted in enough Formal Votings during an epoch are rewarded with ParticipationReward.
+    VAs who voted in none lose NonParticipationDecay of their unstaked reputation.
+    Each epoch starts where the previous one ended, and the reputation holders can be settled in batches.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 1000        |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+      And following configuration
+        | key                           | value        |
+        | ParticipationEpochLength      | 1728000000   |
+        | ParticipationMinFormalVotings | 1            |
+        | ParticipationReward           | 50000000000  |
+        | NonParticipationDecay         | 100          |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 100        | yes     |
+        | VA3     | 100        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 100        | yes     |
+      And 5 days passed
+      And formal voting with id 0 ends in SimpleVoter contract
+
+    Scenario: Formal voters are rewarded and non-voters decay
+      Then Any can't settle the participation epoch
+      When 8 days passed
+      And Any settles the participation epoch
+      Then 1 participation epochs are settled
+      And users balances are
+        | account | REP balance  |
+        | Alice   | 1000         |
+        | VA1     | 1050         |
+        | VA2     | 1050         |
+        | VA3     | 900          |
+        | VA4     | 900          |
+      And Any can't settle the participation epoch
+
+    Scenario: Epochs are settled one by one
+      When 28 days passed
+      And Any settles the participation epoch
+      And Any settles the participation epoch
+      Then 2 participation epochs are settled
+      And users balances are
+        | account | REP balance  |
+        | Alice   | 1000         |
+        | VA1     | 945          |
+        | VA2     | 945          |
+        | VA3     | 810          |
+        | VA4     | 810          |
+      And Any can't settle the participation epoch
+
+    Scenario: Reputation holders are settled in batches
+      When 8 days passed
+      And Any settles 3 reputation holders of the participation epoch
+      Then 0 participation epochs are settled
+      When Any settles 3 reputation holders of the participation epoch
+      Then 1 participation epochs are settled
+      And users balances are
+        | account | REP balance  |
+        | Alice   | 1000         |
+        | VA1     | 1050         |
+        | VA2     | 1050         |
+        | VA3     | 900          |
+        | VA4     | 900          |
+      And Any can't settle the participation epoch
+
+    Scenario: A new epoch length applies from the end of the last settled epoch
+      When 8 days passed
+      And Any settles the participation epoch
+      Given following configuration
+        | key                           | value        |
+        | ParticipationEpochLength      | 864000000    |
+      Then Any can't settle the participation epoch
+      When 10 days passed
+      And Any settles the participation epoch
+      Then 2 participation epochs are settled
+      And users balances are
+        | account | REP balance  |
+        | Alice   | 1000         |
+        | VA1     | 945          |
+        | VA2     | 945          |
+        | VA3     | 810          |
+        | VA4     | 810          |
+      And Any can't settle the participation epoch
+
+    Scenario: Delegators participate through their delegate's ballot
+      Given VA4 delegates voting power to VA2 globally with at most 100 REP
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 1
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 100        | yes     |
+        | VA3     | 100        | yes     |
+      And 5 days passed
+      And informal voting with id 1 ends in SimpleVoter contract
+      And 2 days passed
+      And VA2 votes yes with 100 REP as a delegate in SimpleVoter formal voting with id 1
+        | delegator | REP stake |
+        | VA4       | 100       |
+      And 5 days passed
+      And formal voting with id 1 ends in SimpleVoter contract
+      And 16 days passed
+      And Any settles the participation epoch
+      And Any settles the participation epoch
+      Then 2 participation epochs are settled
+      And users balances are
+        | account | REP balance  |
+        | Alice   | 1000         |
+        | VA1     | 1100         |
+        | VA2     | 1100         |
+        | VA3     | 810          |
+        | VA4     | 950          |
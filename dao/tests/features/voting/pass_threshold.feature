@@ -0,0 +1,84 @@
+Feature: Pass threshold
+    Each kind of voting passes if the stake in favor reaches the configured share
+    of the stake in favor and against.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+
+    Scenario Outline: Voting with a simple majority passes with the default threshold
+      When Admin is the owner of all contracts
+      And VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 300        | no      |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 300        | no      |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1050         | 0         |
+        | VA2     | 1250         | 0         |
+        | VA3     | 700          | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: Voting with a simple majority fails if a supermajority is required
+      Given following configuration
+        | key         | value |
+        | <threshold> | 750   |
+      When Admin is the owner of all contracts
+      And VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 300        | no      |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 300        | no      |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 900          | 0         |
+        | VA2     | 500          | 0         |
+        | VA3     | 1600         | 0         |
+     Examples:
+        | voting_contract  | threshold               | arg1               | arg2             | arg3  |
+        | KycVoter         | KycPassThreshold        | Alice              |                  |       |
+        | Admin            | AdminPassThreshold      | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | SlashingPassThreshold   | VA5                | 1                |       |
+        | RepoVoter        | RepoPassThreshold       | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      | SimplePassThreshold     |                    |                  |       |
+        | ReputationVoter  | ReputationPassThreshold | Alice              | mint             | 100   |
@@ -0,0 +1,80 @@
+Feature: Quadratic voting
+    If quadratic voting is enabled, the vote power of a ballot is the square root of the stake.
+    The reputation is redistributed by the raw stake.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+
+    Scenario Outline: Many voters with a low stake outvote a single large holder
+      Given following configuration
+        | key         | value |
+        | <quadratic> | true  |
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+        | VA3     | 900        | no      |
+        | VA4     | 100        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+        | VA3     | 900        | no      |
+        | VA4     | 100        | yes     |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1150         | 0         |
+        | VA2     | 1600         | 0         |
+        | VA3     | 100          | 0         |
+        | VA4     | 1150         | 0         |
+     Examples:
+        | voting_contract  | quadratic             | arg1               | arg2          | arg3 |
+        | SimpleVoter      | SimpleQuadraticVoting |                    |               |      |
+        | RepoVoter        | RepoQuadraticVoting   | VariableRepository | PostJobDOSFee | 1    |
+
+    Scenario Outline: A single large holder outvotes many voters with a low stake by default
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+        | VA3     | 900        | no      |
+        | VA4     | 100        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And voters vote in <voting_contract> formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+        | VA3     | 900        | no      |
+        | VA4     | 100        | yes     |
+      And 5 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 900          | 0         |
+        | VA2     | 600          | 0         |
+        | VA3     | 1600         | 0         |
+        | VA4     | 900          | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2          | arg3 |
+        | SimpleVoter      |                    |               |      |
+        | RepoVoter        | VariableRepository | PostJobDOSFee | 1    |
@@ -0,0 +1,46 @@
+Feature: Governance queries
+    Votings and ballots can be listed page by page.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+      And following configuration
+        | key                 | value |
+        | InformalQuorumRatio | 1000  |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And VA2 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 100        | yes     |
+        | VA3     | 100        | no      |
+
+    Scenario: Votings are listed by state and by creator
+      Then page of 10 from 0 of SimpleVoter active votings is 0,1,2
+      And page of 1 from 1 of SimpleVoter active votings is 1
+      And page of 10 from 3 of SimpleVoter active votings is none
+      And page of 10 from 0 of SimpleVoter votings created by VA1 is 0,2
+      And page of 1 from 1 of SimpleVoter votings created by VA1 is 2
+      And page of 10 from 0 of SimpleVoter votings created by VA3 is none
+      When 5 days passed
+      And informal voting with id 1 ends in SimpleVoter contract
+      Then page of 10 from 0 of SimpleVoter active votings is 0,2
+
+    Scenario: Ballots are listed by voter and by voting
+      Then page of 10 from 0 of SimpleVoter ballots of VA2 is 1,0
+      And page of 10 from 0 of SimpleVoter ballots of VA3 is 0
+      And page of 10 from 0 of SimpleVoter ballots of Alice is none
+      And SimpleVoter informal voting with id 0 has 3 voters
+      And page of 10 from 0 of ballots in SimpleVoter informal voting with id 0 is cast by VA1,VA2,VA3
+      And page of 2 from 1 of ballots in SimpleVoter informal voting with id 0 is cast by VA2,VA3
+      And page of 2 from 3 of ballots in SimpleVoter informal voting with id 0 is cast by nobody
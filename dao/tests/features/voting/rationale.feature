@@ -0,0 +1,153 @@
+Feature: Ballot rationale
+  A voter can attach a document explaining their choice. It is stored on the ballot.
+  Bid Escrow can require a rationale for a vote against.
+
+  Scenario: A rationale is stored on the ballot
+    Given users
+      | user    | is_va | REP balance |
+      | VA1     | true  | 1000        |
+      | VA2     | true  | 1000        |
+      | VA3     | true  | 1000        |
+    When VA1 starts voting with the following config
+      | voting_contract | stake | arg1 | arg2 | arg3 |
+      | SimpleVoter     | 100   |      |      |      |
+    And voters vote in SimpleVoter informal voting with id 0
+      | user    | REP stake  | choice  | rationale   |
+     #| VA1     | 100        | yes     |             | - automatically voted by the system
+      | VA2     | 100        | no      | QmRationale |
+      | VA3     | 100        | yes     |             |
+    Then VA2 ballot in SimpleVoter informal voting with id 0 has rationale QmRationale
+    And VA3 ballot in SimpleVoter informal voting with id 0 has no rationale
+    And VA1 ballot in SimpleVoter informal voting with id 0 has no rationale
+
+  Scenario: Bid Escrow requires a rationale for a vote against
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value  |
+      | BidEscrowRationaleRequired             | true   |
+      | TimeBetweenInformalAndFormalVoting     | 0      |
+      | VotingStartAfterJobSubmission          | 0      |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    Then votes in BidEscrow informal voting with id 0 fail
+      | account          | REP stake | choice | result            |
+      | VA1              | 500       | No     | RationaleRequired |
+    When voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice | rationale   |
+     #| InternalWorker   | 100       | Yes    |             | - automatically voted by the system
+      | VA1              | 500       | No     | QmRationale |
+      | VA2              | 500       | Yes    |             |
+    Then VA1 ballot in BidEscrow informal voting with id 0 has rationale QmRationale
+    And VA2 ballot in BidEscrow informal voting with id 0 has no rationale
+
+  Scenario: Bid Escrow requires a rationale for a revealed vote against
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value     |
+      | BidEscrowRationaleRequired             | true      |
+      | SecretFormalVoting                     | true      |
+      | FormalVotingRevealTime                 | 172800000 |
+      | TimeBetweenInformalAndFormalVoting     | 0         |
+      | VotingStartAfterJobSubmission          | 0         |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+     #| InternalWorker   | 100       | Yes    | - automatically voted by the system
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    And 6 days passed
+    And informal voting with id 0 ends in BidEscrow contract
+    And VA1 commits secret no vote with 500 REP and salt s1 in BidEscrow voting with id 0
+    And VA2 commits secret yes vote with 500 REP and salt s2 in BidEscrow voting with id 0
+    And 5 days passed
+    Then VA1 can't reveal no vote with salt s1 in BidEscrow voting with id 0 with RationaleRequired
+    When VA1 reveals no vote with salt s1 and rationale QmRationale in BidEscrow voting with id 0
+    And VA2 reveals yes vote with salt s2 in BidEscrow voting with id 0
+    Then VA1 ballot in BidEscrow formal voting with id 0 has rationale QmRationale
+    And VA2 ballot in BidEscrow formal voting with id 0 has no rationale
+
+  Scenario: Bid Escrow requires a rationale for a ballot changed to against
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value  |
+      | BidEscrowRationaleRequired             | true   |
+      | TimeBetweenInformalAndFormalVoting     | 0      |
+      | VotingStartAfterJobSubmission          | 0      |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    And voters vote in BidEscrow informal voting with id 0
+      | account          | REP stake | choice |
+     #| InternalWorker   | 100       | Yes    | - automatically voted by the system
+      | VA1              | 500       | Yes    |
+      | VA2              | 500       | Yes    |
+    Then VA1 can't change vote in BidEscrow informal voting with id 0 to no with 500 REP with RationaleRequired
+    And InternalWorker can't withdraw vote in BidEscrow informal voting with id 0 with CannotVoteOnOwnJob
+    When VA1 changes vote in BidEscrow informal voting with id 0 to no with 400 REP and rationale QmRationale
+    And VA2 withdraws vote in BidEscrow informal voting with id 0
+    Then VA1 ballot in BidEscrow informal voting with id 0 has rationale QmRationale
+    And balances are
+      | account          | CSPR balance | REP balance  | REP stake  |
+      | InternalWorker   | 0            | 1000         | 100        |
+      | VA1              | 0            | 1000         | 400        |
+      | VA2              | 0            | 1000         | 0          |
+
+  Scenario: Bid Escrow requires a rationale for a batched vote against
+    Given following balances
+      | account          | CSPR balance | REP balance  | REP stake  | is_kyced | is_va |
+      | BidEscrow        | 0            | 0            | 0          | false    | false |
+      | MultisigWallet   | 0            | 0            | 0          | false    | false |
+      | JobPoster        | 1000         | 0            | 0          | true     | false |
+      | InternalWorker   | 0            | 1000         | 0          | true     | true  |
+      | VA1              | 0            | 1000         | 0          | true     | true  |
+      | VA2              | 0            | 1000         | 0          | true     | true  |
+    And following configuration
+      | key                                    | value  |
+      | BidEscrowRationaleRequired             | true   |
+      | TimeBetweenInformalAndFormalVoting     | 0      |
+      | VotingStartAfterJobSubmission          | 0      |
+    When JobPoster posted a JobOffer with expected timeframe of 14 days, maximum budget of 1000 CSPR and 400 CSPR DOS Fee
+    And InternalWorker posted the Bid for JobOffer 0 with proposed timeframe of 7 days and 500 CSPR price and 100 REP stake
+    And JobPoster picked the Bid of InternalWorker
+    And InternalWorker submits the JobProof of Job 0
+    Then VA1 can't vote in BidEscrow contract in a batch failing at item 0 with RationaleRequired
+      | voting_id | voting_type | choice | REP stake |
+      | 0         | informal    | no     | 500       |
+    And VA1 can't vote in BidEscrow contract in a batch with rationale failing at item 0 with RationaleRequired
+      | voting_id | voting_type | choice | REP stake | rationale |
+      | 0         | informal    | no     | 500       |           |
+    When VA1 votes in BidEscrow contract in a batch with rationale
+      | voting_id | voting_type | choice | REP stake | rationale   |
+      | 0         | informal    | no     | 500       | QmRationale |
+    And VA2 votes in BidEscrow contract in a batch with rationale
+      | voting_id | voting_type | choice | REP stake | rationale   |
+      | 0         | informal    | yes    | 500       |             |
+    Then VA1 ballot in BidEscrow informal voting with id 0 has rationale QmRationale
+    And VA2 ballot in BidEscrow informal voting with id 0 has no rationale
@@ -0,0 +1,86 @@
+Feature: Secret formal ballots
+    If SecretFormalVoting is enabled, formal voting ballots are committed as hashes
+    and revealed after the formal voting. Unrevealed ballots are penalized.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+      And following configuration
+        | key                      | value     |
+        | SecretFormalVoting       | true      |
+        | FormalVotingRevealTime   | 172800000 |
+        | UnrevealedBallotPenalty  | 500       |
+
+    Scenario Outline: Secret ballots are revealed and unrevealed ballots are penalized
+      When Admin is the owner of all contracts
+      And VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 200        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      And VA2 commits secret yes vote with 500 REP and salt s2 in <voting_contract> voting with id 0
+      And VA3 commits secret yes vote with 200 REP and salt s3 in <voting_contract> voting with id 0
+      And VA4 commits secret no vote with 150 REP and salt s4 in <voting_contract> voting with id 0
+      Then VA6 can't commit secret yes vote with 0 REP and salt s6 in <voting_contract> voting with id 0 with ZeroStake
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 500       |
+        | VA3     | 1000         | 200       |
+        | VA4     | 1000         | 150       |
+      When 5 days passed
+      And VA2 reveals yes vote with salt s2 in <voting_contract> voting with id 0
+      Then VA3 can't reveal no vote with salt s3 in <voting_contract> voting with id 0
+      When VA3 reveals yes vote with salt s3 in <voting_contract> voting with id 0
+      And 3 days passed
+      And formal voting with id 0 ends in <voting_contract> contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 0         |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 0         |
+        | VA4     | 925          | 0         |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: Public ballots are not allowed in the secret formal voting
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 500        | yes     |
+        | VA3     | 200        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      And 2 days passed
+      Then votes in <voting_contract> formal voting with id 0 fail
+        | user    | REP stake  | choice  | result                |
+        | VA2     | 500        | yes     | SecretBallotRequired  |
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
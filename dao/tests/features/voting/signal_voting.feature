@@ -0,0 +1,74 @@
+Feature: Signal voting
+    A Signal Voting is a non-binding poll with a single phase of SignalVotingTime.
+    No reputation is staked, no formal voting starts and no action is performed.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+      And following configuration
+        | key               | value        |
+        | SignalVotingTime  | 86400000     |
+        | SignalQuorumRatio | 500          |
+      When VA1 starts signal voting on Proposal
+
+    Scenario: Signal voting passes without staking reputation
+      When voters vote in signal voting with id 0
+        | user    | choice  |
+       #| VA1     | yes     | - automatically voted by the system
+        | VA2     | yes     |
+        | VA3     | yes     |
+        | VA4     | no      |
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 0         |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 0         |
+        | VA4     | 1000         | 0         |
+      When 2 days passed
+      Then VA5 can't vote yes in signal voting with id 0
+      And signal voting with id 0 ends in_favor
+      And formal voting with id 0 in SignalVoter contract does not start
+      And users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 0         |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 0         |
+        | VA4     | 1000         | 0         |
+
+    Scenario: Signal voting is rejected
+      When voters vote in signal voting with id 0
+        | user    | choice  |
+       #| VA1     | yes     | - automatically voted by the system
+        | VA2     | no      |
+        | VA3     | no      |
+      And 2 days passed
+      Then signal voting with id 0 ends against
+      And formal voting with id 0 in SignalVoter contract does not start
+
+    Scenario: Signal voting requires SignalQuorumRatio of VAs to vote
+      When voters vote in signal voting with id 0
+        | user    | choice  |
+       #| VA1     | yes     | - automatically voted by the system
+        | VA2     | yes     |
+      And 2 days passed
+      Then signal voting with id 0 ends without_quorum
+      And formal voting with id 0 in SignalVoter contract does not start
+
+    Scenario: Voters change and withdraw their votes, the creator's vote is immutable
+      When voters vote in signal voting with id 0
+        | user    | choice  |
+       #| VA1     | yes     | - automatically voted by the system
+        | VA2     | yes     |
+        | VA3     | yes     |
+        | VA4     | no      |
+      And VA2 changes vote to no in signal voting with id 0
+      And VA3 withdraws vote in signal voting with id 0
+      Then VA1 can't withdraw vote in signal voting with id 0
+      When 2 days passed
+      Then signal voting with id 0 ends against
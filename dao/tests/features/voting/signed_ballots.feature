@@ -0,0 +1,60 @@
+Feature: Signed ballots
+    A voter signs a ballot off-chain and any account can relay it to the voting contract.
+    Each signed ballot uses the voter's next nonce, so it cannot be replayed.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+
+    Scenario: A relayer submits ballots signed by voters in a single call
+      When Alice relays ballots signed by voters in informal voting with id 0 in SimpleVoter contract
+        | user    | REP stake  | choice  |
+        | VA2     | 500        | yes     |
+        | VA3     | 300        | no      |
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 500       |
+        | VA3     | 1000         | 300       |
+      And VA2 nonce in SimpleVoter contract is 1
+      And VA3 nonce in SimpleVoter contract is 1
+      And Alice can't relay a used ballot of VA2 with 500 REP in informal voting with id 0 in SimpleVoter contract
+
+    Scenario: No ballot is cast if any ballot in the batch is invalid
+      When Alice relays ballots signed by voters in informal voting with id 0 in SimpleVoter contract
+        | user    | REP stake  | choice  |
+        | VA2     | 500        | yes     |
+        | VA3     | 3000       | no      |
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1000         | 100       |
+        | VA2     | 1000         | 0         |
+        | VA3     | 1000         | 0         |
+      And VA2 nonce in SimpleVoter contract is 0
+      And VA3 nonce in SimpleVoter contract is 0
+
+    Scenario: A ballot signed with another key is rejected
+      Then Alice can't relay a ballot of VA2 signed by VA3 in informal voting with id 0 in SimpleVoter contract
+      And VA2 nonce in SimpleVoter contract is 0
+
+    Scenario: A signed ballot carries the voter's rationale
+      When Alice relays ballots signed by voters in informal voting with id 0 in SimpleVoter contract
+        | user    | REP stake  | choice  | rationale  |
+        | VA2     | 500        | yes     | QmVA2      |
+        | VA3     | 300        | no      |            |
+      Then VA2 ballot in SimpleVoter informal voting with id 0 has rationale QmVA2
+      And VA3 ballot in SimpleVoter informal voting with id 0 has no rationale
+
+    Scenario: A ballot with a changed rationale is rejected
+      Then Alice can't relay a ballot of VA2 with rationale changed to QmForged in informal voting with id 0 in SimpleVoter contract
+      And VA2 nonce in SimpleVoter contract is 0
+
+    Scenario: An expired ballot is rejected
+      When 1 day passed
+      Then Alice can't relay an expired ballot of VA2 with 500 REP in informal voting with id 0 in SimpleVoter contract
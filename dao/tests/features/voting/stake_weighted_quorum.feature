@@ -0,0 +1,82 @@
+Feature: Stake weighted quorum
+    If StakeWeightedQuorum is enabled, the quorum is a share of the total reputation supply
+    and is compared to the reputation staked in the voting, not to the number of voters.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+        | VA5     | true  | 1000        |
+        | VA6     | true  | 1000        |
+      And following configuration
+        | key                  | value |
+        | StakeWeightedQuorum  | true  |
+
+    Scenario Outline: Many voters with a low stake do not reach the quorum
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 100        | yes     |
+        | VA3     | 100        | yes     |
+        | VA4     | 100        | no      |
+        | VA6     | 100        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract does not start
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: Voters with a high stake reach the quorum
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 1000       | yes     |
+        | VA3     | 1000       | yes     |
+        | VA4     | 1000       | no      |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract starts
+     Examples:
+        | voting_contract  | arg1               | arg2             | arg3  |
+        | KycVoter         | Alice              |                  |       |
+        | Admin            | ReputationToken    | add_to_whitelist | Alice |
+        | SlashingVoter    | VA5                | 1                |       |
+        | RepoVoter        | VariableRepository | PostJobDOSFee    | 1     |
+        | SimpleVoter      |                    |                  |       |
+        | ReputationVoter  | Alice              | mint             | 100   |
+
+    Scenario Outline: Quadratic voting does not lower the staked reputation compared to the quorum
+      Given following configuration
+        | key         | value |
+        | <quadratic> | true  |
+      When VA1 starts voting with the following config
+        | voting_contract    | stake | arg1    | arg2    | arg3   |
+        | <voting_contract>  | 100   | <arg1>  | <arg2>  | <arg3> |
+      And voters vote in <voting_contract> informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 1000       | yes     |
+        | VA3     | 1000       | yes     |
+        | VA4     | 1000       | no      |
+      And 5 days passed
+      And informal voting with id 0 ends in <voting_contract> contract
+      Then formal voting with id 0 in <voting_contract> contract starts
+     Examples:
+        | voting_contract  | quadratic             | arg1               | arg2          | arg3 |
+        | SimpleVoter      | SimpleQuadraticVoting |                    |               |      |
+        | RepoVoter        | RepoQuadraticVoting   | VariableRepository | PostJobDOSFee | 1    |
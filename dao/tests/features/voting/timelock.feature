@@ -0,0 +1,90 @@
+Feature: Timelock
+  If TimelockDelay is set, the action of a passed voting is queued and can be executed by anyone
+  once the delay has passed. The guardian or a passed veto voting can cancel the queued action in the meantime.
+
+Background:
+  Given users
+    | user    | is_va | REP balance |
+    | Alice   | false | 0           |
+    | Bob     | false | 0           |
+    | VA1     | true  | 1000        |
+    | VA2     | true  | 1000        |
+    | VA3     | true  | 1000        |
+  And following configuration
+    | key            | value     |
+    | PostJobDOSFee  | 10        |
+    | TimelockDelay  | 172800000 |
+
+Scenario: The action of a passed voting is executed after the delay
+  When RepoVoter voting with id 0 created by VA1 passes
+    | voting_contract | stake | arg1               | arg2             | arg3   |
+    | RepoVoter       | 100   | VariableRepository | PostJobDOSFee    | 12345  |
+  Then value of PostJobDOSFee is 10
+  And Alice can't execute the action of voting with id 0 in RepoVoter contract with TimelockNotExpired
+  When 2 days passed
+  And Alice executes the action of voting with id 0 in RepoVoter contract
+  Then value of PostJobDOSFee is 12345
+  And Alice can't execute the action of voting with id 0 in RepoVoter contract with QueuedActionNotPending
+
+Scenario: The guardian cancels the queued action
+  Given following configuration
+    | key             | value |
+    | GuardianAddress | Bob   |
+  When RepoVoter voting with id 0 created by VA1 passes
+    | voting_contract | stake | arg1               | arg2             | arg3   |
+    | RepoVoter       | 100   | VariableRepository | PostJobDOSFee    | 12345  |
+  Then VA2 can't cancel the action of voting with id 0 in RepoVoter contract
+  When Bob cancels the action of voting with id 0 in RepoVoter contract
+  And 2 days passed
+  Then Alice can't execute the action of voting with id 0 in RepoVoter contract with QueuedActionNotPending
+  And value of PostJobDOSFee is 10
+
+Scenario: A passed veto voting cancels the queued action
+  Given following configuration
+    | key              | value              |
+    | TimelockDelay    | 0                  |
+    | VetoVoterAddress | GovernanceExecutor |
+  When GovernanceExecutor voting with id 0 created by VA1 passes
+    | voting_contract    | stake | arg1       | arg2      | arg3          |
+    | GovernanceExecutor | 100   | allow_call | RepoVoter | cancel_action |
+  Given following configuration
+    | key              | value              |
+    | TimelockDelay    | 1296000000         |
+  When RepoVoter voting with id 1 created by VA1 passes
+    | voting_contract | stake | arg1               | arg2             | arg3   |
+    | RepoVoter       | 100   | VariableRepository | PostJobDOSFee    | 12345  |
+  And GovernanceExecutor voting with id 2 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2      | arg3 |
+    | GovernanceExecutor | 100   | cancel_action | RepoVoter | 1    |
+  And 3 days passed
+  Then Alice can't execute the action of voting with id 1 in RepoVoter contract with QueuedActionNotPending
+  And value of PostJobDOSFee is 10
+
+Scenario: The action is performed right away if there is no delay
+  Given following configuration
+    | key            | value |
+    | TimelockDelay  | 0     |
+  When RepoVoter voting with id 0 created by VA1 passes
+    | voting_contract | stake | arg1               | arg2             | arg3   |
+    | RepoVoter       | 100   | VariableRepository | PostJobDOSFee    | 12345  |
+  Then value of PostJobDOSFee is 12345
+  And Alice can't execute the action of voting with id 0 in RepoVoter contract with QueuedActionNotFound
+
+Scenario: A cancel_action voting of a contract other than the veto voter is queued
+  Given following configuration
+    | key              | value              |
+    | TimelockDelay    | 0                  |
+  When GovernanceExecutor voting with id 0 created by VA1 passes
+    | voting_contract    | stake | arg1       | arg2      | arg3          |
+    | GovernanceExecutor | 100   | allow_call | RepoVoter | cancel_action |
+  Given following configuration
+    | key              | value              |
+    | TimelockDelay    | 1296000000         |
+  When RepoVoter voting with id 1 created by VA1 passes
+    | voting_contract | stake | arg1               | arg2             | arg3   |
+    | RepoVoter       | 100   | VariableRepository | PostJobDOSFee    | 12345  |
+  And GovernanceExecutor voting with id 2 created by VA1 passes
+    | voting_contract    | stake | arg1          | arg2      | arg3 |
+    | GovernanceExecutor | 100   | cancel_action | RepoVoter | 1    |
+  Then Alice can't execute the action of voting with id 2 in GovernanceExecutor contract with TimelockNotExpired
+  And Alice can't execute the action of voting with id 1 in RepoVoter contract with TimelockNotExpired
@@ -0,0 +1,52 @@
+Feature: Batch voting
+  A voter can cast ballots in many votings of a contract in one call.
+  The reputation is staked at once, and either all the ballots are cast or none of them.
+
+  Background:
+    Given users
+      | user    | is_va | REP balance |
+      | VA1     | true  | 1000        |
+      | VA2     | true  | 1000        |
+      | VA3     | true  | 1000        |
+    When VA1 starts voting with the following config
+      | voting_contract | stake | arg1 | arg2 | arg3 |
+      | SimpleVoter     | 100   |      |      |      |
+      | SimpleVoter     | 100   |      |      |      |
+
+  Scenario: All the ballots are cast
+    When VA2 votes in SimpleVoter contract in a batch
+      | voting_id | voting_type | choice | REP stake |
+      | 0         | informal    | yes    | 300       |
+      | 1         | informal    | no     | 200       |
+    Then users balances are
+      | account | REP balance | REP stake |
+      | VA1     | 1000        | 200       |
+      | VA2     | 1000        | 500       |
+    And VA2 ballot in SimpleVoter informal voting with id 0 has no rationale
+    And VA2 ballot in SimpleVoter informal voting with id 1 has no rationale
+
+  Scenario: No ballot is cast if any item fails
+    Then VA2 can't vote in SimpleVoter contract in a batch failing at item 1 with InsufficientBalance
+      | voting_id | voting_type | choice | REP stake |
+      | 0         | informal    | yes    | 600       |
+      | 1         | informal    | no     | 500       |
+    And VA2 can't vote in SimpleVoter contract in a batch failing at item 1 with CannotVoteTwice
+      | voting_id | voting_type | choice | REP stake |
+      | 0         | informal    | yes    | 100       |
+      | 0         | informal    | no     | 100       |
+    And VA2 can't vote in SimpleVoter contract in a batch failing at item 0 with VotingWithGivenTypeNotInProgress
+      | voting_id | voting_type | choice | REP stake |
+      | 0         | formal      | yes    | 100       |
+      | 1         | informal    | no     | 100       |
+    And VA3 can't vote in SimpleVoter contract in a batch failing at item 1 with VotingDoesNotExist
+      | voting_id | voting_type | choice | REP stake |
+      | 0         | informal    | yes    | 100       |
+      | 5         | informal    | no     | 100       |
+    And VA1 can't vote in SimpleVoter contract in a batch failing at item 0 with CannotVoteTwice
+      | voting_id | voting_type | choice | REP stake |
+      | 1         | informal    | yes    | 100       |
+    And users balances are
+      | account | REP balance | REP stake |
+      | VA1     | 1000        | 200       |
+      | VA2     | 1000        | 0         |
+      | VA3     | 1000        | 0         |
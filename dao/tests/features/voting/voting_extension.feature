@@ -0,0 +1,127 @@
+Feature: Voting extension
+    If a ballot cast in the last moments of a voting phase changes the leading side or makes the result close,
+    the phase is extended. The total extension of a phase is capped.
+    Background:
+      Given users
+        | user    | is_va | REP balance |
+        | Alice   | false | 0           |
+        | VA1     | true  | 1000        |
+        | VA2     | true  | 1000        |
+        | VA3     | true  | 1000        |
+        | VA4     | true  | 1000        |
+
+    Scenario: A late ballot that flips the result extends the formal phase
+      Given following configuration
+        | key                    | value     |
+        | VotingExtensionWindow  | 3600000   |
+        | VotingExtensionTime    | 86400000  |
+        | VotingExtensionCap     | 172800000 |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 3 days passed
+      And 23 hours passed
+      And 30 minutes passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA3     | 600        | no      |
+      Then formal phase of voting with id 0 in SimpleVoter contract is extended by 1 day
+      When 1 hour passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA4     | 200        | yes     |
+      Then formal phase of voting with id 0 in SimpleVoter contract is extended by 1 day
+      When 1 day passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1085         | 0         |
+        | VA2     | 1342         | 0         |
+        | VA3     | 400          | 0         |
+        | VA4     | 1171         | 0         |
+
+    Scenario: The extension is capped
+      Given following configuration
+        | key                    | value     |
+        | VotingExtensionWindow  | 3600000   |
+        | VotingExtensionTime    | 86400000  |
+        | VotingExtensionCap     | 86400000  |
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 3 days passed
+      And 23 hours passed
+      And 30 minutes passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA3     | 600        | no      |
+      Then formal phase of voting with id 0 in SimpleVoter contract is extended by 1 day
+      When 1 day passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA4     | 200        | yes     |
+      Then formal phase of voting with id 0 in SimpleVoter contract is extended by 1 day
+      When 1 hour passed
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 1085         | 0         |
+        | VA2     | 1342         | 0         |
+        | VA3     | 400          | 0         |
+        | VA4     | 1171         | 0         |
+
+    Scenario: A late ballot does not extend the phase if the extension is disabled
+      When VA1 starts voting with the following config
+        | voting_contract  | stake | arg1 | arg2 | arg3 |
+        | SimpleVoter      | 100   |      |      |      |
+      And voters vote in SimpleVoter informal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 5 days passed
+      And informal voting with id 0 ends in SimpleVoter contract
+      And 2 days passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+       #| VA1     | 100        | yes     | - automatically voted by the system
+        | VA2     | 400        | yes     |
+      And 3 days passed
+      And 23 hours passed
+      And 30 minutes passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA3     | 600        | no      |
+      Then formal phase of voting with id 0 in SimpleVoter contract is extended by 0 days
+      When 1 hour passed
+      And voters vote in SimpleVoter formal voting with id 0
+        | user    | REP stake  | choice  |
+        | VA4     | 200        | yes     |
+      And formal voting with id 0 ends in SimpleVoter contract
+      Then users balances are
+        | account | REP balance  | REP stake |
+        | VA1     | 900          | 0         |
+        | VA2     | 600          | 0         |
+        | VA3     | 1500         | 0         |
+        | VA4     | 1000         | 0         |
@@ -0,0 +1,55 @@
+Feature: Withdrawing a voting
+  The creator can withdraw their voting while it is informal and no one else has voted.
+  The stake is returned to the creator.
+
+Background:
+  Given users
+    | user    | is_va | REP balance |
+    | Alice   | false | 0           |
+    | VA1     | true  | 1000        |
+    | VA2     | true  | 1000        |
+    | VA3     | true  | 1000        |
+  When VA1 starts voting with the following config
+    | voting_contract | stake | arg1 | arg2 | arg3 |
+    | SimpleVoter     | 100   |      |      |      |
+
+Scenario: The creator withdraws a voting
+  Then VA2 can't withdraw voting with id 0 in SimpleVoter contract with NotVotingCreator
+  When VA1 withdraws voting with id 0 in SimpleVoter contract
+  Then SimpleVoter voting with id 0 is canceled
+  And users balances are
+    | account | REP balance | REP stake |
+    | VA1     | 1000        | 0         |
+  And VA1 can't withdraw voting with id 0 in SimpleVoter contract with VotingAlreadyCanceled
+
+Scenario: A voting can't be withdrawn once someone else has voted
+  When voters vote in SimpleVoter informal voting with id 0
+    | user    | REP stake  | choice  |
+   #| VA1     | 100        | yes     | - automatically voted by the system
+    | VA2     | 100        | no      |
+  Then VA1 can't withdraw voting with id 0 in SimpleVoter contract with VotingHasOtherBallots
+
+Scenario: A voting can't be withdrawn after the informal phase
+  When 6 days passed
+  Then VA1 can't withdraw voting with id 0 in SimpleVoter contract with VotingWithGivenTypeNotInProgress
+
+Scenario: The creator withdraws a repo voting
+  When VA2 starts voting with the following config
+    | voting_contract | stake | arg1               | arg2          | arg3  |
+    | RepoVoter       | 100   | VariableRepository | PostJobDOSFee | 12345 |
+  And VA2 withdraws voting with id 1 in RepoVoter contract
+  Then RepoVoter voting with id 1 is canceled
+  And users balances are
+    | account | REP balance | REP stake |
+    | VA2     | 1000        | 0         |
+
+Scenario: The subject of a withdrawn KYC voting can be proposed again
+  When VA2 starts voting with the following config
+    | voting_contract | stake | arg1  |
+    | KycVoter        | 100   | Alice |
+  And VA2 withdraws voting with id 1 in KycVoter contract
+  Then KycVoter voting with id 1 is canceled
+  When VA3 starts voting with the following config
+    | voting_contract | stake | arg1  |
+    | KycVoter        | 100   | Alice |
+  Then voting with id 2 in KycVoter contract starts
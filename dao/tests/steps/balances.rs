@@ -7,6 +7,16 @@ use crate::common::{
     DaoWorld,
 };
 
+#[when(expr = "{account} mints {reputation} REP to {account}")]
+fn mint_reputation(
+    world: &mut DaoWorld,
+    minter: Account,
+    amount: ReputationBalance,
+    recipient: Account,
+) {
+    world.mint_reputation(&minter, &recipient, amount);
+}
+
 #[when(expr = "{account} burns all reputation of {account}")]
 fn burn_all_reputation(world: &mut DaoWorld, burner: Account, holder: Account) {
     world.burn_all_reputation(&burner, &holder);
@@ -17,6 +27,16 @@ fn total_reputation(world: &mut DaoWorld, total_reputation_expected: ReputationB
     world.assert_total_supply(total_reputation_expected);
 }
 
+#[then(expr = "total reputation is exactly {reputation}")]
+fn exact_total_reputation(world: &mut DaoWorld, total_reputation_expected: ReputationBalance) {
+    world.assert_exact_total_supply(total_reputation_expected);
+}
+
+#[then(expr = "reputation dust in {account} contract is {reputation}")]
+fn reputation_dust(world: &mut DaoWorld, contract: Account, expected_dust: ReputationBalance) {
+    assert_eq!(world.get_reputation_dust(&contract), *expected_dust);
+}
+
 #[then(expr = "passive REP of {account} is {reputation}")]
 fn assert_passive_reputation(
     world: &mut DaoWorld,
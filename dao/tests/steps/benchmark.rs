@@ -0,0 +1,46 @@
+use cucumber::{then, when};
+
+use crate::common::{
+    params::{voting::VotingType, Account, Contract, ReputationBalance},
+    DaoWorld,
+};
+
+#[when(expr = "{account} creates {int} test votings in {contract}")]
+fn create_test_votings(world: &mut DaoWorld, creator: Account, count: u32, contract: Contract) {
+    for _ in 0..count {
+        world.measure_gas(&creator, |world| {
+            world.create_test_voting(contract, creator, ReputationBalance::one())
+        });
+    }
+}
+
+#[when(
+    expr = "{account} finishes {voting_type} votings with ids from {int} to {int} in {account} contract"
+)]
+fn finish_votings(
+    world: &mut DaoWorld,
+    keeper: Account,
+    voting_type: VotingType,
+    from: u32,
+    to: u32,
+    contract: Account,
+) {
+    for voting_id in from..=to {
+        world.measure_gas(&keeper, |world| {
+            world.finish_voting(&contract, voting_id, Some(voting_type))
+        });
+    }
+}
+
+#[then(expr = "the last {int} calls used at most {int} percent more gas than the first ones")]
+fn assert_flat_cost(world: &mut DaoWorld, count: usize, percent: u32) {
+    let (first, last) = world.take_gas_used(count);
+    assert!(
+        last * 100 <= first * (100 + percent),
+        "The last {} calls used {} gas, the first {} used {}",
+        count,
+        last,
+        count,
+        first
+    );
+}
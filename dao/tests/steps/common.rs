@@ -1,11 +1,7 @@
 use cucumber::{gherkin::Step, given, when};
 use odra::types::BlockTime;
 
-use crate::common::{
-    helpers::{to_milliseconds, value_to_bytes},
-    params::TimeUnit,
-    DaoWorld,
-};
+use crate::common::{helpers::to_milliseconds, params::TimeUnit, DaoWorld};
 
 #[when(expr = "{int} {time_unit} passed")]
 fn advance_time(world: &mut DaoWorld, amount: BlockTime, unit: TimeUnit) {
@@ -18,6 +14,7 @@ fn configuration(world: &mut DaoWorld, step: &Step) {
     for row in table {
         let variable = row[0].as_str();
         let value = row[1].as_str();
-        world.set_variable(variable.to_string(), value_to_bytes(value, variable));
+        let value = world.variable_to_bytes(variable, value);
+        world.set_variable(variable.to_string(), value);
     }
 }
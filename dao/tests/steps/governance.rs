@@ -0,0 +1,36 @@
+use cucumber::then;
+use dao::utils::Error;
+
+use crate::common::{
+    params::{Account, Contract},
+    DaoWorld,
+};
+
+#[then(expr = "{account} can't allow {contract} {word} calls in GovernanceExecutor")]
+fn allow_call_fails(
+    world: &mut DaoWorld,
+    caller: Account,
+    contract: Contract,
+    entry_point: String,
+) {
+    world.failing_allow_governance_call(
+        &caller,
+        &contract,
+        &entry_point,
+        Error::NotGovernanceVoting,
+    );
+}
+
+#[then(expr = "GovernanceExecutor voting with id {int} calls {contract} {word}")]
+fn assert_contract_call(
+    world: &mut DaoWorld,
+    voting_id: u32,
+    contract: Contract,
+    entry_point: String,
+) {
+    let address = world.get_contract_address(&contract);
+    let contract_calls = world.governance_contract_calls(voting_id);
+    assert_eq!(contract_calls.len(), 1);
+    assert_eq!(contract_calls[0].address(), &address);
+    assert_eq!(contract_calls[0].entry_point(), entry_point);
+}
@@ -0,0 +1,34 @@
+use cucumber::{then, when};
+use dao::utils::types::DocumentHash;
+
+use crate::common::{
+    params::{Account, Error},
+    DaoWorld,
+};
+
+#[when(
+    expr = "{account} cancels voting with id {int} in {account} contract as the guardian because of {word}"
+)]
+fn guardian_cancel_voting(
+    world: &mut DaoWorld,
+    caller: Account,
+    voting_id: u32,
+    contract: Account,
+    reason: DocumentHash,
+) {
+    world.guardian_cancel_voting(&caller, &contract, voting_id, reason);
+}
+
+#[then(
+    expr = "{account} can't cancel voting with id {int} in {account} contract as the guardian with {word}"
+)]
+fn guardian_cancel_voting_fails(
+    world: &mut DaoWorld,
+    caller: Account,
+    voting_id: u32,
+    contract: Account,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_guardian_cancel_voting(&caller, &contract, voting_id, *error);
+}
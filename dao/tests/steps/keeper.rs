@@ -0,0 +1,58 @@
+use cucumber::{then, when};
+
+use crate::common::{
+    helpers,
+    params::{voting::VotingType, Account, CsprBalance},
+    DaoWorld,
+};
+
+#[when(expr = "{account} funds the keeper reward pool of {account} contract with {balance} CSPR")]
+fn fund_keeper_reward_pool(
+    world: &mut DaoWorld,
+    funder: Account,
+    contract: Account,
+    amount: CsprBalance,
+) {
+    world.fund_keeper_reward_pool(&contract, &funder, *amount);
+}
+
+#[then(expr = "the keeper reward pool of {account} contract is {balance} CSPR")]
+fn assert_keeper_reward_pool(world: &mut DaoWorld, contract: Account, amount: CsprBalance) {
+    assert_eq!(world.get_keeper_reward_pool(&contract), *amount);
+}
+
+#[when(expr = "{account} finishes {voting_type} voting with id {int} in {account} contract")]
+fn finish_voting_as_keeper(
+    world: &mut DaoWorld,
+    keeper: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+) {
+    world.set_caller(&keeper);
+    world.finish_voting(&contract, voting_id, Some(voting_type));
+}
+
+#[then(expr = "voting with id {int} in {account} contract {word} actionable")]
+fn assert_voting_actionable(
+    world: &mut DaoWorld,
+    voting_id: u32,
+    contract: Account,
+    is_actionable: String,
+) {
+    let is_actionable = helpers::parse_bool(is_actionable);
+    assert_eq!(
+        world.is_voting_actionable(&contract, voting_id),
+        is_actionable
+    );
+}
+
+#[then(expr = "JobOffer with id {int} {word} actionable")]
+fn assert_job_offer_actionable(world: &mut DaoWorld, job_offer_id: u32, is_actionable: String) {
+    let is_actionable = helpers::parse_bool(is_actionable);
+    let actionable_work = world.bid_escrow.get_actionable_work(0, 100);
+    assert_eq!(
+        actionable_work.job_offers.contains(&job_offer_id),
+        is_actionable
+    );
+}
@@ -1,9 +1,19 @@
 mod balances;
+mod benchmark;
 mod bid_escrow;
 mod common;
+mod governance;
+mod guardian;
+mod keeper;
 mod kyc;
+mod multi_option;
 mod ownership;
+mod participation;
+mod queries;
 mod setup;
+mod signal;
+mod signed_ballots;
+mod timelock;
 mod va;
 mod variables;
 mod voting;
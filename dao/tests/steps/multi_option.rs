@@ -0,0 +1,175 @@
+use cucumber::{gherkin::Step, given, then, when};
+use dao::utils::Error as DaoError;
+use dao::voting::multi_option_engine::multi_option_voting::VotingOption;
+
+use crate::common::{
+    helpers,
+    params::{
+        voting::{parse_ranking, TallyMethod, VotingType},
+        Account, Error, ReputationBalance,
+    },
+    DaoWorld,
+};
+use crate::steps::suppress;
+
+#[given(
+    expr = "{account} starts {tally_method} multi-option voting with {reputation} REP ranking {word}"
+)]
+#[when(
+    expr = "{account} starts {tally_method} multi-option voting with {reputation} REP ranking {word}"
+)]
+fn multi_option_voting_setup(
+    world: &mut DaoWorld,
+    step: &Step,
+    creator: Account,
+    tally_method: TallyMethod,
+    stake: ReputationBalance,
+    ranking: String,
+) {
+    let options = parse_options(world, step);
+    world.create_multi_option_voting(
+        &creator,
+        options,
+        tally_method.into(),
+        parse_ranking(&ranking),
+        stake,
+    );
+}
+
+#[then(
+    expr = "{account} can't start {tally_method} multi-option voting with {reputation} REP ranking {word} with {word}"
+)]
+fn multi_option_voting_setup_fails(
+    world: &mut DaoWorld,
+    step: &Step,
+    creator: Account,
+    tally_method: TallyMethod,
+    stake: ReputationBalance,
+    ranking: String,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    let options = parse_options(world, step);
+    world.failing_create_multi_option_voting(
+        &creator,
+        options,
+        tally_method.into(),
+        parse_ranking(&ranking),
+        stake,
+        *error,
+    );
+}
+
+fn parse_options(world: &DaoWorld, step: &Step) -> Vec<VotingOption> {
+    step.table
+        .as_ref()
+        .unwrap()
+        .rows
+        .iter()
+        .skip(1)
+        .map(|row| {
+            let document_hash = helpers::parse::<String>(row.get(0), "Couldn't parse document");
+            if row.len() == 1 {
+                return VotingOption {
+                    document_hash,
+                    contract_calls: vec![],
+                };
+            }
+            let key = helpers::parse::<String>(row.get(1), "Couldn't parse key");
+            let value = helpers::parse::<String>(row.get(2), "Couldn't parse value");
+            world.variable_update_option(&document_hash, &key, &value)
+        })
+        .collect()
+}
+
+#[when(expr = "voters rank options in {voting_type} multi-option voting with id {int}")]
+fn rank_options(world: &mut DaoWorld, step: &Step, voting_type: VotingType, voting_id: u32) {
+    let rows = step.table.as_ref().unwrap().rows.iter().skip(1);
+    for row in rows {
+        let voter = helpers::parse::<Account>(row.get(0), "Couldn't parse voter");
+        let stake = helpers::parse_or_default::<ReputationBalance>(row.get(1));
+        let ranking = helpers::parse::<String>(row.get(2), "Couldn't parse ranking");
+        suppress(|| {
+            world.rank_options(
+                &voter,
+                voting_id,
+                voting_type,
+                parse_ranking(&ranking),
+                stake,
+            )
+        });
+    }
+}
+
+#[then(
+    expr = "{account} can't rank options {word} with {reputation} REP in {voting_type} multi-option voting with id {int}"
+)]
+fn rank_options_fails(
+    world: &mut DaoWorld,
+    voter: Account,
+    ranking: String,
+    stake: ReputationBalance,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    world.failing_rank_options(
+        &voter,
+        voting_id,
+        voting_type,
+        parse_ranking(&ranking),
+        stake,
+        DaoError::InvalidRanking,
+    );
+}
+
+#[when(
+    expr = "{account} changes ranking to {word} with {reputation} REP in {voting_type} multi-option voting with id {int}"
+)]
+fn change_ranking(
+    world: &mut DaoWorld,
+    voter: Account,
+    ranking: String,
+    stake: ReputationBalance,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    world.change_ranking(
+        &voter,
+        voting_id,
+        voting_type,
+        parse_ranking(&ranking),
+        stake,
+    );
+}
+
+#[when(expr = "{account} withdraws ranking in {voting_type} multi-option voting with id {int}")]
+fn withdraw_ranking(world: &mut DaoWorld, voter: Account, voting_type: VotingType, voting_id: u32) {
+    world.withdraw_ranking(&voter, voting_id, voting_type);
+}
+
+#[then(
+    expr = "{account} can't withdraw ranking in {voting_type} multi-option voting with id {int}"
+)]
+fn withdraw_ranking_fails(
+    world: &mut DaoWorld,
+    voter: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    world.failing_withdraw_ranking(
+        &voter,
+        voting_id,
+        voting_type,
+        DaoError::CannotChangeCreatorsBallot,
+    );
+}
+
+#[then(expr = "option {int} wins multi-option voting with id {int}")]
+fn assert_winner(world: &mut DaoWorld, option: u32, voting_id: u32) {
+    assert_eq!(world.multi_option_voting_winner(voting_id), Some(option));
+}
+
+#[then(expr = "no option wins multi-option voting with id {int}")]
+fn assert_no_winner(world: &mut DaoWorld, voting_id: u32) {
+    assert_eq!(world.multi_option_voting_winner(voting_id), None);
+}
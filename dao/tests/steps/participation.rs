@@ -0,0 +1,24 @@
+use cucumber::{then, when};
+use dao::utils::Error;
+
+use crate::common::{params::Account, DaoWorld};
+
+#[when(expr = "{account} settles the participation epoch")]
+fn settle_epoch(world: &mut DaoWorld, caller: Account) {
+    world.settle_participation_epoch(&caller, 100);
+}
+
+#[when(expr = "{account} settles {int} reputation holders of the participation epoch")]
+fn settle_epoch_batch(world: &mut DaoWorld, caller: Account, limit: u32) {
+    world.settle_participation_epoch(&caller, limit);
+}
+
+#[then(expr = "{account} can't settle the participation epoch")]
+fn settle_epoch_fails(world: &mut DaoWorld, caller: Account) {
+    world.failing_settle_participation_epoch(&caller, Error::EpochNotEnded);
+}
+
+#[then(expr = "{int} participation epochs are settled")]
+fn assert_settled_epochs(world: &mut DaoWorld, epochs: u64) {
+    assert_eq!(world.settled_participation_epochs(), epochs);
+}
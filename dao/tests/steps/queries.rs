@@ -0,0 +1,95 @@
+use cucumber::then;
+
+use crate::common::{
+    params::{voting::VotingType, Account},
+    DaoWorld,
+};
+
+#[then(expr = "page of {int} from {int} of {account} active votings is {word}")]
+fn assert_active_votings(
+    world: &mut DaoWorld,
+    limit: u32,
+    offset: u32,
+    contract: Account,
+    expected: String,
+) {
+    let votings = world.active_votings(&contract, offset, limit);
+    assert_eq!(votings, parse_ids(&expected));
+}
+
+#[then(expr = "page of {int} from {int} of {account} votings created by {account} is {word}")]
+fn assert_votings_by_creator(
+    world: &mut DaoWorld,
+    limit: u32,
+    offset: u32,
+    contract: Account,
+    creator: Account,
+    expected: String,
+) {
+    let votings = world.votings_by_creator(&contract, &creator, offset, limit);
+    assert_eq!(votings, parse_ids(&expected));
+}
+
+#[then(expr = "page of {int} from {int} of {account} ballots of {account} is {word}")]
+fn assert_ballots_of(
+    world: &mut DaoWorld,
+    limit: u32,
+    offset: u32,
+    contract: Account,
+    voter: Account,
+    expected: String,
+) {
+    let votings = world
+        .ballots_of(&contract, &voter, offset, limit)
+        .iter()
+        .map(|ballot| ballot.voting_id)
+        .collect::<Vec<_>>();
+    assert_eq!(votings, parse_ids(&expected));
+}
+
+#[then(expr = "{account} {voting_type} voting with id {int} has {int} voters")]
+fn assert_voters_count(
+    world: &mut DaoWorld,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    expected: u32,
+) {
+    assert_eq!(
+        world.voters_count(&contract, voting_id, voting_type),
+        expected
+    );
+}
+
+#[then(
+    expr = "page of {int} from {int} of ballots in {account} {voting_type} voting with id {int} is cast by {word}"
+)]
+fn assert_ballots_page(
+    world: &mut DaoWorld,
+    limit: u32,
+    offset: u32,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    expected: String,
+) {
+    let voters = world
+        .ballots_page(&contract, voting_id, voting_type, offset, limit)
+        .iter()
+        .map(|ballot| ballot.voter)
+        .collect::<Vec<_>>();
+    let expected = expected
+        .split(',')
+        .filter(|s| !s.is_empty() && *s != "nobody")
+        .map(|s| s.parse::<Account>().expect("Couldn't parse voter"))
+        .map(|account| world.get_address(&account))
+        .collect::<Vec<_>>();
+    assert_eq!(voters, expected);
+}
+
+fn parse_ids(ids: &str) -> Vec<u32> {
+    ids.split(',')
+        .filter(|s| !s.is_empty() && *s != "none")
+        .map(|s| s.parse().expect("Couldn't parse voting id"))
+        .collect()
+}
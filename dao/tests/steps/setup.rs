@@ -51,12 +51,16 @@ fn users_setup(world: &mut DaoWorld, step: &Step) {
 fn admin_is_the_owner_of_all_contracts(world: &mut DaoWorld) {
     let contracts = [
         Contract::BidEscrow,
+        Contract::GovernanceExecutor,
         Contract::KycToken,
         Contract::KycVoter,
+        Contract::MultiOptionVoter,
         Contract::Onboarding,
+        Contract::Participation,
         Contract::RepoVoter,
         Contract::ReputationToken,
         Contract::ReputationVoter,
+        Contract::SignalVoter,
         Contract::SimpleVoter,
         Contract::SlashingVoter,
         Contract::VaToken,
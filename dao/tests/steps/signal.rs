@@ -0,0 +1,61 @@
+use cucumber::{gherkin::Step, then, when};
+use dao::{utils::Error, voting::voting_engine::voting_state_machine::VotingResult};
+
+use crate::common::{
+    helpers,
+    params::{voting::Choice, Account},
+    DaoWorld,
+};
+use crate::steps::suppress;
+
+#[when(expr = "{account} starts signal voting on {word}")]
+fn start_signal_voting(world: &mut DaoWorld, creator: Account, document_hash: String) {
+    world.create_signal_voting(&creator, document_hash);
+}
+
+#[when(expr = "voters vote in signal voting with id {int}")]
+fn vote(world: &mut DaoWorld, step: &Step, voting_id: u32) {
+    let rows = step.table.as_ref().unwrap().rows.iter().skip(1);
+    for row in rows {
+        let voter = helpers::parse::<Account>(row.get(0), "Couldn't parse voter");
+        let choice = helpers::parse::<Choice>(row.get(1), "Couldn't parse choice");
+        suppress(|| world.signal_vote(&voter, voting_id, choice.into()));
+    }
+}
+
+#[then(expr = "{account} can't vote {choice} in signal voting with id {int}")]
+fn vote_fails(world: &mut DaoWorld, voter: Account, choice: Choice, voting_id: u32) {
+    world.failing_signal_vote(
+        &voter,
+        voting_id,
+        choice.into(),
+        Error::VoteOnCompletedVotingNotAllowed,
+    );
+}
+
+#[when(expr = "{account} changes vote to {choice} in signal voting with id {int}")]
+fn change_vote(world: &mut DaoWorld, voter: Account, choice: Choice, voting_id: u32) {
+    world.signal_change_vote(&voter, voting_id, choice.into());
+}
+
+#[when(expr = "{account} withdraws vote in signal voting with id {int}")]
+fn withdraw_vote(world: &mut DaoWorld, voter: Account, voting_id: u32) {
+    world.signal_withdraw_vote(&voter, voting_id);
+}
+
+#[then(expr = "{account} can't withdraw vote in signal voting with id {int}")]
+fn withdraw_vote_fails(world: &mut DaoWorld, voter: Account, voting_id: u32) {
+    world.failing_signal_withdraw_vote(&voter, voting_id, Error::CannotChangeCreatorsBallot);
+}
+
+#[then(expr = "signal voting with id {int} ends {word}")]
+fn end_signal_voting(world: &mut DaoWorld, voting_id: u32, result: String) {
+    let expected_result = match result.as_str() {
+        "in_favor" => VotingResult::InFavor,
+        "against" => VotingResult::Against,
+        "without_quorum" => VotingResult::QuorumNotReached,
+        invalid => panic!("Invalid voting result: {invalid}"),
+    };
+    let summary = world.finish_signal_voting(voting_id);
+    assert_eq!(summary.result(), expected_result);
+}
@@ -0,0 +1,158 @@
+use cucumber::{gherkin::Step, then, when};
+use dao::{
+    utils::{types::DocumentHash, Error as DaoError},
+    voting::ballot::Choice as DaoChoice,
+};
+
+use crate::common::{
+    helpers,
+    params::{
+        voting::{Choice, VotingType},
+        Account, ReputationBalance,
+    },
+    DaoWorld,
+};
+use crate::steps::suppress;
+
+#[when(
+    expr = "{account} relays ballots signed by voters in {voting_type} voting with id {int} in {account} contract"
+)]
+fn relay_signed_ballots(
+    world: &mut DaoWorld,
+    step: &Step,
+    relayer: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+) {
+    let ballots = step
+        .table
+        .as_ref()
+        .unwrap()
+        .rows
+        .iter()
+        .skip(1)
+        .map(|row| {
+            let voter = helpers::parse::<Account>(row.get(0), "Couldn't parse account");
+            let stake = helpers::parse::<ReputationBalance>(row.get(1), "Couldn't parse stake");
+            let choice = helpers::parse::<Choice>(row.get(2), "Couldn't parse choice");
+            let mut ballot = world.unsigned_ballot(
+                &contract,
+                &voter,
+                voting_id,
+                voting_type,
+                choice.into(),
+                stake,
+            );
+            ballot.rationale = helpers::parse_or_none(row.get(3));
+            world.sign_ballot(&contract, &voter, ballot)
+        })
+        .collect();
+
+    suppress(|| world.relay_ballots(&relayer, &contract, ballots));
+}
+
+#[then(
+    expr = "{account} can't relay a ballot of {account} signed by {account} in {voting_type} voting with id {int} in {account} contract"
+)]
+fn relay_ballot_with_invalid_signature(
+    world: &mut DaoWorld,
+    relayer: Account,
+    voter: Account,
+    signer: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+) {
+    let ballot = world.unsigned_ballot(
+        &contract,
+        &voter,
+        voting_id,
+        voting_type,
+        DaoChoice::InFavor,
+        ReputationBalance::one(),
+    );
+    let ballot = world.sign_ballot(&contract, &signer, ballot);
+    world.failing_relay_ballot(&relayer, &contract, ballot, DaoError::InvalidSignature);
+}
+
+#[then(
+    expr = "{account} can't relay a ballot of {account} with rationale changed to {word} in {voting_type} voting with id {int} in {account} contract"
+)]
+fn relay_ballot_with_changed_rationale(
+    world: &mut DaoWorld,
+    relayer: Account,
+    voter: Account,
+    rationale: DocumentHash,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+) {
+    let ballot = world.unsigned_ballot(
+        &contract,
+        &voter,
+        voting_id,
+        voting_type,
+        DaoChoice::InFavor,
+        ReputationBalance::one(),
+    );
+    let mut ballot = world.sign_ballot(&contract, &voter, ballot);
+    ballot.rationale = Some(rationale);
+    world.failing_relay_ballot(&relayer, &contract, ballot, DaoError::InvalidSignature);
+}
+
+#[then(
+    expr = "{account} can't relay a used ballot of {account} with {reputation} REP in {voting_type} voting with id {int} in {account} contract"
+)]
+fn relay_used_ballot(
+    world: &mut DaoWorld,
+    relayer: Account,
+    voter: Account,
+    stake: ReputationBalance,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+) {
+    let mut ballot = world.unsigned_ballot(
+        &contract,
+        &voter,
+        voting_id,
+        voting_type,
+        DaoChoice::InFavor,
+        stake,
+    );
+    ballot.nonce -= 1;
+    let ballot = world.sign_ballot(&contract, &voter, ballot);
+    world.failing_relay_ballot(&relayer, &contract, ballot, DaoError::InvalidNonce);
+}
+
+#[then(
+    expr = "{account} can't relay an expired ballot of {account} with {reputation} REP in {voting_type} voting with id {int} in {account} contract"
+)]
+fn relay_expired_ballot(
+    world: &mut DaoWorld,
+    relayer: Account,
+    voter: Account,
+    stake: ReputationBalance,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+) {
+    let mut ballot = world.unsigned_ballot(
+        &contract,
+        &voter,
+        voting_id,
+        voting_type,
+        DaoChoice::InFavor,
+        stake,
+    );
+    ballot.deadline = 0;
+    let ballot = world.sign_ballot(&contract, &voter, ballot);
+    world.failing_relay_ballot(&relayer, &contract, ballot, DaoError::SignatureExpired);
+}
+
+#[then(expr = "{account} nonce in {account} contract is {int}")]
+fn assert_nonce(world: &mut DaoWorld, voter: Account, contract: Account, expected_nonce: u64) {
+    let voter = world.get_address(&voter);
+    assert_eq!(world.get_nonce(&contract, &voter), expected_nonce);
+}
@@ -0,0 +1,36 @@
+use cucumber::{then, when};
+use dao::utils::Error as DaoError;
+
+use crate::common::{
+    params::{Account, Error},
+    DaoWorld,
+};
+
+#[when(expr = "{account} executes the action of voting with id {int} in {account} contract")]
+fn execute_action(world: &mut DaoWorld, caller: Account, voting_id: u32, contract: Account) {
+    world.execute_action(&caller, &contract, voting_id);
+}
+
+#[then(
+    expr = "{account} can't execute the action of voting with id {int} in {account} contract with {word}"
+)]
+fn execute_action_fails(
+    world: &mut DaoWorld,
+    caller: Account,
+    voting_id: u32,
+    contract: Account,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_execute_action(&caller, &contract, voting_id, *error);
+}
+
+#[when(expr = "{account} cancels the action of voting with id {int} in {account} contract")]
+fn cancel_action(world: &mut DaoWorld, caller: Account, voting_id: u32, contract: Account) {
+    world.cancel_action(&caller, &contract, voting_id);
+}
+
+#[then(expr = "{account} can't cancel the action of voting with id {int} in {account} contract")]
+fn cancel_action_fails(world: &mut DaoWorld, caller: Account, voting_id: u32, contract: Account) {
+    world.failing_cancel_action(&caller, &contract, voting_id, DaoError::CannotCancelAction);
+}
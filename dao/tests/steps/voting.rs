@@ -1,5 +1,8 @@
 use cucumber::{gherkin::Step, given, then, when};
-use dao::{utils::Error as DaoError, voting::voting_engine::voting_state_machine::VotingState};
+use dao::{
+    utils::{types::DocumentHash, Error as DaoError},
+    voting::voting_engine::voting_state_machine::VotingState,
+};
 use odra::test_env;
 
 use crate::common::{
@@ -46,10 +49,12 @@ fn voting(
     let rows = step.table.as_ref().unwrap().rows.iter().skip(1);
 
     rows.map(|row| {
-        BallotBuilder::default()
+        let mut ballot = BallotBuilder::default()
             .with_voting_id(voting_id)
             .with_voting_type(voting_type)
-            .build(row)
+            .build(row);
+        ballot.rationale = helpers::parse_or_none(row.get(3));
+        ballot
     })
     .filter(|ballot| !ballot.stake.is_zero())
     .for_each(|ballot| {
@@ -57,6 +62,423 @@ fn voting(
     });
 }
 
+#[given(
+    expr = "{account} delegates voting power to {account} globally with at most {reputation} REP"
+)]
+fn delegate_voting_power_globally(
+    world: &mut DaoWorld,
+    delegator: Account,
+    delegate: Account,
+    max_stake: ReputationBalance,
+) {
+    world.delegate_voting_power(&delegator, &delegate, None, max_stake);
+}
+
+#[given(
+    expr = "{account} delegates voting power to {account} in {account} contract with at most {reputation} REP"
+)]
+fn delegate_voting_power(
+    world: &mut DaoWorld,
+    delegator: Account,
+    delegate: Account,
+    contract: Account,
+    max_stake: ReputationBalance,
+) {
+    world.delegate_voting_power(&delegator, &delegate, Some(&contract), max_stake);
+}
+
+#[given(expr = "{account} disables delegation in {account} contract")]
+fn disable_delegation(world: &mut DaoWorld, delegator: Account, contract: Account) {
+    world.disable_delegation(&delegator, &contract);
+}
+
+#[when(
+    expr = "{account} votes {choice} with {reputation} REP as a delegate in {account} {voting_type} voting with id {int}"
+)]
+#[allow(clippy::too_many_arguments)]
+fn vote_as_delegate(
+    world: &mut DaoWorld,
+    step: &Step,
+    delegate: Account,
+    choice: Choice,
+    stake: ReputationBalance,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    let ballot = Ballot {
+        voter: delegate,
+        stake,
+        choice,
+        voting_id,
+        voting_type,
+        rationale: None,
+    };
+    world.vote_as_delegate(&contract, &ballot, parse_delegators(step));
+}
+
+#[then(
+    expr = "{account} can't vote {choice} with {reputation} REP as a delegate in {account} {voting_type} voting with id {int} with {word}"
+)]
+#[allow(clippy::too_many_arguments)]
+fn vote_as_delegate_fails(
+    world: &mut DaoWorld,
+    step: &Step,
+    delegate: Account,
+    choice: Choice,
+    stake: ReputationBalance,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    let ballot = Ballot {
+        voter: delegate,
+        stake,
+        choice,
+        voting_id,
+        voting_type,
+        rationale: None,
+    };
+    world.failing_vote_as_delegate(&contract, &ballot, parse_delegators(step), *error);
+}
+
+fn parse_delegators(step: &Step) -> Vec<(Account, ReputationBalance)> {
+    step.table
+        .as_ref()
+        .unwrap()
+        .rows
+        .iter()
+        .skip(1)
+        .map(|row| {
+            let delegator = helpers::parse::<Account>(row.get(0), "Couldn't parse delegator");
+            let stake = helpers::parse_or_default::<ReputationBalance>(row.get(1));
+            (delegator, stake)
+        })
+        .collect()
+}
+
+#[when(
+    expr = "{account} changes vote in {account} {voting_type} voting with id {int} to {choice} with {reputation} REP"
+)]
+fn change_vote(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    choice: Choice,
+    stake: ReputationBalance,
+) {
+    let ballot = Ballot {
+        voter,
+        stake,
+        choice,
+        voting_id,
+        voting_type,
+        rationale: None,
+    };
+    world.change_vote(&contract, &ballot);
+}
+
+#[when(
+    expr = "{account} changes vote in {account} {voting_type} voting with id {int} to {choice} with {reputation} REP and rationale {word}"
+)]
+#[allow(clippy::too_many_arguments)]
+fn change_vote_with_rationale(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    choice: Choice,
+    stake: ReputationBalance,
+    rationale: DocumentHash,
+) {
+    let ballot = Ballot {
+        voter,
+        stake,
+        choice,
+        voting_id,
+        voting_type,
+        rationale: Some(rationale),
+    };
+    world.change_vote(&contract, &ballot);
+}
+
+#[then(
+    expr = "{account} can't change vote in {account} {voting_type} voting with id {int} to {choice} with {reputation} REP with {word}"
+)]
+#[allow(clippy::too_many_arguments)]
+fn assert_change_vote_fails(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    choice: Choice,
+    stake: ReputationBalance,
+    error: String,
+) {
+    let ballot = Ballot {
+        voter,
+        stake,
+        choice,
+        voting_id,
+        voting_type,
+        rationale: None,
+    };
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_change_vote(&contract, &ballot, *error);
+}
+
+#[when(expr = "{account} withdraws vote in {account} {voting_type} voting with id {int}")]
+fn withdraw_vote(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    world.withdraw_vote(&contract, &voter, voting_id, voting_type);
+}
+
+#[then(expr = "{account} can't withdraw vote in {account} {voting_type} voting with id {int}")]
+fn assert_withdraw_vote_fails(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    world.failing_withdraw_vote(
+        &contract,
+        &voter,
+        voting_id,
+        voting_type,
+        DaoError::CannotChangeCreatorsBallot,
+    );
+}
+
+#[then(
+    expr = "{account} can't withdraw vote in {account} {voting_type} voting with id {int} with {word}"
+)]
+fn assert_withdraw_vote_fails_with(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_withdraw_vote(&contract, &voter, voting_id, voting_type, *error);
+}
+
+#[when(expr = "{account} votes in {account} contract in a batch")]
+fn vote_batch(world: &mut DaoWorld, step: &Step, voter: Account, contract: Account) {
+    let ballots = batch_ballots(step, voter);
+    world.vote_batch(&contract, &voter, &ballots);
+}
+
+#[then(
+    expr = "{account} can't vote in {account} contract in a batch failing at item {int} with {word}"
+)]
+fn assert_vote_batch_fails(
+    world: &mut DaoWorld,
+    step: &Step,
+    voter: Account,
+    contract: Account,
+    index: usize,
+    error: String,
+) {
+    let ballots = batch_ballots(step, voter);
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_vote_batch(&contract, &voter, &ballots, index, *error);
+}
+
+#[when(expr = "{account} votes in {account} contract in a batch with rationale")]
+fn vote_batch_with_rationale(world: &mut DaoWorld, step: &Step, voter: Account, contract: Account) {
+    let ballots = batch_ballots(step, voter);
+    world.vote_batch_with_rationale(&contract, &voter, &ballots);
+}
+
+#[then(
+    expr = "{account} can't vote in {account} contract in a batch with rationale failing at item {int} with {word}"
+)]
+fn assert_vote_batch_with_rationale_fails(
+    world: &mut DaoWorld,
+    step: &Step,
+    voter: Account,
+    contract: Account,
+    index: usize,
+    error: String,
+) {
+    let ballots = batch_ballots(step, voter);
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_vote_batch_with_rationale(&contract, &voter, &ballots, index, *error);
+}
+
+fn batch_ballots(step: &Step, voter: Account) -> Vec<Ballot> {
+    let rows = step.table.as_ref().unwrap().rows.iter().skip(1);
+    rows.map(|row| Ballot {
+        voter,
+        voting_id: helpers::parse(row.get(0), "Couldn't parse voting id"),
+        voting_type: helpers::parse(row.get(1), "Couldn't parse voting type"),
+        choice: helpers::parse(row.get(2), "Couldn't parse choice"),
+        stake: helpers::parse_or_default(row.get(3)),
+        rationale: helpers::parse_or_none(row.get(4)),
+    })
+    .collect()
+}
+
+#[when(expr = "{account} withdraws voting with id {int} in {account} contract")]
+fn withdraw_voting(world: &mut DaoWorld, creator: Account, voting_id: u32, contract: Account) {
+    world.withdraw_voting(&contract, &creator, voting_id);
+}
+
+#[then(expr = "{account} can't withdraw voting with id {int} in {account} contract with {word}")]
+fn assert_withdraw_voting_fails(
+    world: &mut DaoWorld,
+    creator: Account,
+    voting_id: u32,
+    contract: Account,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_withdraw_voting(&contract, &creator, voting_id, *error);
+}
+
+#[when(
+    expr = "{account} commits secret {choice} vote with {reputation} REP and salt {word} in {account} voting with id {int}"
+)]
+fn commit_vote(
+    world: &mut DaoWorld,
+    voter: Account,
+    choice: Choice,
+    stake: ReputationBalance,
+    salt: String,
+    contract: Account,
+    voting_id: u32,
+) {
+    let ballot = Ballot {
+        voter,
+        stake,
+        choice,
+        voting_id,
+        voting_type: VotingType::Formal,
+        rationale: None,
+    };
+    world.commit_vote(&contract, &ballot, &salt);
+}
+
+#[then(
+    expr = "{account} can't commit secret {choice} vote with {reputation} REP and salt {word} in {account} voting with id {int} with {word}"
+)]
+#[allow(clippy::too_many_arguments)]
+fn assert_commit_vote_fails(
+    world: &mut DaoWorld,
+    voter: Account,
+    choice: Choice,
+    stake: ReputationBalance,
+    salt: String,
+    contract: Account,
+    voting_id: u32,
+    error: String,
+) {
+    let ballot = Ballot {
+        voter,
+        stake,
+        choice,
+        voting_id,
+        voting_type: VotingType::Formal,
+        rationale: None,
+    };
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_commit_vote(&contract, &ballot, &salt, *error);
+}
+
+#[when(expr = "{account} reveals {choice} vote with salt {word} in {account} voting with id {int}")]
+fn reveal_vote(
+    world: &mut DaoWorld,
+    voter: Account,
+    choice: Choice,
+    salt: String,
+    contract: Account,
+    voting_id: u32,
+) {
+    world.reveal_vote(&contract, &voter, voting_id, choice.into(), &salt, None);
+}
+
+#[when(
+    expr = "{account} reveals {choice} vote with salt {word} and rationale {word} in {account} voting with id {int}"
+)]
+fn reveal_vote_with_rationale(
+    world: &mut DaoWorld,
+    voter: Account,
+    choice: Choice,
+    salt: String,
+    rationale: DocumentHash,
+    contract: Account,
+    voting_id: u32,
+) {
+    world.reveal_vote(
+        &contract,
+        &voter,
+        voting_id,
+        choice.into(),
+        &salt,
+        Some(rationale),
+    );
+}
+
+#[then(
+    expr = "{account} can't reveal {choice} vote with salt {word} in {account} voting with id {int}"
+)]
+fn assert_reveal_vote_fails(
+    world: &mut DaoWorld,
+    voter: Account,
+    choice: Choice,
+    salt: String,
+    contract: Account,
+    voting_id: u32,
+) {
+    world.failing_reveal_vote(
+        &contract,
+        &voter,
+        voting_id,
+        choice.into(),
+        &salt,
+        None,
+        DaoError::InvalidBallotCommitment,
+    );
+}
+
+#[then(
+    expr = "{account} can't reveal {choice} vote with salt {word} in {account} voting with id {int} with {word}"
+)]
+fn assert_reveal_vote_fails_with(
+    world: &mut DaoWorld,
+    voter: Account,
+    choice: Choice,
+    salt: String,
+    contract: Account,
+    voting_id: u32,
+    error: String,
+) {
+    let error = error.parse::<Error>().expect("Valid error expected");
+    world.failing_reveal_vote(
+        &contract,
+        &voter,
+        voting_id,
+        choice.into(),
+        &salt,
+        None,
+        *error,
+    );
+}
+
 #[when(expr = "{account} creates test voting in {contract} with {reputation} stake")]
 fn create_test_voting(
     world: &mut DaoWorld,
@@ -129,6 +551,15 @@ fn assert_vote_fails(
                 world.failing_vote(&contract, &ballot, DaoError::InsufficientBalance)
             }
             DaoError::ZeroStake => world.failing_vote(&contract, &ballot, DaoError::ZeroStake),
+            DaoError::SecretBallotRequired => {
+                world.failing_vote(&contract, &ballot, DaoError::SecretBallotRequired)
+            }
+            DaoError::StakeExceedsSnapshotBalance => {
+                world.failing_vote(&contract, &ballot, DaoError::StakeExceedsSnapshotBalance)
+            }
+            DaoError::RationaleRequired => {
+                world.failing_vote(&contract, &ballot, DaoError::RationaleRequired)
+            }
             _ => panic!("Unknown error"),
         }
     });
@@ -151,6 +582,7 @@ fn assert_vote(
     let ballot = Ballot {
         voting_id,
         voting_type,
+        rationale: None,
         voter,
         choice,
         stake,
@@ -193,6 +625,37 @@ fn assert_ballot_is_unbounded(
     );
 }
 
+#[then(
+    expr = "{account} ballot in {account} {voting_type} voting with id {int} has rationale {word}"
+)]
+fn assert_ballot_rationale(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    rationale: DocumentHash,
+) {
+    let ballot = world
+        .get_ballot(&contract, &voter, voting_id, voting_type.into())
+        .expect("Ballot doesn't exists");
+    assert_eq!(ballot.rationale, Some(rationale));
+}
+
+#[then(expr = "{account} ballot in {account} {voting_type} voting with id {int} has no rationale")]
+fn assert_ballot_without_rationale(
+    world: &mut DaoWorld,
+    voter: Account,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+) {
+    let ballot = world
+        .get_ballot(&contract, &voter, voting_id, voting_type.into())
+        .expect("Ballot doesn't exists");
+    assert_eq!(ballot.rationale, None);
+}
+
 #[then(expr = "{account} total unbounded stake for voting {int} is {reputation} tokens")]
 fn assert_unbounded_stake(
     w: &mut DaoWorld,
@@ -209,6 +672,34 @@ fn assert_unbounded_stake(
     );
 }
 
+#[then(
+    expr = "{account} {voting_type} voting with id {int} has {reputation} REP abstained by {int} voter(s)"
+)]
+fn assert_abstain_stats(
+    world: &mut DaoWorld,
+    contract: Account,
+    voting_type: VotingType,
+    voting_id: u32,
+    amount: ReputationBalance,
+    votes: u32,
+) {
+    let voting = world.get_voting(&contract, voting_id);
+    let stats = match voting_type {
+        VotingType::Informal => voting.informal_stats(),
+        VotingType::Formal => voting.formal_stats(),
+    };
+    assert_eq!(
+        stats.stake_abstain, *amount,
+        "Abstain stake is {:?}, but should be {:?}",
+        stats.stake_abstain, amount
+    );
+    assert_eq!(
+        stats.votes_abstain, votes,
+        "Abstain votes count is {:?}, but should be {:?}",
+        stats.votes_abstain, votes
+    );
+}
+
 #[when(expr = "{account} voting with id {int} created by {account} passes")]
 fn voting_passes(
     world: &mut DaoWorld,
@@ -269,6 +760,7 @@ fn conduct_voting(
             choice,
             voting_id,
             voting_type,
+            rationale: None,
         })
         .for_each(|ballot| {
             world.vote(&contract, &ballot);
@@ -293,6 +785,7 @@ fn conduct_voting(
             choice,
             voting_id,
             voting_type,
+            rationale: None,
         })
         .for_each(|ballot| world.vote(&contract, &ballot));
 
@@ -302,3 +795,42 @@ fn conduct_voting(
     // formal voting ends
     world.finish_voting(&contract, voting_id, Some(voting_type));
 }
+
+#[then(
+    expr = "{voting_type} phase of voting with id {int} in {account} contract is extended by {int} {time_unit}"
+)]
+fn assert_phase_extension(
+    world: &mut DaoWorld,
+    voting_type: VotingType,
+    voting_id: u32,
+    contract: Account,
+    amount: u64,
+    unit: TimeUnit,
+) {
+    let voting = world.get_voting(&contract, voting_id);
+    let extension = match voting_type {
+        VotingType::Informal => voting.informal_extension(),
+        VotingType::Formal => voting.formal_extension(),
+    };
+    assert_eq!(extension, to_milliseconds(amount, unit));
+}
+
+#[then(expr = "formal voting with id {int} in {account} contract can't end yet")]
+fn assert_formal_voting_cannot_end(world: &mut DaoWorld, voting_id: u32, contract: Account) {
+    world.failing_finish_voting(
+        &contract,
+        voting_id,
+        VotingType::Formal,
+        DaoError::FormalVotingNotCompleted,
+    );
+}
+
+#[then(expr = "voting with id {int} in {account} contract ended early")]
+fn assert_voting_ended_early(world: &mut DaoWorld, voting_id: u32, contract: Account) {
+    assert!(world.get_voting(&contract, voting_id).ended_early());
+}
+
+#[then(expr = "voting with id {int} in {account} contract didn't end early")]
+fn assert_voting_not_ended_early(world: &mut DaoWorld, voting_id: u32, contract: Account) {
+    assert!(!world.get_voting(&contract, voting_id).ended_early());
+}